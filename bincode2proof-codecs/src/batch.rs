@@ -0,0 +1,20 @@
+//! Conversion of batches of receipts.
+//!
+//! With the `rayon` feature, receipts are decoded, hashed and encoded in parallel, which pays off
+//! as digesting dominates the conversion of composite receipts with many segments.
+
+use bincode2proof_core::{batch::map_items, ConvertError, ProofData};
+
+use crate::codec;
+
+/// Convert every bincode encoded receipt of `receipts` to [ProofData], decompressing it first
+/// as [codec::decode_compressed], returning one result per receipt, in order.
+pub fn convert_batch<B>(receipts: &[B]) -> Vec<Result<ProofData, ConvertError>>
+where
+    B: AsRef<[u8]> + Sync,
+{
+    map_items(receipts, |bytes| {
+        let receipt = codec::decode_compressed(bytes.as_ref(), codec::Format::Bincode)?;
+        ProofData::try_from(receipt)
+    })
+}
@@ -0,0 +1,429 @@
+//! Reading and writing receipt types in the serialization formats used by provers and
+//! middleware.
+
+use core::str::FromStr;
+
+use base64::{
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    Engine,
+};
+use bincode::Options;
+use bincode2proof_core::{limits, ConvertError, Receipt};
+#[cfg(feature = "borsh")]
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{de::DeserializeOwned, Serialize};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::trace;
+
+/// Serialization format of an encoded [Receipt] or
+/// [ReceiptClaim](bincode2proof_core::ReceiptClaim).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Bincode,
+    Borsh,
+    Json,
+    Cbor,
+    MsgPack,
+    Postcard,
+}
+
+impl Format {
+    /// Name of this format, as accepted by [Format::from_str].
+    pub fn name(&self) -> &'static str {
+        match self {
+            Format::Bincode => "bincode",
+            Format::Borsh => "borsh",
+            Format::Json => "json",
+            Format::Cbor => "cbor",
+            Format::MsgPack => "msgpack",
+            Format::Postcard => "postcard",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = ConvertError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_lowercase().as_str() {
+            "bincode" => Ok(Format::Bincode),
+            "borsh" => Ok(Format::Borsh),
+            "json" => Ok(Format::Json),
+            "cbor" => Ok(Format::Cbor),
+            "msgpack" | "messagepack" => Ok(Format::MsgPack),
+            "postcard" => Ok(Format::Postcard),
+            _ => Err(ConvertError::UnknownFormat(name.to_string())),
+        }
+    }
+}
+
+/// Options of the bincode 1 encoding.
+///
+/// The default matches `bincode::serialize`: fixed-size little-endian integers, as written by
+/// risc0 provers. [BincodeConfig::standard] matches the `standard()` configuration of bincode 2.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BincodeConfig {
+    /// Maximum number of bytes read while decoding. Defaults to the length of the input, so that
+    /// length prefixes can never claim more data than was supplied.
+    pub limit: Option<u64>,
+    /// Encode integers with a variable number of bytes.
+    pub varint: bool,
+    /// Encode integers in big-endian byte order.
+    pub big_endian: bool,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl BincodeConfig {
+    /// The configuration of `bincode::serialize`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new() -> BincodeConfig {
+        Self::default()
+    }
+
+    /// The `standard()` configuration of bincode 2: variable-size little-endian integers.
+    pub fn standard() -> BincodeConfig {
+        BincodeConfig {
+            varint: true,
+            ..Self::default()
+        }
+    }
+}
+
+impl BincodeConfig {
+    /// Set the maximum number of bytes read while decoding.
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Decode a value from bytes with this configuration. Trailing bytes are ignored.
+    ///
+    /// Errors report the byte offset at which decoding stopped and the path of the field being
+    /// decoded.
+    pub fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, ConvertError> {
+        self.deserialize_reader(bytes, bytes.len())
+    }
+
+    /// Decode a value from the `len` bytes of `reader`, as [BincodeConfig::deserialize].
+    pub fn deserialize_reader<T, R>(&self, reader: R, len: usize) -> Result<T, ConvertError>
+    where
+        T: DeserializeOwned,
+        R: std::io::Read,
+    {
+        let limit = self.limit.unwrap_or(len as u64);
+        let options = bincode::DefaultOptions::new()
+            .with_limit(limit)
+            .allow_trailing_bytes();
+        let reader = CountingReader { reader, read: 0 };
+        match (self.varint, self.big_endian) {
+            (false, false) => deserialize_traced(
+                reader,
+                len,
+                limit,
+                options.with_fixint_encoding().with_little_endian(),
+            ),
+            (false, true) => deserialize_traced(
+                reader,
+                len,
+                limit,
+                options.with_fixint_encoding().with_big_endian(),
+            ),
+            (true, false) => deserialize_traced(
+                reader,
+                len,
+                limit,
+                options.with_varint_encoding().with_little_endian(),
+            ),
+            (true, true) => deserialize_traced(
+                reader,
+                len,
+                limit,
+                options.with_varint_encoding().with_big_endian(),
+            ),
+        }
+    }
+
+    /// Encode a value to bytes with this configuration. The limit is not applied.
+    pub fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, bincode::Error> {
+        let options = bincode::DefaultOptions::new();
+        match (self.varint, self.big_endian) {
+            (false, false) => options
+                .with_fixint_encoding()
+                .with_little_endian()
+                .serialize(value),
+            (false, true) => options
+                .with_fixint_encoding()
+                .with_big_endian()
+                .serialize(value),
+            (true, false) => options
+                .with_varint_encoding()
+                .with_little_endian()
+                .serialize(value),
+            (true, true) => options
+                .with_varint_encoding()
+                .with_big_endian()
+                .serialize(value),
+        }
+    }
+}
+
+/// Counts the bytes read, which gives the offset of a decoding failure.
+struct CountingReader<R> {
+    reader: R,
+    read: usize,
+}
+
+impl<R: std::io::Read> std::io::Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        self.read += read;
+        Ok(read)
+    }
+}
+
+fn deserialize_traced<T, R, O>(
+    mut reader: CountingReader<R>,
+    len: usize,
+    limit: u64,
+    options: O,
+) -> Result<T, ConvertError>
+where
+    T: DeserializeOwned,
+    R: std::io::Read,
+    O: Options,
+{
+    // Read through `io::Read`, as bincode does not enforce the limit on slices.
+    let mut deserializer = bincode::Deserializer::with_reader(&mut reader, options);
+    trace::deserialize(&mut deserializer).map_err(|failure| {
+        let message = match *failure.error {
+            // Only the input length limits reads unless a limit was configured.
+            bincode::ErrorKind::SizeLimit if limit == len as u64 => {
+                "unexpected end of input".to_string()
+            }
+            error => error.to_string(),
+        };
+        ConvertError::InvalidInput {
+            format: Format::Bincode.name(),
+            offset: reader.read,
+            path: failure.path,
+            expected: failure.expected,
+            message,
+        }
+    })
+}
+
+/// Types that can be decoded in every enabled [Format].
+#[cfg(feature = "borsh")]
+pub trait Decode: DeserializeOwned + BorshDeserialize {}
+#[cfg(feature = "borsh")]
+impl<T: DeserializeOwned + BorshDeserialize> Decode for T {}
+/// Types that can be decoded in every enabled [Format].
+#[cfg(not(feature = "borsh"))]
+pub trait Decode: DeserializeOwned {}
+#[cfg(not(feature = "borsh"))]
+impl<T: DeserializeOwned> Decode for T {}
+
+/// Types that can be encoded in every enabled [Format].
+#[cfg(feature = "borsh")]
+pub trait Encode: Serialize + BorshSerialize {}
+#[cfg(feature = "borsh")]
+impl<T: Serialize + BorshSerialize> Encode for T {}
+/// Types that can be encoded in every enabled [Format].
+#[cfg(not(feature = "borsh"))]
+pub trait Encode: Serialize {}
+#[cfg(not(feature = "borsh"))]
+impl<T: Serialize> Encode for T {}
+
+/// Decode a borsh value, reporting the byte offset of a failure. The borsh derives have no
+/// hooks to track the field being decoded, so only the decoded type is reported.
+#[cfg(feature = "borsh")]
+fn borsh_traced<T: BorshDeserialize>(bytes: &[u8]) -> Result<T, ConvertError> {
+    let mut rest = bytes;
+    let decoded = T::deserialize(&mut rest).and_then(|value| match rest.is_empty() {
+        true => Ok(value),
+        false => Err(borsh::io::Error::new(
+            borsh::io::ErrorKind::InvalidData,
+            "not all bytes read",
+        )),
+    });
+    decoded.map_err(|err| ConvertError::InvalidInput {
+        format: Format::Borsh.name(),
+        offset: bytes.len() - rest.len(),
+        path: String::new(),
+        expected: trace::short_type_name(core::any::type_name::<T>()),
+        message: err.to_string(),
+    })
+}
+
+#[cfg(not(feature = "borsh"))]
+fn borsh_traced<T>(_bytes: &[u8]) -> Result<T, ConvertError> {
+    Err(ConvertError::FormatDisabled(Format::Borsh.name()))
+}
+
+#[cfg(feature = "borsh")]
+fn borsh_to_vec<T: BorshSerialize>(value: &T) -> Result<Vec<u8>, ConvertError> {
+    borsh::to_vec(value).map_err(|err| ConvertError::EncodeError(err.to_string()))
+}
+
+#[cfg(not(feature = "borsh"))]
+fn borsh_to_vec<T>(_value: &T) -> Result<Vec<u8>, ConvertError> {
+    Err(ConvertError::FormatDisabled(Format::Borsh.name()))
+}
+
+#[cfg(feature = "json")]
+fn json_from_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, ConvertError> {
+    serde_json::from_slice(bytes).map_err(|err| ConvertError::DecodeError(err.to_string()))
+}
+
+#[cfg(not(feature = "json"))]
+fn json_from_slice<T>(_bytes: &[u8]) -> Result<T, ConvertError> {
+    Err(ConvertError::FormatDisabled(Format::Json.name()))
+}
+
+#[cfg(feature = "json")]
+fn json_to_vec<T: Serialize>(value: &T) -> Result<Vec<u8>, ConvertError> {
+    serde_json::to_vec(value).map_err(|err| ConvertError::EncodeError(err.to_string()))
+}
+
+#[cfg(not(feature = "json"))]
+fn json_to_vec<T>(_value: &T) -> Result<Vec<u8>, ConvertError> {
+    Err(ConvertError::FormatDisabled(Format::Json.name()))
+}
+
+/// Decode a value from bytes in the given [Format].
+pub fn decode<T: Decode>(bytes: &[u8], format: Format) -> Result<T, ConvertError> {
+    limits::enforce(|| {
+        let decoded = match format {
+            Format::Bincode => return BincodeConfig::default().deserialize(bytes),
+            Format::Borsh => return borsh_traced(bytes),
+            Format::Json => return json_from_slice(bytes),
+            Format::Cbor => ciborium::from_reader(bytes).map_err(|err| err.to_string()),
+            Format::MsgPack => rmp_serde::from_slice(bytes).map_err(|err| err.to_string()),
+            Format::Postcard => postcard::from_bytes(bytes).map_err(|err| err.to_string()),
+        };
+        decoded.map_err(ConvertError::DecodeError)
+    })
+}
+
+/// Encode a value to bytes in the given [Format].
+pub fn encode<T: Encode>(value: &T, format: Format) -> Result<Vec<u8>, ConvertError> {
+    let encoded = match format {
+        Format::Bincode => BincodeConfig::default()
+            .serialize(value)
+            .map_err(|err| err.to_string()),
+        Format::Borsh => return borsh_to_vec(value),
+        Format::Json => return json_to_vec(value),
+        Format::Cbor => {
+            let mut bytes = Vec::new();
+            ciborium::into_writer(value, &mut bytes)
+                .map(|_| bytes)
+                .map_err(|err| err.to_string())
+        }
+        // Structs are written as maps keyed by field name, as expected by most MessagePack RPC
+        // peers. Positional arrays are accepted when decoding.
+        Format::MsgPack => rmp_serde::to_vec_named(value).map_err(|err| err.to_string()),
+        Format::Postcard => postcard::to_allocvec(value).map_err(|err| err.to_string()),
+    };
+    encoded.map_err(ConvertError::EncodeError)
+}
+
+/// Same as [decode], decompressing gzip or zstd compressed input first when the `compression`
+/// feature is enabled.
+pub fn decode_compressed(bytes: &[u8], format: Format) -> Result<Receipt, ConvertError> {
+    #[cfg(feature = "compression")]
+    return crate::compress::decode_decompressed(bytes, |bytes| decode(bytes, format));
+    #[cfg(not(feature = "compression"))]
+    decode(bytes, format)
+}
+
+/// Guess the candidate formats of an encoded [Receipt] from its first byte. Text and
+/// self-describing formats have distinctive leading bytes: a JSON object, a CBOR map or a
+/// MessagePack map or array. Bincode, borsh and postcard all start with the [InnerReceipt]
+/// variant tag.
+///
+/// [InnerReceipt]: bincode2proof_core::InnerReceipt
+fn candidates(bytes: &[u8]) -> &'static [Format] {
+    match bytes.iter().find(|byte| !byte.is_ascii_whitespace()) {
+        Some(b'{') => &[Format::Json],
+        Some(0xa0..=0xbf) => &[Format::Cbor],
+        Some(0x80..=0x9f | 0xdc..=0xdf) => &[Format::MsgPack],
+        _ => &[Format::Bincode, Format::Borsh, Format::Postcard],
+    }
+}
+
+/// Decode a [Receipt] in any supported [Format], returning the detected format.
+///
+/// Binary formats without a distinctive header are tried in turn, and a candidate is accepted
+/// only if it consumes the whole input. Gzip and zstd compressed input is decompressed first when
+/// the `compression` feature is enabled.
+pub fn decode_auto(bytes: &[u8]) -> Result<(Receipt, Format), ConvertError> {
+    #[cfg(feature = "compression")]
+    return crate::compress::decode_decompressed(bytes, decode_auto_uncompressed);
+    #[cfg(not(feature = "compression"))]
+    decode_auto_uncompressed(bytes)
+}
+
+fn decode_auto_uncompressed(bytes: &[u8]) -> Result<(Receipt, Format), ConvertError> {
+    let mut first_error = None;
+    for &format in candidates(bytes) {
+        let consumed = |receipt: &Receipt| match format {
+            Format::Bincode => bincode::serialized_size(receipt).ok() == Some(bytes.len() as u64),
+            Format::Postcard => {
+                postcard::to_allocvec(receipt).is_ok_and(|b| b.len() == bytes.len())
+            }
+            // Borsh rejects trailing bytes, and the self-describing formats are not ambiguous.
+            _ => true,
+        };
+        match decode(bytes, format) {
+            Ok(receipt) if consumed(&receipt) => return Ok((receipt, format)),
+            Ok(_) => {}
+            Err(err) => {
+                first_error.get_or_insert(err);
+            }
+        }
+    }
+    Err(first_error.unwrap_or_else(|| {
+        ConvertError::DecodeError("input is not a receipt in any supported format".to_string())
+    }))
+}
+
+/// Decode a hex or base64 string holding an encoded receipt. Hex is recognized by a `0x`
+/// prefix, or by an even number of hex digits, and base64 may be standard or URL-safe, with or
+/// without padding. Whitespace is ignored.
+pub fn decode_text(text: &str) -> Result<Vec<u8>, ConvertError> {
+    let text: String = text.split_ascii_whitespace().collect();
+    let hex = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => Some(hex),
+        None if text.len().is_multiple_of(2) && text.bytes().all(|c| c.is_ascii_hexdigit()) => {
+            Some(text.as_str())
+        }
+        None => None,
+    };
+    if let Some(hex) = hex {
+        return decode_hex(hex);
+    }
+    let alphabet = match text.contains(['-', '_']) {
+        true => &base64::alphabet::URL_SAFE,
+        false => &base64::alphabet::STANDARD,
+    };
+    let config =
+        GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+    GeneralPurpose::new(alphabet, config)
+        .decode(&text)
+        .map_err(|err| ConvertError::DecodeError(format!("invalid base64: {err}")))
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>, ConvertError> {
+    let bytes = bincode2proof_core::hex_bytes(hex).map_err(|c| {
+        ConvertError::DecodeError(format!("invalid hex: unexpected character {c:?}"))
+    })?;
+    if !hex.len().is_multiple_of(2) {
+        return Err(ConvertError::DecodeError(
+            "invalid hex: odd number of digits".to_string(),
+        ));
+    }
+    Ok(bytes.collect())
+}
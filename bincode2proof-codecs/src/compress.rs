@@ -0,0 +1,171 @@
+//! Gzip, zstd and brotli compression of encoded receipts, for archiving and transferring STARK and
+//! composite receipts, which typically compress 3-5x.
+
+use core::str::FromStr;
+use std::io::{Read, Write};
+
+use bincode2proof_core::{limits, limits::LimitedField, ConvertError};
+
+/// Brotli quality level, trading compression ratio for speed, from 0 to 11.
+const BROTLI_QUALITY: u32 = 9;
+
+/// Brotli window size, as the base-2 logarithm of its length in bytes.
+const BROTLI_WINDOW: u32 = 22;
+
+/// Compression codec of an encoded receipt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    Gzip,
+    Zstd,
+    Brotli,
+}
+
+impl Compression {
+    /// Name of this codec, as accepted by [Compression::from_str].
+    pub fn name(&self) -> &'static str {
+        match self {
+            Compression::Gzip => "gzip",
+            Compression::Zstd => "zstd",
+            Compression::Brotli => "brotli",
+        }
+    }
+}
+
+/// Leading bytes of a gzip member.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Leading bytes of a zstd frame.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+impl Compression {
+    /// Codec of `bytes` recognized by its magic bytes. Brotli streams have none, so they are
+    /// never detected.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&GZIP_MAGIC) {
+            Some(Compression::Gzip)
+        } else if bytes.starts_with(&ZSTD_MAGIC) {
+            Some(Compression::Zstd)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Compression {
+    type Err = ConvertError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_lowercase().as_str() {
+            "gzip" | "gz" => Ok(Compression::Gzip),
+            "zstd" | "zst" => Ok(Compression::Zstd),
+            "brotli" | "br" => Ok(Compression::Brotli),
+            _ => Err(ConvertError::UnknownFormat(name.to_string())),
+        }
+    }
+}
+
+fn compression_error(err: std::io::Error) -> ConvertError {
+    ConvertError::CompressionError(err.to_string())
+}
+
+/// Compress `bytes` with the given codec.
+pub fn compress(bytes: &[u8], compression: Compression) -> Result<Vec<u8>, ConvertError> {
+    match compression {
+        Compression::Gzip => {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(bytes).map_err(compression_error)?;
+            encoder.finish().map_err(compression_error)
+        }
+        Compression::Zstd => Ok(ruzstd::encoding::compress_to_vec(
+            bytes,
+            ruzstd::encoding::CompressionLevel::Fastest,
+        )),
+        Compression::Brotli => {
+            let mut compressed = Vec::new();
+            {
+                let mut encoder = brotli::CompressorWriter::new(
+                    &mut compressed,
+                    4096,
+                    BROTLI_QUALITY,
+                    BROTLI_WINDOW,
+                );
+                encoder.write_all(bytes).map_err(compression_error)?;
+            }
+            Ok(compressed)
+        }
+    }
+}
+
+/// Read `decoder` to the end, failing with [ConvertError::LimitExceeded] once the output exceeds
+/// the active [DecodeLimits](bincode2proof_core::limits::DecodeLimits) cap on encoded bytes.
+fn read_capped(decoder: impl Read) -> Result<Vec<u8>, ConvertError> {
+    let limit = limits::limit(LimitedField::Encoded);
+    let mut decompressed = Vec::new();
+    decoder
+        .take(limit as u64 + 1)
+        .read_to_end(&mut decompressed)
+        .map_err(compression_error)?;
+    if decompressed.len() > limit {
+        return Err(ConvertError::LimitExceeded {
+            field: LimitedField::Encoded,
+            limit,
+        });
+    }
+    Ok(decompressed)
+}
+
+/// Decompress `bytes` compressed with the given codec, up to the encoded length cap of the
+/// active [DecodeLimits](bincode2proof_core::limits::DecodeLimits).
+pub fn decompress(bytes: &[u8], compression: Compression) -> Result<Vec<u8>, ConvertError> {
+    match compression {
+        Compression::Gzip => read_capped(flate2::read::GzDecoder::new(bytes)),
+        Compression::Zstd => read_capped(
+            ruzstd::decoding::StreamingDecoder::new(bytes)
+                .map_err(|err| ConvertError::CompressionError(err.to_string()))?,
+        ),
+        Compression::Brotli => read_capped(brotli::Decompressor::new(bytes, 4096)),
+    }
+}
+
+/// Decode `bytes` with `decode`, first decompressing them if they start with the magic bytes of
+/// gzip or zstd. Brotli streams have no magic bytes, so they must be decompressed explicitly with
+/// [decompress].
+pub fn decode_decompressed<T>(
+    bytes: &[u8],
+    decode: impl Fn(&[u8]) -> Result<T, ConvertError>,
+) -> Result<T, ConvertError> {
+    match Compression::detect(bytes) {
+        Some(compression) => decode(&decompress(bytes, compression)?),
+        None => decode(bytes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bincode2proof_core::limits::DecodeLimits;
+
+    #[test]
+    fn decompression_is_capped() {
+        let limits = DecodeLimits {
+            max_encoded_bytes: 1024,
+            ..Default::default()
+        };
+        for compression in [Compression::Gzip, Compression::Zstd, Compression::Brotli] {
+            let fits = compress(&[7; 1024], compression).unwrap();
+            assert_eq!(
+                limits.scope(|| decompress(&fits, compression)).unwrap(),
+                [7; 1024]
+            );
+            let bomb = compress(&[7; 1025], compression).unwrap();
+            assert_eq!(
+                limits.scope(|| decompress(&bomb, compression)),
+                Err(ConvertError::LimitExceeded {
+                    field: LimitedField::Encoded,
+                    limit: 1024,
+                })
+            );
+        }
+    }
+}
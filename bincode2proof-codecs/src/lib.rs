@@ -0,0 +1,13 @@
+//! Decoding and encoding of the receipt types of `bincode2proof-core` in the serialization formats
+//! used by provers and middleware: bincode, Borsh, JSON, CBOR, MessagePack, postcard and the risc0
+//! protobuf messages.
+
+pub mod batch;
+pub mod codec;
+#[cfg(feature = "compression")]
+pub mod compress;
+pub mod peek;
+#[cfg(feature = "protobuf")]
+pub mod proto;
+pub mod stream;
+mod trace;
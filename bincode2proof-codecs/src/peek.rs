@@ -0,0 +1,72 @@
+//! Partial decoding of bincode encoded receipts, for callers that only need one field and should
+//! not pay for decoding megabytes of seals.
+
+use bincode2proof_core::{limits, ConvertError, Receipt};
+use serde::de::{self, Deserialize, Deserializer, EnumAccess, Unexpected, Visitor};
+
+use crate::codec::{self, BincodeConfig};
+
+/// Kinds of [InnerReceipt](bincode2proof_core::InnerReceipt), in the order of its variants.
+const INNER_RECEIPT_KINDS: &[&str] = &["Composite", "Succinct", "Groth16", "Fake", "SetInclusion"];
+
+/// The kind of an [InnerReceipt](bincode2proof_core::InnerReceipt), read from its variant tag
+/// without decoding the variant.
+struct Kind(&'static str);
+
+impl<'de> Deserialize<'de> for Kind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_enum("InnerReceipt", INNER_RECEIPT_KINDS, KindVisitor)
+    }
+}
+
+struct KindVisitor;
+
+impl<'de> Visitor<'de> for KindVisitor {
+    type Value = Kind;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("an InnerReceipt variant")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Kind, A::Error> {
+        // The variant itself is left unread, which bincode allows.
+        let (index, _) = data.variant::<u32>()?;
+        INNER_RECEIPT_KINDS
+            .get(index as usize)
+            .map(|kind| Kind(kind))
+            .ok_or_else(|| {
+                de::Error::invalid_value(Unexpected::Unsigned(index.into()), &"a receipt kind")
+            })
+    }
+}
+
+/// The leading `inner` field of a [Receipt](bincode2proof_core::Receipt), reduced to its kind.
+#[derive(serde::Deserialize)]
+struct KindOnly {
+    inner: Kind,
+}
+
+/// Kind of the [InnerReceipt](bincode2proof_core::InnerReceipt) of a bincode encoded receipt, e.g.
+/// "Groth16", read from the first bytes of the input only.
+pub fn peek_kind(bytes: &[u8]) -> Result<&'static str, ConvertError> {
+    let KindOnly { inner } = BincodeConfig::default().deserialize(bytes)?;
+    Ok(inner.0)
+}
+
+/// Journal of a bincode encoded receipt. The seals are read past without being stored, which
+/// saves most of the allocations when decoding composite receipts.
+pub fn extract_journal(bytes: &[u8]) -> Result<Vec<u8>, ConvertError> {
+    let receipt: Receipt = limits::skipping_seals(|| codec::decode(bytes, codec::Format::Bincode))?;
+    Ok(receipt.journal.bytes)
+}
+
+/// Approximate peak memory, in bytes, of converting a bincode encoded receipt: the input itself
+/// plus the seals, journal, segments and assumptions allocated by decoding it, as given by their
+/// length prefixes. The receipt is walked without allocating the seals.
+pub fn estimate_decode_memory(bytes: &[u8]) -> Result<usize, ConvertError> {
+    let (decoded, allocated) = limits::tallying(|| {
+        limits::skipping_seals(|| codec::decode::<Receipt>(bytes, codec::Format::Bincode))
+    });
+    decoded?;
+    Ok(bytes.len() + allocated)
+}
@@ -0,0 +1,967 @@
+//! Conversion between [Receipt] and the protobuf messages of the risc0 `core.proto` and
+//! `base.proto` schemas, as exchanged by r0vm and Bonsai.
+
+use bincode2proof_core::{
+    limits::{self, LimitedField},
+    Assumption, Assumptions, CompositeReceipt, ConvertError, Digest, ExitCode, FakeReceipt,
+    Groth16Receipt, InnerAssumptionReceipt, InnerReceipt, Input, Journal, MaybePruned, MerkleProof,
+    Output, Receipt, ReceiptClaim, ReceiptMetadata, SegmentReceipt, SuccinctReceipt, SystemState,
+    Unknown,
+};
+use prost::Message;
+
+/// Messages of the risc0 protobuf schema, as generated by prost.
+pub mod pb {
+    pub mod base {
+        #[derive(Clone, Copy, PartialEq, prost::Message)]
+        pub struct CompatVersion {
+            #[prost(uint32, tag = "1")]
+            pub value: u32,
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct Digest {
+            #[prost(uint32, repeated, tag = "1")]
+            pub words: Vec<u32>,
+        }
+
+        #[derive(Clone, Copy, PartialEq, prost::Message)]
+        pub struct ExitCode {
+            #[prost(oneof = "exit_code::Kind", tags = "1, 2, 3, 4")]
+            pub kind: Option<exit_code::Kind>,
+        }
+
+        pub mod exit_code {
+            #[derive(Clone, Copy, PartialEq, prost::Oneof)]
+            pub enum Kind {
+                #[prost(uint32, tag = "1")]
+                Halted(u32),
+                #[prost(uint32, tag = "2")]
+                Paused(u32),
+                #[prost(message, tag = "3")]
+                SystemSplit(()),
+                #[prost(message, tag = "4")]
+                SessionLimit(()),
+            }
+        }
+    }
+
+    pub mod core {
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct Receipt {
+            #[prost(message, optional, tag = "1")]
+            pub version: Option<super::base::CompatVersion>,
+            #[prost(message, optional, tag = "2")]
+            pub inner: Option<InnerReceipt>,
+            #[prost(bytes = "vec", tag = "3")]
+            pub journal: Vec<u8>,
+            #[prost(message, optional, tag = "4")]
+            pub metadata: Option<ReceiptMetadata>,
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct ReceiptMetadata {
+            #[prost(message, optional, tag = "1")]
+            pub verifier_parameters: Option<super::base::Digest>,
+        }
+
+        /// Both [InnerReceipt](bincode2proof_core::InnerReceipt) and
+        /// [InnerAssumptionReceipt](bincode2proof_core::InnerAssumptionReceipt) in the schema.
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct InnerReceipt {
+            #[prost(oneof = "inner_receipt::Kind", tags = "1, 2, 3, 4")]
+            pub kind: Option<inner_receipt::Kind>,
+        }
+
+        pub mod inner_receipt {
+            #[derive(Clone, PartialEq, prost::Oneof)]
+            pub enum Kind {
+                #[prost(message, tag = "1")]
+                Composite(super::CompositeReceipt),
+                #[prost(message, tag = "2")]
+                Succinct(super::SuccinctReceipt),
+                #[prost(message, tag = "3")]
+                Fake(super::FakeReceipt),
+                #[prost(message, tag = "4")]
+                Groth16(super::Groth16Receipt),
+            }
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct CompositeReceipt {
+            #[prost(message, repeated, tag = "1")]
+            pub segments: Vec<SegmentReceipt>,
+            #[prost(message, repeated, tag = "2")]
+            pub assumption_receipts: Vec<InnerReceipt>,
+            #[prost(message, optional, tag = "3")]
+            pub verifier_parameters: Option<super::base::Digest>,
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct SegmentReceipt {
+            #[prost(message, optional, tag = "1")]
+            pub version: Option<super::base::CompatVersion>,
+            #[prost(bytes = "vec", tag = "2")]
+            pub seal: Vec<u8>,
+            #[prost(uint32, tag = "3")]
+            pub index: u32,
+            #[prost(string, tag = "4")]
+            pub hashfn: String,
+            #[prost(message, optional, tag = "5")]
+            pub claim: Option<ReceiptClaim>,
+            #[prost(message, optional, tag = "6")]
+            pub verifier_parameters: Option<super::base::Digest>,
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct SuccinctReceipt {
+            #[prost(message, optional, tag = "1")]
+            pub version: Option<super::base::CompatVersion>,
+            #[prost(bytes = "vec", tag = "2")]
+            pub seal: Vec<u8>,
+            #[prost(message, optional, tag = "3")]
+            pub control_id: Option<super::base::Digest>,
+            #[prost(message, optional, tag = "4")]
+            pub claim: Option<MaybePruned>,
+            #[prost(message, optional, tag = "5")]
+            pub control_inclusion_proof: Option<MerkleProof>,
+            #[prost(string, tag = "6")]
+            pub hashfn: String,
+            #[prost(message, optional, tag = "7")]
+            pub verifier_parameters: Option<super::base::Digest>,
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct MerkleProof {
+            #[prost(uint32, tag = "1")]
+            pub index: u32,
+            #[prost(message, repeated, tag = "2")]
+            pub digests: Vec<super::base::Digest>,
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct Groth16Receipt {
+            #[prost(message, optional, tag = "1")]
+            pub version: Option<super::base::CompatVersion>,
+            #[prost(bytes = "vec", tag = "2")]
+            pub seal: Vec<u8>,
+            #[prost(message, optional, tag = "3")]
+            pub claim: Option<MaybePruned>,
+            #[prost(message, optional, tag = "4")]
+            pub verifier_parameters: Option<super::base::Digest>,
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct ReceiptClaim {
+            #[prost(message, optional, tag = "1")]
+            pub pre: Option<MaybePruned>,
+            #[prost(message, optional, tag = "2")]
+            pub post: Option<MaybePruned>,
+            #[prost(message, optional, tag = "3")]
+            pub exit_code: Option<super::base::ExitCode>,
+            #[prost(message, optional, tag = "4")]
+            pub input: Option<MaybePruned>,
+            #[prost(message, optional, tag = "5")]
+            pub output: Option<MaybePruned>,
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct MaybePruned {
+            #[prost(oneof = "maybe_pruned::Kind", tags = "1, 2")]
+            pub kind: Option<maybe_pruned::Kind>,
+        }
+
+        pub mod maybe_pruned {
+            #[derive(Clone, PartialEq, prost::Oneof)]
+            pub enum Kind {
+                /// Protobuf encoded bytes of the inner value.
+                #[prost(bytes, tag = "1")]
+                Value(Vec<u8>),
+                #[prost(message, tag = "2")]
+                Pruned(super::super::base::Digest),
+            }
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct SystemState {
+            #[prost(uint32, tag = "1")]
+            pub pc: u32,
+            #[prost(message, optional, tag = "2")]
+            pub merkle_root: Option<super::base::Digest>,
+        }
+
+        #[derive(Clone, Copy, PartialEq, prost::Message)]
+        pub struct Input {}
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct Output {
+            #[prost(message, optional, tag = "1")]
+            pub journal: Option<MaybePruned>,
+            #[prost(message, optional, tag = "2")]
+            pub assumptions: Option<MaybePruned>,
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct Assumption {
+            #[prost(message, optional, tag = "1")]
+            pub claim: Option<super::base::Digest>,
+            #[prost(message, optional, tag = "2")]
+            pub control_root: Option<super::base::Digest>,
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct Assumptions {
+            #[prost(message, repeated, tag = "1")]
+            pub inner: Vec<MaybePruned>,
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct FakeReceipt {
+            #[prost(message, optional, tag = "1")]
+            pub claim: Option<MaybePruned>,
+        }
+    }
+}
+
+/// Highest message versions understood by this crate.
+mod ver {
+    use super::pb::base::CompatVersion;
+
+    pub const RECEIPT: CompatVersion = CompatVersion { value: 1 };
+    pub const SEGMENT_RECEIPT: CompatVersion = CompatVersion { value: 1 };
+    pub const SUCCINCT_RECEIPT: CompatVersion = CompatVersion { value: 1 };
+    pub const GROTH16_RECEIPT: CompatVersion = CompatVersion { value: 1 };
+}
+
+fn required<T>(value: Option<T>, field: &'static str) -> Result<T, ConvertError> {
+    value.ok_or(ConvertError::MalformedReceipt(field))
+}
+
+fn check_version(
+    version: Option<pb::base::CompatVersion>,
+    supported: pb::base::CompatVersion,
+    field: &'static str,
+) -> Result<(), ConvertError> {
+    let version = required(version, field)?.value;
+    if version > supported.value {
+        return Err(ConvertError::DecodeError(format!(
+            "incompatible {field} {version}, expected at most {}",
+            supported.value
+        )));
+    }
+    Ok(())
+}
+
+fn decode_message<M: Message + Default>(bytes: &[u8]) -> Result<M, ConvertError> {
+    M::decode(bytes).map_err(|err| ConvertError::DecodeError(err.to_string()))
+}
+
+fn seal_from_bytes(seal: &[u8]) -> Result<Vec<u32>, ConvertError> {
+    limits::check_len(LimitedField::Seal, seal.len() / 4)?;
+    Ok(seal
+        .chunks_exact(4)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+        .collect())
+}
+
+impl From<Digest> for pb::base::Digest {
+    fn from(value: Digest) -> Self {
+        Self {
+            words: value.as_words().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::base::Digest> for Digest {
+    type Error = ConvertError;
+
+    fn try_from(value: pb::base::Digest) -> Result<Self, Self::Error> {
+        let words = value
+            .words
+            .try_into()
+            .map_err(|_| ConvertError::MalformedReceipt("digest must have 8 words"))?;
+        Ok(Digest::new(words))
+    }
+}
+
+impl From<ExitCode> for pb::base::ExitCode {
+    fn from(value: ExitCode) -> Self {
+        use pb::base::exit_code::Kind;
+
+        let kind = match value {
+            ExitCode::Halted(code) => Kind::Halted(code),
+            ExitCode::Paused(code) => Kind::Paused(code),
+            ExitCode::SystemSplit => Kind::SystemSplit(()),
+            ExitCode::SessionLimit => Kind::SessionLimit(()),
+        };
+        Self { kind: Some(kind) }
+    }
+}
+
+impl TryFrom<pb::base::ExitCode> for ExitCode {
+    type Error = ConvertError;
+
+    fn try_from(value: pb::base::ExitCode) -> Result<Self, Self::Error> {
+        use pb::base::exit_code::Kind;
+
+        Ok(match required(value.kind, "ExitCode.kind")? {
+            Kind::Halted(code) => ExitCode::Halted(code),
+            Kind::Paused(code) => ExitCode::Paused(code),
+            Kind::SystemSplit(()) => ExitCode::SystemSplit,
+            Kind::SessionLimit(()) => ExitCode::SessionLimit,
+        })
+    }
+}
+
+/// A type held by a [MaybePruned], whose value is stored as the protobuf encoding of its message.
+trait Pruneable: Sized + Clone + serde::Serialize {
+    type Message: Message + Default;
+
+    fn to_message(self) -> Result<Self::Message, ConvertError>;
+
+    fn from_message(message: Self::Message) -> Result<Self, ConvertError>;
+}
+
+fn maybe_pruned_to_pb<T: Pruneable>(
+    value: MaybePruned<T>,
+) -> Result<pb::core::MaybePruned, ConvertError> {
+    use pb::core::maybe_pruned::Kind;
+
+    let kind = match value {
+        MaybePruned::Value(value) => Kind::Value(value.to_message()?.encode_to_vec()),
+        MaybePruned::Pruned(digest) => Kind::Pruned(digest.into()),
+    };
+    Ok(pb::core::MaybePruned { kind: Some(kind) })
+}
+
+fn maybe_pruned_from_pb<T: Pruneable>(
+    value: Option<pb::core::MaybePruned>,
+    field: &'static str,
+) -> Result<MaybePruned<T>, ConvertError> {
+    use pb::core::maybe_pruned::Kind;
+
+    Ok(match required(required(value, field)?.kind, field)? {
+        Kind::Value(bytes) => MaybePruned::Value(T::from_message(decode_message(&bytes)?)?),
+        Kind::Pruned(digest) => MaybePruned::Pruned(digest.try_into()?),
+    })
+}
+
+impl Pruneable for Vec<u8> {
+    type Message = Vec<u8>;
+
+    fn to_message(self) -> Result<Self::Message, ConvertError> {
+        Ok(self)
+    }
+
+    fn from_message(message: Self::Message) -> Result<Self, ConvertError> {
+        limits::check_len(LimitedField::Journal, message.len())?;
+        Ok(message)
+    }
+}
+
+impl Pruneable for Unknown {
+    type Message = ();
+
+    fn to_message(self) -> Result<Self::Message, ConvertError> {
+        match self { /* unreachable */ }
+    }
+
+    fn from_message(_message: Self::Message) -> Result<Self, ConvertError> {
+        Err(ConvertError::MalformedReceipt(
+            "claim of an assumption receipt must be pruned",
+        ))
+    }
+}
+
+impl Pruneable for Input {
+    type Message = pb::core::Input;
+
+    fn to_message(self) -> Result<Self::Message, ConvertError> {
+        // Input is uninhabited, so no value can reach this.
+        Err(ConvertError::MalformedReceipt("Input"))
+    }
+
+    fn from_message(_message: Self::Message) -> Result<Self, ConvertError> {
+        Err(ConvertError::MalformedReceipt("Input"))
+    }
+}
+
+impl Pruneable for SystemState {
+    type Message = pb::core::SystemState;
+
+    fn to_message(self) -> Result<Self::Message, ConvertError> {
+        Ok(pb::core::SystemState {
+            pc: self.pc,
+            merkle_root: Some(self.merkle_root.into()),
+        })
+    }
+
+    fn from_message(message: Self::Message) -> Result<Self, ConvertError> {
+        Ok(SystemState {
+            pc: message.pc,
+            merkle_root: required(message.merkle_root, "SystemState.merkle_root")?.try_into()?,
+        })
+    }
+}
+
+impl Pruneable for Assumption {
+    type Message = pb::core::Assumption;
+
+    fn to_message(self) -> Result<Self::Message, ConvertError> {
+        Ok(pb::core::Assumption {
+            claim: Some(self.claim.into()),
+            control_root: Some(self.control_root.into()),
+        })
+    }
+
+    fn from_message(message: Self::Message) -> Result<Self, ConvertError> {
+        Ok(Assumption {
+            claim: required(message.claim, "Assumption.claim")?.try_into()?,
+            control_root: required(message.control_root, "Assumption.control_root")?.try_into()?,
+        })
+    }
+}
+
+impl Pruneable for Assumptions {
+    type Message = pb::core::Assumptions;
+
+    fn to_message(self) -> Result<Self::Message, ConvertError> {
+        Ok(pb::core::Assumptions {
+            inner: self
+                .0
+                .into_iter()
+                .map(maybe_pruned_to_pb)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn from_message(message: Self::Message) -> Result<Self, ConvertError> {
+        limits::check_len(LimitedField::Assumptions, message.inner.len())?;
+        Ok(Assumptions(
+            message
+                .inner
+                .into_iter()
+                .map(|assumption| maybe_pruned_from_pb(Some(assumption), "Assumptions.inner"))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl Pruneable for Output {
+    type Message = pb::core::Output;
+
+    fn to_message(self) -> Result<Self::Message, ConvertError> {
+        Ok(pb::core::Output {
+            journal: Some(maybe_pruned_to_pb(self.journal)?),
+            assumptions: Some(maybe_pruned_to_pb(self.assumptions)?),
+        })
+    }
+
+    fn from_message(message: Self::Message) -> Result<Self, ConvertError> {
+        Ok(Output {
+            journal: maybe_pruned_from_pb(message.journal, "Output.journal")?,
+            assumptions: maybe_pruned_from_pb(message.assumptions, "Output.assumptions")?,
+        })
+    }
+}
+
+/// Translate `MaybePruned<Option<T>>` to the `Option<MaybePruned<T>>` of the schema.
+fn optional_to_pb<T: Pruneable>(
+    value: MaybePruned<Option<T>>,
+) -> Result<Option<pb::core::MaybePruned>, ConvertError> {
+    match value {
+        MaybePruned::Value(None) => Ok(None),
+        MaybePruned::Value(Some(value)) => maybe_pruned_to_pb(MaybePruned::Value(value)).map(Some),
+        MaybePruned::Pruned(digest) => {
+            maybe_pruned_to_pb(MaybePruned::<T>::Pruned(digest)).map(Some)
+        }
+    }
+}
+
+/// Translate the `Option<MaybePruned<T>>` of the schema to `MaybePruned<Option<T>>`.
+fn optional_from_pb<T: Pruneable>(
+    value: Option<pb::core::MaybePruned>,
+    field: &'static str,
+) -> Result<MaybePruned<Option<T>>, ConvertError> {
+    if value.is_none() {
+        return Ok(MaybePruned::Value(None));
+    }
+    Ok(match maybe_pruned_from_pb::<T>(value, field)? {
+        MaybePruned::Value(value) => MaybePruned::Value(Some(value)),
+        MaybePruned::Pruned(digest) => MaybePruned::Pruned(digest),
+    })
+}
+
+impl Pruneable for ReceiptClaim {
+    type Message = pb::core::ReceiptClaim;
+
+    fn to_message(self) -> Result<Self::Message, ConvertError> {
+        Ok(pb::core::ReceiptClaim {
+            pre: Some(maybe_pruned_to_pb(self.pre)?),
+            post: Some(maybe_pruned_to_pb(self.post)?),
+            exit_code: Some(self.exit_code.into()),
+            input: optional_to_pb(self.input)?,
+            output: optional_to_pb(self.output)?,
+        })
+    }
+
+    fn from_message(message: Self::Message) -> Result<Self, ConvertError> {
+        Ok(ReceiptClaim {
+            pre: maybe_pruned_from_pb(message.pre, "ReceiptClaim.pre")?,
+            post: maybe_pruned_from_pb(message.post, "ReceiptClaim.post")?,
+            exit_code: required(message.exit_code, "ReceiptClaim.exit_code")?.try_into()?,
+            input: optional_from_pb(message.input, "ReceiptClaim.input")?,
+            output: optional_from_pb(message.output, "ReceiptClaim.output")?,
+        })
+    }
+}
+
+impl From<MerkleProof> for pb::core::MerkleProof {
+    fn from(value: MerkleProof) -> Self {
+        Self {
+            index: value.index,
+            digests: value.digests.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::core::MerkleProof> for MerkleProof {
+    type Error = ConvertError;
+
+    fn try_from(value: pb::core::MerkleProof) -> Result<Self, Self::Error> {
+        Ok(MerkleProof::new(
+            value.index,
+            value
+                .digests
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl TryFrom<SegmentReceipt> for pb::core::SegmentReceipt {
+    type Error = ConvertError;
+
+    fn try_from(value: SegmentReceipt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: Some(ver::SEGMENT_RECEIPT),
+            seal: value.get_seal_bytes(),
+            index: value.index,
+            hashfn: value.hashfn,
+            claim: Some(value.claim.to_message()?),
+            verifier_parameters: Some(value.verifier_parameters.into()),
+        })
+    }
+}
+
+impl TryFrom<pb::core::SegmentReceipt> for SegmentReceipt {
+    type Error = ConvertError;
+
+    fn try_from(value: pb::core::SegmentReceipt) -> Result<Self, Self::Error> {
+        // Segment receipts written before versioning carry no version.
+        if value.version.is_some() {
+            check_version(
+                value.version,
+                ver::SEGMENT_RECEIPT,
+                "SegmentReceipt.version",
+            )?;
+        }
+        Ok(SegmentReceipt::new(
+            seal_from_bytes(&value.seal)?,
+            value.index,
+            value.hashfn,
+            required(
+                value.verifier_parameters,
+                "SegmentReceipt.verifier_parameters",
+            )?
+            .try_into()?,
+            ReceiptClaim::from_message(required(value.claim, "SegmentReceipt.claim")?)?,
+        ))
+    }
+}
+
+impl<Claim> TryFrom<SuccinctReceipt<Claim>> for pb::core::SuccinctReceipt
+where
+    Claim: Pruneable + bincode2proof_core::Digestible + core::fmt::Debug,
+{
+    type Error = ConvertError;
+
+    fn try_from(value: SuccinctReceipt<Claim>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: Some(ver::SUCCINCT_RECEIPT),
+            seal: value.get_seal_bytes(),
+            control_id: Some(value.control_id.into()),
+            claim: Some(maybe_pruned_to_pb(value.claim)?),
+            control_inclusion_proof: Some(value.control_inclusion_proof.into()),
+            hashfn: value.hashfn,
+            verifier_parameters: Some(value.verifier_parameters.into()),
+        })
+    }
+}
+
+impl<Claim> TryFrom<pb::core::SuccinctReceipt> for SuccinctReceipt<Claim>
+where
+    Claim: Pruneable + bincode2proof_core::Digestible + core::fmt::Debug,
+{
+    type Error = ConvertError;
+
+    fn try_from(value: pb::core::SuccinctReceipt) -> Result<Self, Self::Error> {
+        check_version(
+            value.version,
+            ver::SUCCINCT_RECEIPT,
+            "SuccinctReceipt.version",
+        )?;
+        Ok(SuccinctReceipt::new(
+            seal_from_bytes(&value.seal)?,
+            required(value.control_id, "SuccinctReceipt.control_id")?.try_into()?,
+            maybe_pruned_from_pb(value.claim, "SuccinctReceipt.claim")?,
+            value.hashfn,
+            required(
+                value.verifier_parameters,
+                "SuccinctReceipt.verifier_parameters",
+            )?
+            .try_into()?,
+            required(
+                value.control_inclusion_proof,
+                "SuccinctReceipt.control_inclusion_proof",
+            )?
+            .try_into()?,
+        ))
+    }
+}
+
+impl<Claim> TryFrom<Groth16Receipt<Claim>> for pb::core::Groth16Receipt
+where
+    Claim: Pruneable + bincode2proof_core::Digestible + core::fmt::Debug,
+{
+    type Error = ConvertError;
+
+    fn try_from(value: Groth16Receipt<Claim>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: Some(ver::GROTH16_RECEIPT),
+            seal: value.seal,
+            claim: Some(maybe_pruned_to_pb(value.claim)?),
+            verifier_parameters: Some(value.verifier_parameters.into()),
+        })
+    }
+}
+
+impl<Claim> TryFrom<pb::core::Groth16Receipt> for Groth16Receipt<Claim>
+where
+    Claim: Pruneable + bincode2proof_core::Digestible + core::fmt::Debug,
+{
+    type Error = ConvertError;
+
+    fn try_from(value: pb::core::Groth16Receipt) -> Result<Self, Self::Error> {
+        check_version(
+            value.version,
+            ver::GROTH16_RECEIPT,
+            "Groth16Receipt.version",
+        )?;
+        limits::check_len(LimitedField::Seal, value.seal.len().div_ceil(4))?;
+        Ok(Groth16Receipt::new(
+            value.seal,
+            maybe_pruned_from_pb(value.claim, "Groth16Receipt.claim")?,
+            required(
+                value.verifier_parameters,
+                "Groth16Receipt.verifier_parameters",
+            )?
+            .try_into()?,
+        ))
+    }
+}
+
+impl<Claim> TryFrom<FakeReceipt<Claim>> for pb::core::FakeReceipt
+where
+    Claim: Pruneable + bincode2proof_core::Digestible + core::fmt::Debug,
+{
+    type Error = ConvertError;
+
+    fn try_from(value: FakeReceipt<Claim>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            claim: Some(maybe_pruned_to_pb(value.claim)?),
+        })
+    }
+}
+
+impl<Claim> TryFrom<pb::core::FakeReceipt> for FakeReceipt<Claim>
+where
+    Claim: Pruneable + bincode2proof_core::Digestible + core::fmt::Debug,
+{
+    type Error = ConvertError;
+
+    fn try_from(value: pb::core::FakeReceipt) -> Result<Self, Self::Error> {
+        Ok(FakeReceipt::new(maybe_pruned_from_pb(
+            value.claim,
+            "FakeReceipt.claim",
+        )?))
+    }
+}
+
+impl TryFrom<CompositeReceipt> for pb::core::CompositeReceipt {
+    type Error = ConvertError;
+
+    fn try_from(value: CompositeReceipt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            segments: value
+                .segments
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            assumption_receipts: value
+                .assumption_receipts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            verifier_parameters: Some(value.verifier_parameters.into()),
+        })
+    }
+}
+
+impl TryFrom<pb::core::CompositeReceipt> for CompositeReceipt {
+    type Error = ConvertError;
+
+    fn try_from(value: pb::core::CompositeReceipt) -> Result<Self, Self::Error> {
+        limits::check_len(LimitedField::Segments, value.segments.len())?;
+        limits::check_len(LimitedField::Assumptions, value.assumption_receipts.len())?;
+        Ok(CompositeReceipt {
+            segments: value
+                .segments
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            assumption_receipts: value
+                .assumption_receipts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            verifier_parameters: required(
+                value.verifier_parameters,
+                "CompositeReceipt.verifier_parameters",
+            )?
+            .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<InnerReceipt> for pb::core::InnerReceipt {
+    type Error = ConvertError;
+
+    fn try_from(value: InnerReceipt) -> Result<Self, Self::Error> {
+        use pb::core::inner_receipt::Kind;
+
+        let kind = match value {
+            InnerReceipt::Composite(inner) => Kind::Composite(inner.try_into()?),
+            InnerReceipt::Succinct(inner) => Kind::Succinct(inner.try_into()?),
+            InnerReceipt::Groth16(inner) => Kind::Groth16(inner.try_into()?),
+            InnerReceipt::Fake(inner) => Kind::Fake(inner.try_into()?),
+            other => return Err(ConvertError::UnsupportedReceiptKind(other.kind())),
+        };
+        Ok(Self { kind: Some(kind) })
+    }
+}
+
+impl TryFrom<pb::core::InnerReceipt> for InnerReceipt {
+    type Error = ConvertError;
+
+    fn try_from(value: pb::core::InnerReceipt) -> Result<Self, Self::Error> {
+        use pb::core::inner_receipt::Kind;
+
+        Ok(match required(value.kind, "InnerReceipt.kind")? {
+            Kind::Composite(inner) => InnerReceipt::Composite(inner.try_into()?),
+            Kind::Succinct(inner) => InnerReceipt::Succinct(inner.try_into()?),
+            Kind::Groth16(inner) => InnerReceipt::Groth16(inner.try_into()?),
+            Kind::Fake(inner) => InnerReceipt::Fake(inner.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<InnerAssumptionReceipt> for pb::core::InnerReceipt {
+    type Error = ConvertError;
+
+    fn try_from(value: InnerAssumptionReceipt) -> Result<Self, Self::Error> {
+        use pb::core::inner_receipt::Kind;
+
+        let kind = match value {
+            InnerAssumptionReceipt::Composite(inner) => Kind::Composite(inner.try_into()?),
+            InnerAssumptionReceipt::Succinct(inner) => Kind::Succinct(inner.try_into()?),
+            InnerAssumptionReceipt::Groth16(inner) => Kind::Groth16(inner.try_into()?),
+            InnerAssumptionReceipt::Fake(inner) => Kind::Fake(inner.try_into()?),
+            other => return Err(ConvertError::UnsupportedReceiptKind(other.kind())),
+        };
+        Ok(Self { kind: Some(kind) })
+    }
+}
+
+impl TryFrom<pb::core::InnerReceipt> for InnerAssumptionReceipt {
+    type Error = ConvertError;
+
+    fn try_from(value: pb::core::InnerReceipt) -> Result<Self, Self::Error> {
+        use pb::core::inner_receipt::Kind;
+
+        Ok(match required(value.kind, "InnerReceipt.kind")? {
+            Kind::Composite(inner) => InnerAssumptionReceipt::Composite(inner.try_into()?),
+            Kind::Succinct(inner) => InnerAssumptionReceipt::Succinct(inner.try_into()?),
+            Kind::Groth16(inner) => InnerAssumptionReceipt::Groth16(inner.try_into()?),
+            Kind::Fake(inner) => InnerAssumptionReceipt::Fake(inner.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<Receipt> for pb::core::Receipt {
+    type Error = ConvertError;
+
+    fn try_from(value: Receipt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: Some(ver::RECEIPT),
+            inner: Some(value.inner.try_into()?),
+            journal: value.journal.bytes,
+            metadata: Some(pb::core::ReceiptMetadata {
+                verifier_parameters: Some(value.metadata.verifier_parameters.into()),
+            }),
+        })
+    }
+}
+
+impl TryFrom<pb::core::Receipt> for Receipt {
+    type Error = ConvertError;
+
+    fn try_from(value: pb::core::Receipt) -> Result<Self, Self::Error> {
+        check_version(value.version, ver::RECEIPT, "Receipt.version")?;
+        limits::check_len(LimitedField::Journal, value.journal.len())?;
+        let metadata = required(value.metadata, "Receipt.metadata")?;
+        Ok(Receipt {
+            inner: required(value.inner, "Receipt.inner")?.try_into()?,
+            journal: Journal {
+                bytes: value.journal,
+            },
+            metadata: ReceiptMetadata::new(
+                required(
+                    metadata.verifier_parameters,
+                    "ReceiptMetadata.verifier_parameters",
+                )?
+                .try_into()?,
+            ),
+        })
+    }
+}
+
+/// Decode a protobuf encoded `core.Receipt` message, within the caps of the active
+/// [DecodeLimits](limits::DecodeLimits).
+pub fn decode_receipt(bytes: &[u8]) -> Result<Receipt, ConvertError> {
+    limits::check_len(LimitedField::Encoded, bytes.len())?;
+    decode_message::<pb::core::Receipt>(bytes)?.try_into()
+}
+
+/// Encode a [Receipt] as a protobuf `core.Receipt` message.
+pub fn encode_receipt(receipt: Receipt) -> Result<Vec<u8>, ConvertError> {
+    let message: pb::core::Receipt = receipt.try_into()?;
+    Ok(message.encode_to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bincode2proof_core::{limits::DecodeLimits, Digestible, sha};
+
+    use crate::codec;
+
+    fn groth16_receipt(journal: &[u8]) -> Receipt {
+        let claim = ReceiptClaim::ok(Digest::new([1; 8]), journal.to_vec());
+        Receipt {
+            inner: InnerReceipt::Groth16(Groth16Receipt::new(
+                vec![7; 256],
+                MaybePruned::Value(claim),
+                Digest::new([2; 8]),
+            )),
+            journal: Journal {
+                bytes: journal.to_vec(),
+            },
+            metadata: ReceiptMetadata::new(Digest::new([2; 8])),
+        }
+    }
+
+    fn composite_receipt(segments: u32) -> Receipt {
+        let segment = |index| {
+            SegmentReceipt::new(
+                vec![index; 16],
+                index,
+                "poseidon2".to_string(),
+                Digest::new([3; 8]),
+                ReceiptClaim::ok(Digest::new([index; 8]), Vec::new()),
+            )
+        };
+        Receipt {
+            inner: InnerReceipt::Composite(CompositeReceipt {
+                segments: (0..segments).map(segment).collect(),
+                assumption_receipts: vec![InnerAssumptionReceipt::Fake(FakeReceipt::new(
+                    MaybePruned::Pruned(Digest::new([4; 8])),
+                ))],
+                verifier_parameters: Digest::new([5; 8]),
+            }),
+            journal: Journal { bytes: Vec::new() },
+            metadata: ReceiptMetadata::new(Digest::new([5; 8])),
+        }
+    }
+
+    fn assert_round_trip(receipt: Receipt) {
+        let encoded = encode_receipt(receipt.clone()).unwrap();
+        let decoded = decode_receipt(&encoded).unwrap();
+        assert_eq!(
+            codec::encode(&decoded, codec::Format::Bincode).unwrap(),
+            codec::encode(&receipt, codec::Format::Bincode).unwrap()
+        );
+        assert_eq!(
+            decoded.inner.claim().unwrap().digest::<sha::Impl>(),
+            receipt.inner.claim().unwrap().digest::<sha::Impl>()
+        );
+        assert_eq!(encode_receipt(decoded).unwrap(), encoded);
+    }
+
+    #[test]
+    fn groth16_round_trip() {
+        assert_round_trip(groth16_receipt(b"journal"));
+    }
+
+    #[test]
+    fn composite_round_trip() {
+        assert_round_trip(composite_receipt(3));
+    }
+
+    #[test]
+    fn decode_applies_limits() {
+        let limits = DecodeLimits {
+            max_journal_bytes: 4,
+            max_segments: 2,
+            ..Default::default()
+        };
+        let encoded = encode_receipt(groth16_receipt(b"journal")).unwrap();
+        assert_eq!(
+            limits.scope(|| decode_receipt(&encoded)).unwrap_err(),
+            ConvertError::LimitExceeded {
+                field: LimitedField::Journal,
+                limit: 4,
+            }
+        );
+        let encoded = encode_receipt(composite_receipt(3)).unwrap();
+        assert_eq!(
+            limits.scope(|| decode_receipt(&encoded)).unwrap_err(),
+            ConvertError::LimitExceeded {
+                field: LimitedField::Segments,
+                limit: 2,
+            }
+        );
+        let limits = DecodeLimits {
+            max_encoded_bytes: encoded.len() as u32 - 1,
+            ..Default::default()
+        };
+        assert!(matches!(
+            limits.scope(|| decode_receipt(&encoded)),
+            Err(ConvertError::LimitExceeded {
+                field: LimitedField::Encoded,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,110 @@
+//! Decoding of receipts arriving in chunks, e.g. over a WebSocket or a `fetch` body stream.
+
+#[cfg(feature = "wasm")]
+use bincode2proof_core::ProofData;
+use bincode2proof_core::{limits, limits::LimitedField, ConvertError, Receipt};
+#[cfg(feature = "wasm")]
+use js_sys::Uint8Array;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::codec;
+
+/// Accumulates the chunks of a bincode encoded [Receipt] in wasm memory and decodes it once all
+/// chunks are received, so that they need not be concatenated in JavaScript first.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Debug, Default)]
+pub struct StreamingDecoder {
+    buffer: Vec<u8>,
+}
+
+impl StreamingDecoder {
+    /// Append the next chunk of the encoded receipt, failing if the receipt would exceed the
+    /// active [DecodeLimits](limits::DecodeLimits) cap on encoded bytes.
+    pub fn push(&mut self, chunk: &[u8]) -> Result<(), ConvertError> {
+        limits::check_len(LimitedField::Encoded, self.buffer.len() + chunk.len())?;
+        self.buffer.extend_from_slice(chunk);
+        Ok(())
+    }
+
+    /// Append a chunk held in JavaScript memory, copying it once into the buffer.
+    #[cfg(feature = "wasm")]
+    fn push_array(&mut self, chunk: &Uint8Array) -> Result<(), ConvertError> {
+        let start = self.buffer.len();
+        limits::check_len(LimitedField::Encoded, start + chunk.length() as usize)?;
+        self.buffer.resize(start + chunk.length() as usize, 0);
+        chunk.copy_to(&mut self.buffer[start..]);
+        Ok(())
+    }
+
+    /// Decode the receipt from the chunks received so far, decompressing them first if they are
+    /// compressed, as [codec::decode_compressed].
+    pub fn finish(self) -> Result<Receipt, ConvertError> {
+        codec::decode_compressed(&self.buffer, codec::Format::Bincode)
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl StreamingDecoder {
+    /// Start a decoder, reserving `expected_len` bytes up front if the length of the receipt is
+    /// known, e.g. from a `Content-Length` header. The reservation is capped at the active
+    /// [DecodeLimits](limits::DecodeLimits) cap on encoded bytes.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(expected_len: Option<usize>) -> StreamingDecoder {
+        let capacity = expected_len
+            .unwrap_or_default()
+            .min(limits::limit(LimitedField::Encoded));
+        Self {
+            buffer: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Append the next chunk of the encoded receipt.
+    #[cfg(feature = "wasm")]
+    #[wasm_bindgen(js_name = push)]
+    pub fn push_js(&mut self, chunk: &Uint8Array) -> Result<(), JsError> {
+        Ok(self.push_array(chunk)?)
+    }
+
+    /// Number of bytes received so far.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn bytes_received(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Decode the receipt and encode its seal and journal for on-chain verification. The decoder
+    /// cannot be used afterwards.
+    #[cfg(feature = "wasm")]
+    #[wasm_bindgen(js_name = finish)]
+    pub fn finish_js(self) -> Result<ProofData, JsError> {
+        Ok(ProofData::try_from(self.finish()?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bincode2proof_core::limits::DecodeLimits;
+
+    #[test]
+    fn push_is_capped() {
+        let limits = DecodeLimits {
+            max_encoded_bytes: 1024,
+            ..Default::default()
+        };
+        limits.scope(|| {
+            let mut decoder = StreamingDecoder::new(Some(usize::MAX));
+            assert!(decoder.buffer.capacity() <= 1024);
+            decoder.push(&[7; 1000]).unwrap();
+            decoder.push(&[7; 24]).unwrap();
+            assert_eq!(
+                decoder.push(&[7]),
+                Err(ConvertError::LimitExceeded {
+                    field: LimitedField::Encoded,
+                    limit: 1024,
+                })
+            );
+            assert_eq!(decoder.bytes_received(), 1024);
+        });
+    }
+}
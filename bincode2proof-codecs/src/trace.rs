@@ -0,0 +1,439 @@
+//! Tracking of the field being decoded by a serde [Deserializer], so that errors in formats
+//! without field names, such as bincode, can report where in a
+//! [Receipt](bincode2proof_core::Receipt) the input went wrong.
+
+use core::{
+    any::type_name,
+    cell::{Cell, RefCell},
+    fmt,
+};
+
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+
+enum Segment {
+    Field(&'static str),
+    Index(usize),
+    Variant(&'static str),
+}
+
+thread_local! {
+    static PATH: RefCell<Vec<Segment>> = const { RefCell::new(Vec::new()) };
+    static FAILURE: RefCell<Option<(String, &'static str)>> = const { RefCell::new(None) };
+    static IDENTIFIER: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Location of a decoding failure.
+pub(crate) struct Failure<E> {
+    pub error: E,
+    /// Path of the innermost field being decoded, e.g. `inner.Groth16.seal[88]`.
+    pub path: String,
+    /// Rust type of that field, without module paths.
+    pub expected: String,
+}
+
+/// Deserialize a `T`, tracking the path of the field being decoded.
+pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, Failure<D::Error>>
+where
+    T: de::Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    PATH.with(|path| path.borrow_mut().clear());
+    FAILURE.with(|failure| failure.borrow_mut().take());
+    T::deserialize(Tracer(deserializer)).map_err(|error| {
+        let (path, expected) = FAILURE
+            .with(|failure| failure.borrow_mut().take())
+            .unwrap_or_else(|| (String::new(), type_name::<T>()));
+        Failure {
+            error,
+            path,
+            expected: short_type_name(expected),
+        }
+    })
+}
+
+/// Run `decode` inside `segment`, recording the path of the innermost failure.
+fn within<T, E>(
+    segment: Segment,
+    expected: &'static str,
+    decode: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    PATH.with(|path| path.borrow_mut().push(segment));
+    let result = decode();
+    PATH.with(|path| {
+        if result.is_err() {
+            FAILURE.with(|failure| {
+                failure
+                    .borrow_mut()
+                    .get_or_insert_with(|| (render(&path.borrow()), expected));
+            });
+        }
+        path.borrow_mut().pop();
+    });
+    result
+}
+
+fn render(path: &[Segment]) -> String {
+    let mut out = String::new();
+    for segment in path {
+        match segment {
+            Segment::Field(name) | Segment::Variant(name) => {
+                if !out.is_empty() {
+                    out.push('.');
+                }
+                out.push_str(name);
+            }
+            Segment::Index(index) => out.push_str(&format!("[{index}]")),
+        }
+    }
+    out
+}
+
+/// Strip the module paths from a type name, e.g. `alloc::vec::Vec<u8>` becomes `Vec<u8>`.
+pub(crate) fn short_type_name(name: &str) -> String {
+    let mut out = String::new();
+    let mut token = String::new();
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            token.clear();
+        } else if c.is_alphanumeric() || c == '_' {
+            token.push(c);
+        } else {
+            out.push_str(&token);
+            token.clear();
+            out.push(c);
+        }
+    }
+    out.push_str(&token);
+    out
+}
+
+struct Tracer<D>(D);
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*))*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error> {
+                self.0.$method($($arg,)* TraceVisitor::new(visitor))
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Tracer<D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any()
+        deserialize_bool()
+        deserialize_i8()
+        deserialize_i16()
+        deserialize_i32()
+        deserialize_i64()
+        deserialize_i128()
+        deserialize_u8()
+        deserialize_u16()
+        deserialize_u32()
+        deserialize_u64()
+        deserialize_u128()
+        deserialize_f32()
+        deserialize_f64()
+        deserialize_char()
+        deserialize_str()
+        deserialize_string()
+        deserialize_bytes()
+        deserialize_byte_buf()
+        deserialize_option()
+        deserialize_unit()
+        deserialize_unit_struct(name: &'static str)
+        deserialize_newtype_struct(name: &'static str)
+        deserialize_seq()
+        deserialize_tuple(len: usize)
+        deserialize_tuple_struct(name: &'static str, len: usize)
+        deserialize_map()
+        deserialize_ignored_any()
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let visitor = TraceVisitor {
+            fields: Some(fields),
+            ..TraceVisitor::new(visitor)
+        };
+        self.0.deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let visitor = TraceVisitor {
+            variants: Some(variants),
+            ..TraceVisitor::new(visitor)
+        };
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, D::Error> {
+        IDENTIFIER.with(|identifier| identifier.set(None));
+        let visitor = TraceVisitor {
+            identifier: true,
+            ..TraceVisitor::new(visitor)
+        };
+        self.0.deserialize_identifier(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+struct TraceSeed<S>(S);
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for TraceSeed<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        self.0.deserialize(Tracer(deserializer))
+    }
+}
+
+struct TraceVisitor<V> {
+    visitor: V,
+    fields: Option<&'static [&'static str]>,
+    variants: Option<&'static [&'static str]>,
+    identifier: bool,
+}
+
+impl<V> TraceVisitor<V> {
+    fn new(visitor: V) -> Self {
+        TraceVisitor {
+            visitor,
+            fields: None,
+            variants: None,
+            identifier: false,
+        }
+    }
+
+    fn identify(&self, index: u64) {
+        if self.identifier {
+            IDENTIFIER.with(|identifier| identifier.set(Some(index)));
+        }
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty))*) => {
+        $(
+            fn $method<E: de::Error>(self, value: $ty) -> Result<V::Value, E> {
+                self.visitor.$method(value)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for TraceVisitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.visitor.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool)
+        visit_i8(i8)
+        visit_i16(i16)
+        visit_i32(i32)
+        visit_i64(i64)
+        visit_i128(i128)
+        visit_u8(u8)
+        visit_u16(u16)
+        visit_u128(u128)
+        visit_f32(f32)
+        visit_f64(f64)
+        visit_char(char)
+        visit_str(&str)
+        visit_borrowed_str(&'de str)
+        visit_string(String)
+        visit_bytes(&[u8])
+        visit_borrowed_bytes(&'de [u8])
+        visit_byte_buf(Vec<u8>)
+    }
+
+    fn visit_u32<E: de::Error>(self, value: u32) -> Result<V::Value, E> {
+        self.identify(value.into());
+        self.visitor.visit_u32(value)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<V::Value, E> {
+        self.identify(value);
+        self.visitor.visit_u64(value)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<V::Value, E> {
+        self.visitor.visit_none()
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<V::Value, E> {
+        self.visitor.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<V::Value, D::Error> {
+        self.visitor.visit_some(Tracer(deserializer))
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<V::Value, D::Error> {
+        self.visitor.visit_newtype_struct(Tracer(deserializer))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<V::Value, A::Error> {
+        self.visitor.visit_seq(TraceSeq {
+            seq,
+            fields: self.fields,
+            index: 0,
+        })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<V::Value, A::Error> {
+        self.visitor.visit_map(TraceMap(map))
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<V::Value, A::Error> {
+        self.visitor.visit_enum(TraceEnum {
+            data,
+            variants: self.variants.unwrap_or_default(),
+        })
+    }
+}
+
+struct TraceSeq<A> {
+    seq: A,
+    fields: Option<&'static [&'static str]>,
+    index: usize,
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for TraceSeq<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, A::Error> {
+        let segment = match self.fields.and_then(|fields| fields.get(self.index)) {
+            Some(field) => Segment::Field(field),
+            None => Segment::Index(self.index),
+        };
+        self.index += 1;
+        within(segment, type_name::<S::Value>(), || {
+            self.seq.next_element_seed(TraceSeed(seed))
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.seq.size_hint()
+    }
+}
+
+struct TraceMap<A>(A);
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for TraceMap<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        self.0.next_key_seed(TraceSeed(seed))
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, A::Error> {
+        self.0.next_value_seed(TraceSeed(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+struct TraceEnum<A> {
+    data: A,
+    variants: &'static [&'static str],
+}
+
+impl<'de, A: EnumAccess<'de>> EnumAccess<'de> for TraceEnum<A> {
+    type Error = A::Error;
+    type Variant = TraceVariant<A::Variant>;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<(S::Value, Self::Variant), A::Error> {
+        let (value, variant) = self.data.variant_seed(TraceSeed(seed))?;
+        let name = IDENTIFIER
+            .with(Cell::take)
+            .and_then(|index| self.variants.get(index as usize).copied());
+        Ok((value, TraceVariant { variant, name }))
+    }
+}
+
+struct TraceVariant<A> {
+    variant: A,
+    name: Option<&'static str>,
+}
+
+fn within_variant<T, E>(
+    name: Option<&'static str>,
+    expected: &'static str,
+    decode: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    match name {
+        Some(name) => within(Segment::Variant(name), expected, decode),
+        None => decode(),
+    }
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for TraceVariant<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.variant.unit_variant()
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(self, seed: S) -> Result<S::Value, A::Error> {
+        within_variant(self.name, type_name::<S::Value>(), || {
+            self.variant.newtype_variant_seed(TraceSeed(seed))
+        })
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, A::Error> {
+        within_variant(self.name, type_name::<V::Value>(), || {
+            self.variant.tuple_variant(len, TraceVisitor::new(visitor))
+        })
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        let visitor = TraceVisitor {
+            fields: Some(fields),
+            ..TraceVisitor::new(visitor)
+        };
+        within_variant(self.name, type_name::<V::Value>(), || {
+            self.variant.struct_variant(fields, visitor)
+        })
+    }
+}
@@ -0,0 +1,44 @@
+//! Throughput of the SHA-256 compression function behind [Sha256::compress_slice], as used to
+//! digest large receipts. Compare a build with the `simd` feature against one without, running
+//! the wasm build under a WASI runtime such as wasmtime:
+//!
+//! ```text
+//! RUSTFLAGS="-C target-feature=+simd128" \
+//!     cargo bench --bench sha --target wasm32-wasip1 --features simd
+//! ```
+
+use std::time::Instant;
+
+use bincode2proof_core::{sha, Sha256};
+
+/// Words hashed per iteration, 1 MiB.
+const WORDS: usize = 1 << 18;
+
+const ITERATIONS: u32 = 32;
+
+fn main() {
+    let data: Vec<u32> = (0..WORDS as u32)
+        .map(|i| i.wrapping_mul(0x9e37_79b9))
+        .collect();
+    // Warm up, and keep the result alive so the work is not optimized away.
+    let mut digest = *sha::Impl::hash_raw_data_slice(&data);
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        digest = *sha::Impl::hash_raw_data_slice(&data);
+    }
+    let elapsed = start.elapsed();
+    let mib_per_sec =
+        f64::from(ITERATIONS) / elapsed.as_secs_f64() * (WORDS * 4) as f64 / 1048576.0;
+    println!(
+        "compress_slice: {:.1} MiB/s ({} MiB in {:?}, simd: {}), digest {digest}",
+        mib_per_sec,
+        ITERATIONS * (WORDS as u32 * 4) / 1048576,
+        elapsed,
+        cfg!(all(
+            feature = "simd",
+            target_arch = "wasm32",
+            target_feature = "simd128"
+        )),
+    );
+}
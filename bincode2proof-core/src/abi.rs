@@ -0,0 +1,13 @@
+//! Decoding of Solidity ABI encoded journals.
+
+use alloy_dyn_abi::{DynSolType, DynSolValue};
+
+use crate::ConvertError;
+
+/// Decode a journal written with `abi.encode` according to a Solidity type string, such as
+/// `"(address,uint256,bytes32)"`.
+pub fn decode_journal(journal: &[u8], sol_type: &str) -> Result<DynSolValue, ConvertError> {
+    let ty = DynSolType::parse(sol_type).map_err(|err| ConvertError::AbiError(err.to_string()))?;
+    ty.abi_decode_params(journal)
+        .map_err(|err| ConvertError::AbiError(err.to_string()))
+}
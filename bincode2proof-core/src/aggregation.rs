@@ -0,0 +1,197 @@
+//! Merkle trees of claim digests, as verified by the `RiscZeroSetVerifier` contract.
+//!
+//! Aggregators prove a batch of claims at once by committing to the root of this tree. Each
+//! claim is then verified on-chain with its Merkle path, carried in the seal of a
+//! [crate::SetInclusionReceipt].
+
+use sha3::{Digest as _, Keccak256};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{ConvertError, Digest, MerkleProof};
+
+/// Hash two nodes of the tree, ordering them first so the result does not depend on which
+/// side each node is on.
+pub fn commutative_keccak256(a: &Digest, b: &Digest) -> Digest {
+    let (first, second) = if a.as_bytes() < b.as_bytes() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let mut hasher = Keccak256::new();
+    hasher.update(first.as_bytes());
+    hasher.update(second.as_bytes());
+    Digest::from_bytes(hasher.finalize().into())
+}
+
+/// Root of the tree reached by following `path` from `leaf`.
+pub fn merkle_path_root(leaf: &Digest, path: &[Digest]) -> Digest {
+    path.iter()
+        .fold(*leaf, |node, sibling| commutative_keccak256(&node, sibling))
+}
+
+/// A Merkle tree over a set of leaves, with the inclusion proof of each leaf.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Debug)]
+pub struct MerkleSet {
+    root: Digest,
+    proofs: Vec<MerkleProof>,
+}
+
+impl MerkleSet {
+    /// Build the tree over the given leaves, in order. The tree is left-balanced: each node
+    /// splits its leaves at the largest power of two smaller than their count.
+    pub fn new(leaves: &[Digest]) -> Result<Self, ConvertError> {
+        if leaves.is_empty() {
+            return Err(ConvertError::MalformedReceipt(
+                "cannot build a Merkle set with no leaves",
+            ));
+        }
+        let mut paths = vec![Vec::new(); leaves.len()];
+        let root = build(leaves, &mut paths);
+        let proofs = paths
+            .into_iter()
+            .enumerate()
+            .map(|(index, digests)| MerkleProof {
+                index: index as u32,
+                digests,
+            })
+            .collect();
+        Ok(MerkleSet { root, proofs })
+    }
+
+    /// Root of the tree.
+    pub fn root_digest(&self) -> Digest {
+        self.root
+    }
+
+    /// Inclusion proofs of the leaves, in the order they were given.
+    pub fn proofs(&self) -> &[MerkleProof] {
+        &self.proofs
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl MerkleSet {
+    /// Root of the tree.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn root(&self) -> Vec<u8> {
+        self.root.as_bytes().to_vec()
+    }
+
+    /// Number of leaves in the tree.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn len(&self) -> usize {
+        self.proofs.len()
+    }
+
+    /// Whether the tree has no leaves, which never holds for a built tree.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn is_empty(&self) -> bool {
+        self.proofs.is_empty()
+    }
+
+    /// Merkle path of the leaf at `index`, as its sibling digests from the leaf upwards
+    /// concatenated. This is the `path` of the set verifier seal.
+    pub fn path(&self, index: usize) -> Option<Vec<u8>> {
+        let proof = self.proofs.get(index)?;
+        Some(
+            proof
+                .digests
+                .iter()
+                .flat_map(|digest| digest.as_bytes())
+                .copied()
+                .collect(),
+        )
+    }
+}
+
+/// Compute the root of `leaves`, pushing the sibling of each subtree onto the paths of its
+/// leaves.
+fn build(leaves: &[Digest], paths: &mut [Vec<Digest>]) -> Digest {
+    if leaves.len() == 1 {
+        return leaves[0];
+    }
+    let mid = leaves.len().next_power_of_two() / 2;
+    let (left, right) = leaves.split_at(mid);
+    let (left_paths, right_paths) = paths.split_at_mut(mid);
+    let left_root = build(left, left_paths);
+    let right_root = build(right, right_paths);
+    for path in left_paths.iter_mut() {
+        path.push(right_root);
+    }
+    for path in right_paths.iter_mut() {
+        path.push(left_root);
+    }
+    commutative_keccak256(&left_root, &right_root)
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+    use crate::hash::{baby_bear, HashFn};
+
+    /// The node hash of the set verifier, to check the proofs with [MerkleProof::verify].
+    struct Keccak;
+
+    impl HashFn for Keccak {
+        fn hash_pair(&self, a: &Digest, b: &Digest) -> Box<Digest> {
+            Box::new(commutative_keccak256(a, b))
+        }
+
+        fn hash_elem_slice(&self, _slice: &[baby_bear::Elem]) -> Box<Digest> {
+            unimplemented!("the set verifier only hashes pairs")
+        }
+    }
+
+    fn leaves(count: u8) -> Vec<Digest> {
+        (1..=count).map(|i| Digest::from_bytes([i; 32])).collect()
+    }
+
+    #[test]
+    fn proofs_reach_the_root() {
+        for count in [1, 2, 3, 5, 9, 17] {
+            let leaves = leaves(count);
+            let set = MerkleSet::new(&leaves).unwrap();
+            let root = set.root_digest();
+            assert_eq!(set.len(), leaves.len());
+            for (index, (leaf, proof)) in leaves.iter().zip(set.proofs()).enumerate() {
+                assert_eq!(proof.index as usize, index);
+                assert_eq!(merkle_path_root(leaf, &proof.digests), root);
+                assert_eq!(proof.root(leaf, &Keccak), root);
+                assert_eq!(proof.verify(leaf, &root, &Keccak), Ok(()));
+                let path: Vec<&[u8]> = proof.digests.iter().map(Digest::as_bytes).collect();
+                assert_eq!(set.path(index).unwrap(), path.concat());
+                if count > 1 {
+                    let other = Digest::from_bytes([0xff; 32]);
+                    assert!(proof.verify(&other, &root, &Keccak).is_err());
+                }
+            }
+            assert_eq!(set.path(leaves.len()), None);
+        }
+        assert!(MerkleSet::new(&[]).is_err());
+    }
+
+    #[test]
+    fn root_known_answer() {
+        // Roots of the `RiscZeroSetVerifier` tree, computed with an independent Keccak-256.
+        assert_eq!(
+            MerkleSet::new(&leaves(1)).unwrap().root_digest(),
+            Digest::from_bytes([1; 32])
+        );
+        assert_eq!(
+            MerkleSet::new(&leaves(3)).unwrap().root_digest(),
+            Digest::from_bytes(hex!(
+                "1d614fa3c8de62938b0948972494f9a3858575db69ce1d34c77926f30732c981"
+            ))
+        );
+        assert_eq!(
+            MerkleSet::new(&leaves(5)).unwrap().root_digest(),
+            Digest::from_bytes(hex!(
+                "55d458ff264cc10cb4ef71d27a18db86f587999b2355cfa5eb5fccf8c973bced"
+            ))
+        );
+    }
+}
@@ -0,0 +1,22 @@
+//! Mapping over batches of receipts and segments.
+//!
+//! With the `rayon` feature, items are processed in parallel, which pays off as digesting
+//! dominates the conversion of composite receipts with many segments. In the browser, the
+//! `wasm-threads` feature of `bincode2proof-wasm` runs the thread pool on Web Workers started by
+//! `initThreadPool`.
+
+/// Map `f` over `items`, in parallel with the `rayon` feature.
+pub fn map_items<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        items.par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    items.iter().map(f).collect()
+}
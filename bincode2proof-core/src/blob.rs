@@ -0,0 +1,154 @@
+//! Packing of serialized receipts into EIP-4844 blobs, for rollups posting full receipts as blob
+//! data.
+//!
+//! Each 32-byte field element carries 31 bytes of data after a zero byte, which keeps it below
+//! the BLS12-381 scalar field modulus. The data is prefixed with its length as a big-endian
+//! `u32` and zero-padded to fill the last blob.
+
+use crate::ConvertError;
+
+/// Number of field elements in a blob.
+pub const FIELD_ELEMENTS_PER_BLOB: usize = 4096;
+
+/// Size in bytes of a field element.
+pub const FIELD_ELEMENT_BYTES: usize = 32;
+
+/// Number of data bytes carried by a field element.
+pub const USABLE_BYTES_PER_FIELD_ELEMENT: usize = 31;
+
+/// Size in bytes of a blob.
+pub const BLOB_BYTES: usize = FIELD_ELEMENTS_PER_BLOB * FIELD_ELEMENT_BYTES;
+
+/// Number of data bytes carried by a blob.
+pub const USABLE_BYTES_PER_BLOB: usize = FIELD_ELEMENTS_PER_BLOB * USABLE_BYTES_PER_FIELD_ELEMENT;
+
+const LENGTH_PREFIX_BYTES: usize = 4;
+
+/// Pack `data`, e.g. a bincode encoded receipt, into as many blobs as needed.
+pub fn pack_blobs(data: &[u8]) -> Result<Vec<Vec<u8>>, ConvertError> {
+    let len = u32::try_from(data.len())
+        .map_err(|_| ConvertError::InvalidBlob(format!("{} bytes is too long", data.len())))?;
+    let mut payload = Vec::with_capacity(LENGTH_PREFIX_BYTES + data.len());
+    payload.extend_from_slice(&len.to_be_bytes());
+    payload.extend_from_slice(data);
+
+    Ok(payload
+        .chunks(USABLE_BYTES_PER_BLOB)
+        .map(|chunk| {
+            let mut blob = vec![0u8; BLOB_BYTES];
+            for (element, bytes) in blob
+                .chunks_exact_mut(FIELD_ELEMENT_BYTES)
+                .zip(chunk.chunks(USABLE_BYTES_PER_FIELD_ELEMENT))
+            {
+                element[1..1 + bytes.len()].copy_from_slice(bytes);
+            }
+            blob
+        })
+        .collect())
+}
+
+/// Recover the data packed by [pack_blobs] from its blobs, in order.
+pub fn unpack_blobs<B: AsRef<[u8]>>(blobs: &[B]) -> Result<Vec<u8>, ConvertError> {
+    let mut payload = Vec::with_capacity(blobs.len() * USABLE_BYTES_PER_BLOB);
+    for (index, blob) in blobs.iter().enumerate() {
+        let blob = blob.as_ref();
+        if blob.len() != BLOB_BYTES {
+            return Err(ConvertError::InvalidBlob(format!(
+                "blob {index} has {} bytes, expected {BLOB_BYTES}",
+                blob.len()
+            )));
+        }
+        for element in blob.chunks_exact(FIELD_ELEMENT_BYTES) {
+            if element[0] != 0 {
+                return Err(ConvertError::InvalidBlob(format!(
+                    "blob {index} has a field element with a non-zero high byte"
+                )));
+            }
+            payload.extend_from_slice(&element[1..]);
+        }
+    }
+
+    let Some((len, data)) = payload.split_first_chunk::<LENGTH_PREFIX_BYTES>() else {
+        return Err(ConvertError::InvalidBlob("no blobs".to_string()));
+    };
+    let len = u32::from_be_bytes(*len) as usize;
+    if len > data.len() {
+        return Err(ConvertError::InvalidBlob(format!(
+            "length prefix of {len} bytes exceeds the {} bytes of blob data",
+            data.len()
+        )));
+    }
+    payload.drain(..LENGTH_PREFIX_BYTES);
+    payload.truncate(len);
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Data filling exactly one blob together with its length prefix.
+    const ONE_BLOB: usize = USABLE_BYTES_PER_BLOB - LENGTH_PREFIX_BYTES;
+
+    fn data(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn round_trip_at_boundaries() {
+        let first_element = USABLE_BYTES_PER_FIELD_ELEMENT - LENGTH_PREFIX_BYTES;
+        for (len, blobs) in [
+            (0, 1),
+            (1, 1),
+            (first_element, 1),
+            (first_element + 1, 1),
+            (ONE_BLOB - 1, 1),
+            (ONE_BLOB, 1),
+            (ONE_BLOB + 1, 2),
+            (ONE_BLOB + USABLE_BYTES_PER_BLOB, 2),
+            (ONE_BLOB + USABLE_BYTES_PER_BLOB + 1, 3),
+        ] {
+            let data = data(len);
+            let packed = pack_blobs(&data).unwrap();
+            assert_eq!(packed.len(), blobs, "{len} bytes");
+            for blob in &packed {
+                assert_eq!(blob.len(), BLOB_BYTES);
+                assert!(blob.iter().step_by(FIELD_ELEMENT_BYTES).all(|&b| b == 0));
+            }
+            assert_eq!(unpack_blobs(&packed).unwrap(), data, "{len} bytes");
+        }
+    }
+
+    #[test]
+    fn length_prefix_is_big_endian() {
+        let packed = pack_blobs(&data(0x0102)).unwrap();
+        assert_eq!(packed[0][..6], [0, 0, 0, 0x01, 0x02, 0]);
+    }
+
+    #[test]
+    fn rejects_malformed_blobs() {
+        assert!(matches!(
+            unpack_blobs::<Vec<u8>>(&[]),
+            Err(ConvertError::InvalidBlob(_))
+        ));
+        assert!(matches!(
+            unpack_blobs(&[vec![0u8; BLOB_BYTES - 1]]),
+            Err(ConvertError::InvalidBlob(_))
+        ));
+
+        let mut packed = pack_blobs(&data(10)).unwrap();
+        packed[0][FIELD_ELEMENT_BYTES] = 1;
+        assert!(matches!(
+            unpack_blobs(&packed),
+            Err(ConvertError::InvalidBlob(_))
+        ));
+
+        // A length prefix claiming more data than a single blob carries.
+        let mut packed = pack_blobs(&data(ONE_BLOB + 1)).unwrap();
+        packed.pop();
+        assert!(matches!(
+            unpack_blobs(&packed),
+            Err(ConvertError::InvalidBlob(_))
+        ));
+    }
+}
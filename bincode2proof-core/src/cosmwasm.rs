@@ -0,0 +1,72 @@
+//! JSON execute messages for the RISC Zero verifier contracts on CosmWasm chains.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::Serialize;
+
+use crate::{
+    encoder::{EvmSealEncoder, SealEncoder},
+    Digestible, sha, ConvertError, Receipt,
+};
+
+/// Arguments of the `verify` message. The seal is a `Binary` and the digests are `HexBinary`,
+/// i.e. standard padded base64 and bare lowercase hex.
+#[derive(Serialize)]
+struct VerifyMsg {
+    seal: String,
+    image_id: String,
+    journal_digest: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ExecuteMsg {
+    Verify(VerifyMsg),
+}
+
+/// Encodes the JSON `{"verify":{...}}` execute message of a verifier contract, carrying the seal
+/// prefixed with its selector. The claim must not be pruned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CosmWasmMsgEncoder;
+
+impl SealEncoder for CosmWasmMsgEncoder {
+    fn encode_seal(&self, receipt: &Receipt) -> Result<Vec<u8>, ConvertError> {
+        let seal = EvmSealEncoder::default().encode_seal(receipt)?;
+        let image_id = receipt.claim()?.as_value()?.pre.digest::<sha::Impl>();
+        let msg = ExecuteMsg::Verify(VerifyMsg {
+            seal: BASE64.encode(seal),
+            image_id: format!("{image_id:x}"),
+            journal_digest: format!("{:x}", receipt.journal.digest::<sha::Impl>()),
+        });
+        serde_json::to_vec(&msg).map_err(|err| ConvertError::EncodeError(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{groth16::receipt_from_seal, Digest};
+
+    #[test]
+    fn verify_msg() {
+        let raw: Vec<u8> = (0..=255).collect();
+        let seal = [&[0xbb, 0x00, 0x1d, 0x44][..], &raw].concat();
+        let receipt =
+            receipt_from_seal(&seal, b"hi".to_vec(), Digest::from_bytes([0x22; 32])).unwrap();
+
+        let expected = concat!(
+            r#"{"verify":{"seal":""#,
+            "uwAdRAABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZGhscHR4fICEiIyQlJicoKSorLC0uLzAxMjM0NTY3ODk6",
+            "Ozw9Pj9AQUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVpbXF1eX2BhYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5",
+            "ent8fX5/gIGCg4SFhoeIiYqLjI2Oj5CRkpOUlZaXmJmam5ydnp+goaKjpKWmp6ipqqusra6vsLGys7S1tre4",
+            "ubq7vL2+v8DBwsPExcbHyMnKy8zNzs/Q0dLT1NXW19jZ2tvc3d7f4OHi4+Tl5ufo6err7O3u7/Dx8vP09fb3",
+            "+Pn6+/z9/v8=",
+            r#"","image_id":""#,
+            "2222222222222222222222222222222222222222222222222222222222222222",
+            r#"","journal_digest":""#,
+            "8f434346648f6b96df89dda901c5176b10a6d83961dd3c1ac88b59b2dc327aa4",
+            r#""}}"#,
+        );
+        let msg = CosmWasmMsgEncoder.encode_seal(&receipt).unwrap();
+        assert_eq!(String::from_utf8(msg).unwrap(), expected);
+    }
+}
@@ -0,0 +1,185 @@
+//! Encoding of receipt seals for the on-chain verifiers of a target chain, and a registry of
+//! encoders by target name.
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use alloc::{string::String, sync::Arc};
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+#[cfg(feature = "json")]
+use crate::cosmwasm::CosmWasmMsgEncoder;
+#[cfg(all(feature = "borsh", feature = "json"))]
+use crate::near::NearArgsEncoder;
+#[cfg(all(feature = "std", feature = "borsh"))]
+use crate::solana::SolanaArgsEncoder;
+#[cfg(feature = "std")]
+use crate::starknet::StarknetSealEncoder;
+use crate::{
+    selector::SELECTOR_BYTES, ConvertError, ExitCode, InnerReceipt, MaybePruned, Receipt,
+    SealFormat,
+};
+
+/// Encodes the seal of a [Receipt] in the format expected by an on-chain verifier.
+///
+/// Implement this trait to add encodings for other chains, and pass the encoder to
+/// [ProofData::from_receipt_with_encoder](crate::ProofData::from_receipt_with_encoder) or
+/// [ConvertOptions::with_encoder](crate::options::ConvertOptions::with_encoder).
+pub trait SealEncoder: Send + Sync {
+    /// Encode the seal of `receipt`.
+    fn encode_seal(&self, receipt: &Receipt) -> Result<Vec<u8>, ConvertError>;
+}
+
+/// Encoding for the RISC Zero verifier contracts on EVM chains.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EvmSealEncoder {
+    /// Layout of the encoded seal.
+    pub format: SealFormat,
+}
+
+impl EvmSealEncoder {
+    /// Append the encoded seal of `receipt` to `out`, e.g. to reuse one buffer when converting a
+    /// batch of receipts. The receipt is only borrowed, and its seal copied once into `out`.
+    pub fn encode_seal_into(
+        &self,
+        receipt: &Receipt,
+        out: &mut Vec<u8>,
+    ) -> Result<(), ConvertError> {
+        if let MaybePruned::Value(claim) = receipt.claim()? {
+            if let ExitCode::SystemSplit | ExitCode::SessionLimit = claim.exit_code {
+                return Err(ConvertError::NoOutput(claim.exit_code));
+            }
+        }
+        match (&receipt.inner, self.format) {
+            (InnerReceipt::Groth16(receipt), SealFormat::Legacy) => {
+                out.extend_from_slice(&receipt.seal);
+            }
+            (other, SealFormat::Legacy) => {
+                return Err(ConvertError::UnsupportedReceiptKind(other.kind()))
+            }
+            #[cfg(feature = "dev-mode")]
+            (InnerReceipt::Fake(receipt), SealFormat::Selector) => {
+                use crate::{Digestible, sha};
+
+                out.extend_from_slice(&[0u8; SELECTOR_BYTES]);
+                out.extend_from_slice(receipt.claim.digest::<sha::Impl>().as_bytes());
+            }
+            (InnerReceipt::Groth16(receipt), SealFormat::Selector) => {
+                out.extend_from_slice(&receipt.verifier_parameters.as_bytes()[..SELECTOR_BYTES]);
+                out.extend_from_slice(&receipt.seal);
+            }
+            (InnerReceipt::SetInclusion(receipt), SealFormat::Selector) => {
+                out.extend_from_slice(&receipt.abi_encode_seal()?);
+            }
+            (other, SealFormat::Selector) => {
+                return Err(ConvertError::UnsupportedReceiptKind(other.kind()))
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SealEncoder for EvmSealEncoder {
+    fn encode_seal(&self, receipt: &Receipt) -> Result<Vec<u8>, ConvertError> {
+        let mut seal = Vec::with_capacity(SELECTOR_BYTES + receipt.seal_size());
+        self.encode_seal_into(receipt, &mut seal)?;
+        Ok(seal)
+    }
+}
+
+#[cfg(feature = "std")]
+static REGISTERED_ENCODERS: RwLock<Vec<(String, Arc<dyn SealEncoder>)>> = RwLock::new(Vec::new());
+
+/// Register an additional encoder, to be returned by [seal_encoder_from_name] for `target`. An
+/// encoder with the name of a built-in or previously registered target replaces it.
+#[cfg(feature = "std")]
+pub fn register_seal_encoder(target: impl Into<String>, encoder: Arc<dyn SealEncoder>) {
+    let target = target.into();
+    let mut encoders = REGISTERED_ENCODERS
+        .write()
+        .unwrap_or_else(|err| err.into_inner());
+    encoders.retain(|(registered, _)| *registered != target);
+    encoders.push((target, encoder));
+}
+
+/// Look up the encoder of a target chain, e.g. "eth".
+#[cfg(feature = "std")]
+pub fn seal_encoder_from_name(target: impl AsRef<str>) -> Option<Arc<dyn SealEncoder>> {
+    let target = target.as_ref();
+    let encoders = REGISTERED_ENCODERS
+        .read()
+        .unwrap_or_else(|err| err.into_inner());
+    if let Some((_, encoder)) = encoders.iter().find(|(name, _)| name == target) {
+        return Some(encoder.clone());
+    }
+    match target {
+        "eth" | "evm" => Some(Arc::new(EvmSealEncoder::default())),
+        "starknet" => Some(Arc::new(StarknetSealEncoder)),
+        #[cfg(feature = "borsh")]
+        "solana" => Some(Arc::new(SolanaArgsEncoder)),
+        #[cfg(all(feature = "borsh", feature = "json"))]
+        "near" => Some(Arc::new(NearArgsEncoder)),
+        #[cfg(feature = "json")]
+        "cosmwasm" => Some(Arc::new(CosmWasmMsgEncoder)),
+        _ => None,
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+    use crate::{groth16::receipt_from_seal, Digest};
+
+    /// Generator of G1, as `x || y`.
+    const G1: [u8; 64] = hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000002"
+    );
+    /// Generator of G2, as `x1 || x0 || y1 || y0`.
+    const G2: [u8; 128] = hex!(
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"
+        "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"
+        "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"
+        "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+    );
+
+    /// A Groth16 receipt whose proof points are on the curve, so that every encoder accepts it.
+    fn receipt() -> Receipt {
+        let seal = [&[0xbb, 0x00, 0x1d, 0x44][..], &G1, &G2, &G1].concat();
+        receipt_from_seal(&seal, b"journal".to_vec(), Digest::new([7; 8])).unwrap()
+    }
+
+    fn encode(target: &str) -> Vec<u8> {
+        seal_encoder_from_name(target)
+            .unwrap_or_else(|| panic!("no encoder for {target}"))
+            .encode_seal(&receipt())
+            .unwrap()
+    }
+
+    #[test]
+    fn built_in_targets() {
+        let receipt = receipt();
+        assert_eq!(
+            encode("eth"),
+            EvmSealEncoder::default().encode_seal(&receipt).unwrap()
+        );
+        assert_eq!(encode("evm"), encode("eth"));
+        assert_eq!(
+            encode("starknet"),
+            StarknetSealEncoder.encode_seal(&receipt).unwrap()
+        );
+        #[cfg(feature = "borsh")]
+        assert_eq!(
+            encode("solana"),
+            SolanaArgsEncoder.encode_seal(&receipt).unwrap()
+        );
+        #[cfg(feature = "json")]
+        assert_eq!(
+            encode("cosmwasm"),
+            CosmWasmMsgEncoder.encode_seal(&receipt).unwrap()
+        );
+        assert!(seal_encoder_from_name("bitcoin").is_none());
+    }
+}
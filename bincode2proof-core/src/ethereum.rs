@@ -0,0 +1,140 @@
+//! Solidity ABI encoding of proofs for the RISC Zero verifier contracts.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{Digestible, sha, ConvertError, Digest, ProofData};
+
+/// Function selector of `IRiscZeroVerifier.verify(bytes,bytes32,bytes32)`.
+pub const VERIFY_SELECTOR: [u8; 4] = [0xab, 0x75, 0x0e, 0x75];
+
+/// Calldata gas per zero byte, per EIP-2028.
+pub const ZERO_BYTE_GAS: u64 = 4;
+
+/// Calldata gas per non-zero byte, per EIP-2028.
+pub const NON_ZERO_BYTE_GAS: u64 = 16;
+
+/// Approximate gas used by `RiscZeroGroth16Verifier.verify` itself, dominated by the pairing
+/// check, used when no verifier cost is given to [estimate_verify_gas].
+pub const DEFAULT_VERIFIER_GAS: u64 = 300_000;
+
+/// Size in bytes of an ABI word.
+pub(crate) const WORD_BYTES: usize = 32;
+
+/// Encode an offset or length as a big-endian ABI word.
+pub(crate) fn abi_word(value: usize) -> [u8; WORD_BYTES] {
+    let mut word = [0u8; WORD_BYTES];
+    word[WORD_BYTES - 8..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
+
+/// Append the tail encoding of a dynamic `bytes` value: its length followed by the data padded
+/// to a whole number of words.
+pub(crate) fn abi_append_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&abi_word(bytes.len()));
+    out.extend_from_slice(bytes);
+    out.resize(
+        out.len() + bytes.len().next_multiple_of(WORD_BYTES) - bytes.len(),
+        0,
+    );
+}
+
+/// Append `abi.encode(seal, imageId, journalDigest)` to `out`.
+fn append_proof(out: &mut Vec<u8>, seal: &[u8], image_id: &Digest, journal_digest: &Digest) {
+    out.extend_from_slice(&abi_word(3 * WORD_BYTES));
+    out.extend_from_slice(image_id.as_bytes());
+    out.extend_from_slice(journal_digest.as_bytes());
+    abi_append_bytes(out, seal);
+}
+
+/// Encode the calldata of `IRiscZeroVerifier.verify(seal, imageId, journalDigest)`.
+pub fn verify_calldata(seal: &[u8], image_id: &Digest, journal_digest: &Digest) -> Vec<u8> {
+    let mut calldata = Vec::with_capacity(4 + 4 * WORD_BYTES + seal.len() + WORD_BYTES);
+    calldata.extend_from_slice(&VERIFY_SELECTOR);
+    append_proof(&mut calldata, seal, image_id, journal_digest);
+    calldata
+}
+
+impl ProofData {
+    /// `abi.encode(seal, imageId, journalDigest)`, i.e. the `verify` calldata without its
+    /// function selector. Fails if the claim was pruned from the receipt, leaving the image ID
+    /// unknown.
+    pub fn abi_bytes(&self) -> Result<Vec<u8>, ConvertError> {
+        let image_id = self.image_id.ok_or(ConvertError::PrunedClaim)?;
+        let journal_digest = self.journal.digest::<sha::Impl>();
+        let mut encoded = Vec::with_capacity(4 * WORD_BYTES + self.seal.len() + WORD_BYTES);
+        append_proof(&mut encoded, &self.seal, &image_id, &journal_digest);
+        Ok(encoded)
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ProofData {
+    /// The seal, image ID and journal digest as one `abi.encode(bytes, bytes32, bytes32)` byte
+    /// string, to be decoded with `abi.decode` at submission.
+    #[cfg(feature = "wasm")]
+    pub fn abi_encode(&self) -> Result<Vec<u8>, JsError> {
+        Ok(self.abi_bytes()?)
+    }
+}
+
+/// Calldata gas of `bytes`, charging zero and non-zero bytes per EIP-2028.
+pub fn calldata_gas(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .map(|&byte| match byte {
+            0 => ZERO_BYTE_GAS,
+            _ => NON_ZERO_BYTE_GAS,
+        })
+        .sum()
+}
+
+/// Expected gas cost of submitting a proof for on-chain verification.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasEstimate {
+    calldata_gas: u64,
+    verifier_gas: u64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl GasEstimate {
+    /// Calldata gas of the seal and journal.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn calldata_gas(&self) -> u64 {
+        self.calldata_gas
+    }
+
+    /// Base cost of the verifier call.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn verifier_gas(&self) -> u64 {
+        self.verifier_gas
+    }
+
+    /// Sum of the calldata and verifier gas.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn total_gas(&self) -> u64 {
+        self.calldata_gas + self.verifier_gas
+    }
+}
+
+impl ProofData {
+    /// Estimate the gas of verifying this proof on-chain: the calldata gas of the seal and
+    /// journal, plus `verifier_gas` for the verifier call itself. The intrinsic transaction cost
+    /// and the ABI padding of the application call are not included.
+    pub fn estimate_verify_gas(&self, verifier_gas: u64) -> GasEstimate {
+        GasEstimate {
+            calldata_gas: calldata_gas(&self.seal) + calldata_gas(&self.journal),
+            verifier_gas,
+        }
+    }
+}
+
+/// Estimate the gas of verifying `proof_data` on-chain, given the base cost of the verifier
+/// call, which defaults to [DEFAULT_VERIFIER_GAS].
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn estimate_verify_gas(proof_data: &ProofData, verifier_gas: Option<u64>) -> GasEstimate {
+    proof_data.estimate_verify_gas(verifier_gas.unwrap_or(DEFAULT_VERIFIER_GAS))
+}
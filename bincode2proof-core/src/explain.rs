@@ -0,0 +1,42 @@
+//! Human-readable summaries of receipts, for triaging user-submitted proofs.
+
+use core::fmt::Write;
+
+use crate::{selector::Selector, Receipt};
+
+impl Receipt {
+    /// Summary of the receipt: its kind, the risc0 release guessed from its selector, exit code,
+    /// journal length, number of assumptions and total seal size, one per line.
+    pub fn explain(&self) -> String {
+        let selector = Selector::from(self.inner.verifier_parameters());
+        let version = match selector.info() {
+            Some(info) => format!(
+                "{} {} (selector {selector})",
+                info.verifier(),
+                info.version()
+            ),
+            None => format!("unknown (selector {selector})"),
+        };
+        let exit_code = match self.claim() {
+            Ok(claim) => match claim.as_value() {
+                Ok(claim) => format!("{:?}", claim.exit_code),
+                Err(_) => "unknown (claim is pruned)".to_string(),
+            },
+            Err(err) => format!("unknown ({err})"),
+        };
+        let assumptions = match (self.assumptions(), self.unresolved_assumptions()) {
+            (Ok(all), Ok(unresolved)) => format!("{} ({} unresolved)", all.len(), unresolved.len()),
+            (Err(err), _) | (_, Err(err)) => format!("unknown ({err})"),
+        };
+
+        let mut out = String::new();
+        // Writing to a String cannot fail.
+        let _ = writeln!(out, "kind: {}", self.inner.kind());
+        let _ = writeln!(out, "risc0 version: {version}");
+        let _ = writeln!(out, "exit code: {exit_code}");
+        let _ = writeln!(out, "journal: {} bytes", self.journal.bytes.len());
+        let _ = writeln!(out, "assumptions: {assumptions}");
+        let _ = write!(out, "seal: {} bytes", self.seal_size());
+        out
+    }
+}
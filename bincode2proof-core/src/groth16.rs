@@ -0,0 +1,721 @@
+//! Verification of [Groth16Receipt] seals over the BN254 curve.
+
+use core::str::FromStr;
+
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::PrimeField;
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, VerifyingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    Digestible,
+    selector::{self, SELECTOR_BYTES},
+    sha, tagged_iter, tagged_struct,
+    verify::VerifierContext,
+    ConvertError, Digest, Groth16Receipt, InnerAssumptionReceipt, InnerReceipt, Journal,
+    MaybePruned, Receipt, ReceiptClaim, ReceiptMetadata, Sha256, VerificationError, DIGEST_BYTES,
+};
+
+// Constants from: risc0-ethereum/contracts/src/groth16/Groth16Verifier.sol
+const ALPHA_X: &str =
+    "20491192805390485299153009773594534940189261866228447918068658471970481763042";
+const ALPHA_Y: &str =
+    "9383485363053290200918347156157836566562967994039712273449902621266178545958";
+const BETA_X1: &str =
+    "4252822878758300859123897981450591353533073413197771768651442665752259397132";
+const BETA_X2: &str =
+    "6375614351688725206403948262868962793625744043794305715222011528459656738731";
+const BETA_Y1: &str =
+    "21847035105528745403288232691147584728191162732299865338377159692350059136679";
+const BETA_Y2: &str =
+    "10505242626370262277552901082094356697409835680220590971873171140371331206856";
+const GAMMA_X1: &str =
+    "11559732032986387107991004021392285783925812861821192530917403151452391805634";
+const GAMMA_X2: &str =
+    "10857046999023057135944570762232829481370756359578518086990519993285655852781";
+const GAMMA_Y1: &str =
+    "4082367875863433681332203403145435568316851327593401208105741076214120093531";
+const GAMMA_Y2: &str =
+    "8495653923123431417604973247489272438418190587263600148770280649306958101930";
+const DELTA_X1: &str =
+    "1668323501672964604911431804142266013250380587483576094566949227275849579036";
+const DELTA_X2: &str =
+    "12043754404802191763554326994664886008979042643626290185762540825416902247219";
+const DELTA_Y1: &str =
+    "7710631539206257456743780535472368339139328733484942210876916214502466455394";
+const DELTA_Y2: &str =
+    "13740680757317479711909903993315946540841369848973133181051452051592786724563";
+
+const IC: [(&str, &str); 6] = [
+    (
+        "8446592859352799428420270221449902464741693648963397251242447530457567083492",
+        "1064796367193003797175961162477173481551615790032213185848276823815288302804",
+    ),
+    (
+        "3179835575189816632597428042194253779818690147323192973511715175294048485951",
+        "20895841676865356752879376687052266198216014795822152491318012491767775979074",
+    ),
+    (
+        "5332723250224941161709478398807683311971555792614491788690328996478511465287",
+        "21199491073419440416471372042641226693637837098357067793586556692319371762571",
+    ),
+    (
+        "12457994489566736295787256452575216703923664299075106359829199968023158780583",
+        "19706766271952591897761291684837117091856807401404423804318744964752784280790",
+    ),
+    (
+        "19617808913178163826953378459323299110911217259216006187355745713323154132237",
+        "21663537384585072695701846972542344484111393047775983928357046779215877070466",
+    ),
+    (
+        "6834578911681792552110317589222010969491336870276623105249474534788043166867",
+        "15060583660288623605191393599883223885678013570733629274538391874953353488393",
+    ),
+];
+
+/// Root of the Merkle tree of recursion program control IDs accepted by the Groth16 circuit.
+pub const ALLOWED_CONTROL_ROOT: Digest = Digest::from_bytes(hex_literal::hex!(
+    "ce52bf56033842021af3cf6db8a50d1b7535c125a34f1a22c6fdcf002c5a1529"
+));
+
+/// Control ID of the identity recursion program, using Poseidon over the BN254 scalar field.
+pub const BN254_IDENTITY_CONTROL_ID: Digest = Digest::from_bytes(hex_literal::hex!(
+    "c07a65145c3cb48b6101962ea607a4dd93c753bb26975cb47feb00d3666e4404"
+));
+
+/// Size in bytes of a Groth16 seal: two G1 points and one G2 point.
+pub const SEAL_BYTES: usize = 256;
+
+fn fq(decimal: &str) -> Fq {
+    // The verifying key constants are known-good field elements.
+    Fq::from_str(decimal).unwrap_or_else(|_| panic!("invalid field element: {decimal}"))
+}
+
+fn g1(x: &str, y: &str) -> G1Affine {
+    G1Affine::new_unchecked(fq(x), fq(y))
+}
+
+/// Build a G2 point from its Solidity representation, which lists the imaginary part first.
+fn g2(x1: &str, x2: &str, y1: &str, y2: &str) -> G2Affine {
+    G2Affine::new_unchecked(Fq2::new(fq(x2), fq(x1)), Fq2::new(fq(y2), fq(y1)))
+}
+
+/// Default Groth16 verifying key for RISC Zero recursive verification.
+pub fn verifying_key() -> VerifyingKey<Bn254> {
+    VerifyingKey {
+        alpha_g1: g1(ALPHA_X, ALPHA_Y),
+        beta_g2: g2(BETA_X1, BETA_X2, BETA_Y1, BETA_Y2),
+        gamma_g2: g2(GAMMA_X1, GAMMA_X2, GAMMA_Y1, GAMMA_Y2),
+        delta_g2: g2(DELTA_X1, DELTA_X2, DELTA_Y1, DELTA_Y2),
+        gamma_abc_g1: IC.iter().map(|(x, y)| g1(x, y)).collect(),
+    }
+}
+
+/// Hash a point on G1 or G2 by hashing the concatenated big-endian representation of (x, y).
+/// The point at infinity has no such representation and is rejected.
+fn hash_point<S: Sha256>(point: impl AffineRepr) -> Result<Digest, ConvertError> {
+    let mut buffer = Vec::new();
+    let (x, y) = point.xy().ok_or_else(|| {
+        ConvertError::EncodeError("verifying key point is the point at infinity".to_string())
+    })?;
+    y.serialize_uncompressed(&mut buffer)
+        .expect("failed to serialize field element");
+    x.serialize_uncompressed(&mut buffer)
+        .expect("failed to serialize field element");
+    buffer.reverse();
+    Ok(*S::hash_bytes(&buffer))
+}
+
+/// Digest of a Groth16 verifying key, as committed to by the verifier parameters. Fails if one
+/// of its points is the point at infinity.
+pub fn verifying_key_digest<S: Sha256>(vk: &VerifyingKey<Bn254>) -> Result<Digest, ConvertError> {
+    let ic = vk
+        .gamma_abc_g1
+        .iter()
+        .map(|point| hash_point::<S>(*point))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(tagged_struct::<S>(
+        "risc0_groth16.VerifyingKey",
+        &[
+            hash_point::<S>(vk.alpha_g1)?,
+            hash_point::<S>(vk.beta_g2)?,
+            hash_point::<S>(vk.gamma_g2)?,
+            hash_point::<S>(vk.delta_g2)?,
+            tagged_iter::<S>("risc0_groth16.VerifyingKey.IC", ic.iter()),
+        ],
+        &[],
+    ))
+}
+
+/// Parameters of the Groth16 receipt verifier. Their digest is the `verifier_parameters` field
+/// of a [Groth16Receipt], and its first four bytes form the seal selector.
+#[derive(Clone, Debug)]
+pub struct Groth16ReceiptVerifierParameters {
+    /// Root of the Merkle tree of recursion program control IDs accepted by the circuit.
+    pub control_root: Digest,
+    /// Control ID of the identity recursion program over the BN254 scalar field.
+    pub bn254_control_id: Digest,
+    /// Verifying key of the Groth16 circuit, checked by [Groth16ReceiptVerifierParameters::new].
+    verifying_key: VerifyingKey<Bn254>,
+}
+
+impl Default for Groth16ReceiptVerifierParameters {
+    /// Parameters of the release this crate was built against.
+    fn default() -> Self {
+        Groth16ReceiptVerifierParameters {
+            control_root: ALLOWED_CONTROL_ROOT,
+            bn254_control_id: BN254_IDENTITY_CONTROL_ID,
+            verifying_key: verifying_key(),
+        }
+    }
+}
+
+impl Digestible for Groth16ReceiptVerifierParameters {
+    /// Hash the [Groth16ReceiptVerifierParameters] to get a digest of the struct.
+    fn digest<S: Sha256>(&self) -> Digest {
+        tagged_struct::<S>(
+            "risc0.Groth16ReceiptVerifierParameters",
+            &[
+                self.control_root,
+                self.bn254_control_id,
+                verifying_key_digest::<S>(&self.verifying_key)
+                    .expect("verifying key is checked on construction"),
+            ],
+            &[],
+        )
+    }
+}
+
+impl Groth16ReceiptVerifierParameters {
+    /// Parameters for the given control root, identity control ID and verifying key. Fails if a
+    /// point of the verifying key is the point at infinity, as it cannot be hashed.
+    pub fn new(
+        control_root: Digest,
+        bn254_control_id: Digest,
+        verifying_key: VerifyingKey<Bn254>,
+    ) -> Result<Self, ConvertError> {
+        verifying_key_digest::<sha::Impl>(&verifying_key)?;
+        Ok(Groth16ReceiptVerifierParameters {
+            control_root,
+            bn254_control_id,
+            verifying_key,
+        })
+    }
+
+    /// Verifying key of the Groth16 circuit.
+    pub fn verifying_key(&self) -> &VerifyingKey<Bn254> {
+        &self.verifying_key
+    }
+
+    /// Seal selector of Groth16 receipts produced with these parameters.
+    pub fn selector(&self) -> [u8; 4] {
+        let digest = self.digest::<sha::Impl>();
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&digest.as_bytes()[..4]);
+        selector
+    }
+}
+
+/// Digest of the default [Groth16ReceiptVerifierParameters], against which the
+/// `verifier_parameters` of a [Groth16Receipt] can be compared.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn groth16_verifier_parameters() -> Vec<u8> {
+    Groth16ReceiptVerifierParameters::default()
+        .digest::<sha::Impl>()
+        .as_bytes()
+        .to_vec()
+}
+
+/// Read a big-endian 32-byte base field element.
+fn fq_from_be(bytes: &[u8]) -> Result<Fq, VerificationError> {
+    let le: Vec<u8> = bytes.iter().rev().copied().collect();
+    Fq::deserialize_uncompressed(le.as_slice()).map_err(|_| VerificationError::ReceiptFormatError)
+}
+
+/// Decode the `(A, B, C)` proof points from a big-endian encoded seal.
+pub fn decode_proof(seal: &[u8]) -> Result<Proof<Bn254>, VerificationError> {
+    if seal.len() != SEAL_BYTES {
+        return Err(VerificationError::ReceiptFormatError);
+    }
+    let limbs = seal
+        .chunks_exact(32)
+        .map(fq_from_be)
+        .collect::<Result<Vec<_>, _>>()?;
+    let a = G1Affine::new_unchecked(limbs[0], limbs[1]);
+    let b = G2Affine::new_unchecked(Fq2::new(limbs[3], limbs[2]), Fq2::new(limbs[5], limbs[4]));
+    let c = G1Affine::new_unchecked(limbs[6], limbs[7]);
+    for on_curve in [a.is_on_curve(), b.is_on_curve(), c.is_on_curve()] {
+        if !on_curve {
+            return Err(VerificationError::ReceiptFormatError);
+        }
+    }
+    Ok(Proof { a, b, c })
+}
+
+/// Check that a seal decodes to BN254 points that are on the curve and in the prime-order
+/// subgroup, which the Solidity verifier would otherwise only reject on-chain.
+pub fn validate_seal(seal: &[u8]) -> Result<(), ConvertError> {
+    if seal.len() != SEAL_BYTES {
+        return Err(ConvertError::MalformedReceipt(
+            "Groth16 seal must be 256 bytes",
+        ));
+    }
+    let proof = decode_proof(seal).map_err(|_| {
+        ConvertError::MalformedReceipt("Groth16 seal point is not on the BN254 curve")
+    })?;
+    let in_subgroup = proof.a.is_in_correct_subgroup_assuming_on_curve()
+        && proof.b.is_in_correct_subgroup_assuming_on_curve()
+        && proof.c.is_in_correct_subgroup_assuming_on_curve();
+    if !in_subgroup {
+        return Err(ConvertError::MalformedReceipt(
+            "Groth16 seal point is not in the BN254 prime-order subgroup",
+        ));
+    }
+    Ok(())
+}
+
+/// Validate every Groth16 seal that [crate::encode_seal] would place in the on-chain seal of
+/// `receipt`. Receipts without a Groth16 seal are accepted unchanged.
+pub fn validate_receipt_seal(receipt: &Receipt) -> Result<(), ConvertError> {
+    match &receipt.inner {
+        InnerReceipt::Groth16(inner) => validate_seal(&inner.seal),
+        InnerReceipt::SetInclusion(inner) => match inner.root.as_deref() {
+            Some(InnerAssumptionReceipt::Groth16(root)) => validate_seal(&root.seal),
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Big-endian 32-byte limb of a proof point coordinate.
+pub type Limb = [u8; 32];
+
+/// The `(A, B, C)` proof points of a Groth16 seal, as big-endian limbs in the order used by the
+/// Solidity verifier. G2 coordinates list the imaginary part first.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Groth16Points {
+    a: [Limb; 2],
+    b: [[Limb; 2]; 2],
+    c: [Limb; 2],
+}
+
+impl Groth16Points {
+    /// Split a 256-byte seal into its proof points, without checking they are on the curve.
+    pub fn from_seal(seal: &[u8]) -> Result<Self, VerificationError> {
+        if seal.len() != SEAL_BYTES {
+            return Err(VerificationError::ReceiptFormatError);
+        }
+        let mut limbs = [[0u8; 32]; 8];
+        for (limb, chunk) in limbs.iter_mut().zip(seal.chunks_exact(32)) {
+            limb.copy_from_slice(chunk);
+        }
+        Ok(Groth16Points {
+            a: [limbs[0], limbs[1]],
+            b: [[limbs[2], limbs[3]], [limbs[4], limbs[5]]],
+            c: [limbs[6], limbs[7]],
+        })
+    }
+
+    /// Native accessor for the `A` point as `[x, y]`.
+    pub fn a_limbs(&self) -> &[Limb; 2] {
+        &self.a
+    }
+
+    /// Native accessor for the `B` point as `[[x1, x0], [y1, y0]]`.
+    pub fn b_limbs(&self) -> &[[Limb; 2]; 2] {
+        &self.b
+    }
+
+    /// Native accessor for the `C` point as `[x, y]`.
+    pub fn c_limbs(&self) -> &[Limb; 2] {
+        &self.c
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Groth16Points {
+    /// The `A` point as 64 bytes: `x || y`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn a(&self) -> Vec<u8> {
+        self.a.concat()
+    }
+
+    /// The `B` point as 128 bytes: `x1 || x0 || y1 || y0`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn b(&self) -> Vec<u8> {
+        self.b.concat().concat()
+    }
+
+    /// The `C` point as 64 bytes: `x || y`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn c(&self) -> Vec<u8> {
+        self.c.concat()
+    }
+}
+
+/// Split a digest into two field elements, each holding 128 bits of the digest.
+fn split_digest(digest: &Digest) -> (Fr, Fr) {
+    let (a, b) = digest.as_bytes().split_at(DIGEST_BYTES / 2);
+    (Fr::from_le_bytes_mod_order(a), Fr::from_le_bytes_mod_order(b))
+}
+
+impl<Claim> Groth16Receipt<Claim>
+where
+    Claim: Digestible + core::fmt::Debug + Clone + Serialize,
+{
+    /// Verify the integrity of this receipt, ensuring the claim is attested to by the seal.
+    pub fn verify_integrity(&self) -> Result<(), VerificationError> {
+        self.verify_integrity_with_context(&VerifierContext::default())
+    }
+
+    /// Verify the integrity of this receipt, accepting a seal produced under any of the control
+    /// roots of the given [VerifierContext].
+    pub fn verify_integrity_with_context(
+        &self,
+        ctx: &VerifierContext,
+    ) -> Result<(), VerificationError> {
+        self.verify_with_key(ctx, verifying_key())
+    }
+
+    fn verify_with_key(
+        &self,
+        ctx: &VerifierContext,
+        vk: VerifyingKey<Bn254>,
+    ) -> Result<(), VerificationError> {
+        let claim_digest = self.claim.digest::<sha::Impl>();
+        let proof = decode_proof(&self.seal)?;
+        let pvk = PreparedVerifyingKey::from(vk);
+        for control_root in ctx.control_roots() {
+            let public_inputs = public_inputs(control_root, &claim_digest)?;
+            if let Ok(true) = Groth16::<Bn254>::verify_proof(&pvk, &proof, &public_inputs) {
+                return Ok(());
+            }
+        }
+        Err(VerificationError::InvalidProof)
+    }
+
+    /// Split the seal into its `(A, B, C)` proof points.
+    pub fn points(&self) -> Result<Groth16Points, VerificationError> {
+        Groth16Points::from_seal(&self.seal)
+    }
+
+    /// Convert the seal into the snarkjs `proof.json` format.
+    pub fn snarkjs_proof(&self) -> Result<SnarkjsProof, VerificationError> {
+        let proof = decode_proof(&self.seal)?;
+        Ok(SnarkjsProof {
+            pi_a: [
+                proof.a.x.to_string(),
+                proof.a.y.to_string(),
+                "1".to_string(),
+            ],
+            pi_b: [
+                [proof.b.x.c0.to_string(), proof.b.x.c1.to_string()],
+                [proof.b.y.c0.to_string(), proof.b.y.c1.to_string()],
+                ["1".to_string(), "0".to_string()],
+            ],
+            pi_c: [
+                proof.c.x.to_string(),
+                proof.c.y.to_string(),
+                "1".to_string(),
+            ],
+            protocol: "groth16",
+            curve: "bn128",
+        })
+    }
+
+    /// Public inputs of the circuit in the snarkjs `public.json` format.
+    pub fn snarkjs_public(&self) -> Result<Vec<String>, VerificationError> {
+        let public_inputs =
+            public_inputs(&ALLOWED_CONTROL_ROOT, &self.claim.digest::<sha::Impl>())?;
+        Ok(public_inputs.iter().map(Fr::to_string).collect())
+    }
+}
+
+/// Public inputs of the Groth16 circuit attesting to the claim with the given digest under the
+/// given control root.
+fn public_inputs(
+    control_root: &Digest,
+    claim_digest: &Digest,
+) -> Result<[Fr; 5], VerificationError> {
+    let (a0, a1) = split_digest(control_root);
+    let (c0, c1) = split_digest(claim_digest);
+    let id_bn254 = Fr::deserialize_uncompressed(BN254_IDENTITY_CONTROL_ID.as_bytes())
+        .map_err(|_| VerificationError::ReceiptFormatError)?;
+    Ok([a0, a1, c0, c1, id_bn254])
+}
+
+/// Groth16 proof in the JSON format produced and consumed by snarkjs.
+#[derive(Clone, Debug, Serialize)]
+pub struct SnarkjsProof {
+    pub pi_a: [String; 3],
+    pub pi_b: [[String; 2]; 3],
+    pub pi_c: [String; 3],
+    pub protocol: &'static str,
+    pub curve: &'static str,
+}
+
+/// Contents of the snarkjs `proof.json` and `public.json` files for a Groth16 receipt.
+#[cfg(feature = "json")]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct SnarkjsExport {
+    proof: String,
+    public: String,
+}
+
+#[cfg(feature = "json")]
+impl SnarkjsExport {
+    /// Render the snarkjs files of a Groth16 receipt as pretty-printed JSON.
+    pub fn new(receipt: &Groth16Receipt<ReceiptClaim>) -> Result<Self, ConvertError> {
+        let proof = serde_json::to_string_pretty(&receipt.snarkjs_proof()?)
+            .map_err(|err| ConvertError::EncodeError(err.to_string()))?;
+        let public = serde_json::to_string_pretty(&receipt.snarkjs_public()?)
+            .map_err(|err| ConvertError::EncodeError(err.to_string()))?;
+        Ok(Self { proof, public })
+    }
+}
+
+#[cfg(feature = "json")]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl SnarkjsExport {
+    /// Proof points, in the `proof.json` format.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn proof(&self) -> String {
+        self.proof.clone()
+    }
+
+    /// Public inputs derived from the claim, in the `public.json` format.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn public(&self) -> String {
+        self.public.clone()
+    }
+}
+
+/// Rebuild the Groth16 [Receipt] of a successful execution from the seal, journal and image ID
+/// passed to `IRiscZeroVerifier.verify` on-chain.
+///
+/// The seal must start with the selector of the verifier parameters of this release, as the full
+/// parameter digest cannot be recovered from any other selector.
+pub fn receipt_from_seal(
+    seal: &[u8],
+    journal: Vec<u8>,
+    image_id: Digest,
+) -> Result<Receipt, ConvertError> {
+    let verifier_parameters = Groth16ReceiptVerifierParameters::default().digest::<sha::Impl>();
+    let (selector, seal) = selector::split_seal(seal)?;
+    if selector.0[..] != verifier_parameters.as_bytes()[..SELECTOR_BYTES] {
+        let target = selector
+            .info()
+            .map(|info| format!("{} {}", info.verifier(), info.version()))
+            .unwrap_or_else(|| "an unknown verifier".to_string());
+        return Err(ConvertError::DecodeError(format!(
+            "seal selector {} targets {target}, not the Groth16 verifier of this release",
+            selector.to_hex(),
+        )));
+    }
+    if seal.len() != SEAL_BYTES {
+        return Err(ConvertError::MalformedReceipt(
+            "Groth16 seal must be 256 bytes",
+        ));
+    }
+
+    let claim = ReceiptClaim::ok(image_id, journal.clone());
+    Ok(Receipt {
+        inner: InnerReceipt::Groth16(Groth16Receipt {
+            seal: seal.to_vec(),
+            claim: MaybePruned::Value(claim),
+            verifier_parameters,
+        }),
+        journal: Journal { bytes: journal },
+        metadata: ReceiptMetadata {
+            verifier_parameters,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::BigInteger;
+    use ark_relations::{
+        lc,
+        r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError},
+    };
+    use ark_snark::SNARK;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{MaybePruned, ReceiptClaim};
+
+    /// Circuit with the five public inputs of the risc0 verifier, each constrained to square into
+    /// a witness. The recursion circuit itself cannot be proven here, so receipts proven with this
+    /// circuit are checked against its own verifying key.
+    struct Squares([Fr; 5]);
+
+    impl ConstraintSynthesizer<Fr> for Squares {
+        fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+            for input in self.0 {
+                let x = cs.new_input_variable(|| Ok(input))?;
+                let square = cs.new_witness_variable(|| Ok(input * input))?;
+                cs.enforce_constraint(lc!() + x, lc!() + x, lc!() + square)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Encode proof points as a big-endian seal, the inverse of [decode_proof].
+    fn encode_proof(proof: &Proof<Bn254>) -> Vec<u8> {
+        [
+            proof.a.x,
+            proof.a.y,
+            proof.b.x.c1,
+            proof.b.x.c0,
+            proof.b.y.c1,
+            proof.b.y.c0,
+            proof.c.x,
+            proof.c.y,
+        ]
+        .iter()
+        .flat_map(|limb| limb.into_bigint().to_bytes_be())
+        .collect()
+    }
+
+    /// A Groth16 receipt proving a successful execution, and the verifying key it was proven for.
+    fn proven_receipt() -> (Groth16Receipt<ReceiptClaim>, VerifyingKey<Bn254>) {
+        let claim = ReceiptClaim::ok(Digest::new([1; 8]), b"journal".to_vec());
+        let inputs = public_inputs(&ALLOWED_CONTROL_ROOT, &claim.digest::<sha::Impl>()).unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+        let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(Squares(inputs), &mut rng).unwrap();
+        let proof = Groth16::<Bn254>::prove(&pk, Squares(inputs), &mut rng).unwrap();
+        let receipt = Groth16Receipt {
+            seal: encode_proof(&proof),
+            claim: MaybePruned::Value(claim),
+            verifier_parameters: Groth16ReceiptVerifierParameters::default().digest::<sha::Impl>(),
+        };
+        (receipt, vk)
+    }
+
+    #[test]
+    fn proven_receipt_verifies() {
+        let (receipt, vk) = proven_receipt();
+        let ctx = VerifierContext::default();
+        assert_eq!(receipt.verify_with_key(&ctx, vk.clone()), Ok(()));
+        assert_eq!(
+            decode_proof(&receipt.seal).map(|proof| encode_proof(&proof)),
+            Ok(receipt.seal.clone())
+        );
+
+        // Only seals produced under an accepted control root verify.
+        let other_root = VerifierContext::empty().with_control_root(Digest::new([9; 8]));
+        assert_eq!(
+            receipt.verify_with_key(&other_root, vk),
+            Err(VerificationError::InvalidProof)
+        );
+        // The seal is not a proof for the embedded risc0 verifying key.
+        assert_eq!(
+            receipt.verify_integrity(),
+            Err(VerificationError::InvalidProof)
+        );
+    }
+
+    #[test]
+    fn tampered_receipt_fails() {
+        let (receipt, vk) = proven_receipt();
+        let ctx = VerifierContext::default();
+
+        let mut tampered_claim = receipt.clone();
+        tampered_claim.claim =
+            MaybePruned::Value(ReceiptClaim::ok(Digest::new([1; 8]), b"forged".to_vec()));
+        assert_eq!(
+            tampered_claim.verify_with_key(&ctx, vk.clone()),
+            Err(VerificationError::InvalidProof)
+        );
+
+        // Swapping A and C keeps the points on the curve, but breaks the pairing equation.
+        let mut tampered_seal = receipt.clone();
+        tampered_seal.seal[..64].copy_from_slice(&receipt.seal[192..]);
+        tampered_seal.seal[192..].copy_from_slice(&receipt.seal[..64]);
+        assert_eq!(
+            tampered_seal.verify_with_key(&ctx, vk.clone()),
+            Err(VerificationError::InvalidProof)
+        );
+
+        let mut truncated = receipt;
+        truncated.seal.pop();
+        assert_eq!(
+            truncated.verify_with_key(&ctx, vk),
+            Err(VerificationError::ReceiptFormatError)
+        );
+    }
+
+    #[test]
+    fn validate_seal_checks_points() {
+        let (receipt, _) = proven_receipt();
+        assert_eq!(validate_seal(&receipt.seal), Ok(()));
+
+        let mut truncated = receipt.seal.clone();
+        truncated.pop();
+        assert_eq!(
+            validate_seal(&truncated),
+            Err(ConvertError::MalformedReceipt(
+                "Groth16 seal must be 256 bytes"
+            ))
+        );
+
+        let mut off_curve = receipt.seal.clone();
+        off_curve[63] ^= 1;
+        assert_eq!(
+            validate_seal(&off_curve),
+            Err(ConvertError::MalformedReceipt(
+                "Groth16 seal point is not on the BN254 curve"
+            ))
+        );
+
+        // Points of the twist curve are almost never in the prime-order subgroup.
+        let b = (1u64..)
+            .find_map(|x| G2Affine::get_point_from_x_unchecked(Fq2::from(x), false))
+            .unwrap();
+        assert!(!b.is_in_correct_subgroup_assuming_on_curve());
+        let proof = Proof {
+            b,
+            ..decode_proof(&receipt.seal).unwrap()
+        };
+        assert_eq!(
+            validate_seal(&encode_proof(&proof)),
+            Err(ConvertError::MalformedReceipt(
+                "Groth16 seal point is not in the BN254 prime-order subgroup"
+            ))
+        );
+    }
+    #[test]
+    fn verifying_key_at_infinity_is_rejected() {
+        let mut vk = verifying_key();
+        vk.gamma_abc_g1[2] = G1Affine::zero();
+        assert_eq!(
+            verifying_key_digest::<sha::Impl>(&vk),
+            Err(ConvertError::EncodeError(
+                "verifying key point is the point at infinity".to_string()
+            ))
+        );
+        let params = Groth16ReceiptVerifierParameters::new(
+            ALLOWED_CONTROL_ROOT,
+            BN254_IDENTITY_CONTROL_ID,
+            vk,
+        );
+        assert!(params.is_err());
+
+        let params = Groth16ReceiptVerifierParameters::new(
+            ALLOWED_CONTROL_ROOT,
+            BN254_IDENTITY_CONTROL_ID,
+            verifying_key(),
+        )
+        .unwrap();
+        assert_eq!(
+            params.digest::<sha::Impl>(),
+            Groth16ReceiptVerifierParameters::default().digest::<sha::Impl>()
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Hash functions used to build the Merkle trees of STARK receipts.
+
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+use crate::{sha, Digest, Sha256};
+
+pub mod baby_bear;
+pub mod poseidon2;
+
+/// A hash function over [Digest]s and Baby Bear field elements.
+pub trait HashFn: Send + Sync {
+    /// Generate a hash from a pair of [Digest].
+    fn hash_pair(&self, a: &Digest, b: &Digest) -> Box<Digest>;
+
+    /// Generate a hash from a slice of field elements. This may be unpadded, so it is only safe
+    /// to use when the size is known.
+    fn hash_elem_slice(&self, slice: &[baby_bear::Elem]) -> Box<Digest>;
+}
+
+/// The [HashFn] of the `sha-256` hash suite.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sha256HashFn;
+
+impl HashFn for Sha256HashFn {
+    fn hash_pair(&self, a: &Digest, b: &Digest) -> Box<Digest> {
+        sha::Impl::hash_pair(a, b)
+    }
+
+    fn hash_elem_slice(&self, slice: &[baby_bear::Elem]) -> Box<Digest> {
+        let words: Vec<u32> = slice
+            .iter()
+            .map(baby_bear::Elem::as_u32_montgomery)
+            .collect();
+        sha::Impl::hash_raw_data_slice(&words)
+    }
+}
+
+/// A named hash function, as referenced by the `hashfn` field of receipts.
+#[derive(Clone)]
+pub struct HashSuite {
+    /// The name of this hash suite, e.g. "sha-256".
+    pub name: String,
+
+    /// The hash function of this suite.
+    pub hashfn: Arc<dyn HashFn>,
+}
+
+impl core::fmt::Debug for HashSuite {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HashSuite")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HashSuite {
+    /// Create a hash suite from a name and a hash function.
+    pub fn new(name: impl Into<String>, hashfn: impl HashFn + 'static) -> Self {
+        HashSuite {
+            name: name.into(),
+            hashfn: Arc::new(hashfn),
+        }
+    }
+}
+
+/// Hash suites registered in addition to the built-in ones.
+#[cfg(feature = "std")]
+static REGISTERED_SUITES: RwLock<Vec<HashSuite>> = RwLock::new(Vec::new());
+
+/// Register an additional hash suite, to be returned by [hash_suite_from_name]. A suite with the
+/// name of a built-in or previously registered suite replaces it.
+#[cfg(feature = "std")]
+pub fn register_hash_suite(suite: HashSuite) {
+    let mut suites = REGISTERED_SUITES
+        .write()
+        .unwrap_or_else(|err| err.into_inner());
+    suites.retain(|registered| registered.name != suite.name);
+    suites.push(suite);
+}
+
+/// Look up a hash suite by the name stored in the `hashfn` field of receipts.
+pub fn hash_suite_from_name(name: impl AsRef<str>) -> Option<HashSuite> {
+    let name = name.as_ref();
+    #[cfg(feature = "std")]
+    {
+        let suites = REGISTERED_SUITES
+            .read()
+            .unwrap_or_else(|err| err.into_inner());
+        if let Some(suite) = suites.iter().find(|suite| suite.name == name) {
+            return Some(suite.clone());
+        }
+    }
+    match name {
+        "sha-256" => Some(HashSuite::new(name, Sha256HashFn)),
+        "poseidon2" => Some(HashSuite::new(name, poseidon2::Poseidon2HashFn)),
+        _ => None,
+    }
+}
@@ -0,0 +1,148 @@
+//! Arithmetic in the Baby Bear field, of prime order `15 * 2^27 + 1`.
+//!
+//! Elements are kept in Montgomery form, which is also the form in which they are hashed.
+
+use core::ops;
+
+/// The field modulus.
+pub const P: u32 = 15 * (1 << 27) + 1;
+
+const P_U64: u64 = P as u64;
+
+/// `-P^-1 mod 2^32`, used for Montgomery reduction.
+const M: u32 = 0x88000001;
+
+/// `2^64 mod P`, used to convert into Montgomery form.
+const R2: u32 = 1172168163;
+
+/// An element of the Baby Bear field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Elem(u32);
+
+impl Elem {
+    pub const ZERO: Elem = Elem::new(0);
+    pub const ONE: Elem = Elem::new(1);
+
+    /// Create an element from an integer, reducing it modulo [P].
+    pub const fn new(x: u32) -> Self {
+        Elem(encode(x % P))
+    }
+
+    /// Create an element from its Montgomery form representation.
+    pub const fn new_raw(x: u32) -> Self {
+        Elem(x)
+    }
+
+    /// The integer value of this element.
+    pub const fn as_u32(&self) -> u32 {
+        decode(self.0)
+    }
+
+    /// The Montgomery form representation, used when hashing elements.
+    pub const fn as_u32_montgomery(&self) -> u32 {
+        self.0
+    }
+
+    /// Whether the representation is less than the modulus.
+    pub fn is_reduced(&self) -> bool {
+        self.0 < P
+    }
+}
+
+impl ops::Add for Elem {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Elem(add(self.0, rhs.0))
+    }
+}
+
+impl ops::AddAssign for Elem {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 = add(self.0, rhs.0)
+    }
+}
+
+impl ops::Mul for Elem {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Elem(mul(self.0, rhs.0))
+    }
+}
+
+impl ops::MulAssign for Elem {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 = mul(self.0, rhs.0)
+    }
+}
+
+fn add(lhs: u32, rhs: u32) -> u32 {
+    let x = lhs.wrapping_add(rhs);
+    if x >= P {
+        x - P
+    } else {
+        x
+    }
+}
+
+/// Montgomery multiplication.
+const fn mul(lhs: u32, rhs: u32) -> u32 {
+    let mut o64: u64 = (lhs as u64).wrapping_mul(rhs as u64);
+    let low: u32 = 0u32.wrapping_sub(o64 as u32);
+    let red = M.wrapping_mul(low);
+    o64 += (red as u64).wrapping_mul(P_U64);
+    let ret = (o64 >> 32) as u32;
+    if ret >= P {
+        ret - P
+    } else {
+        ret
+    }
+}
+
+/// Encode to Montgomery form from direct form.
+const fn encode(a: u32) -> u32 {
+    mul(R2, a)
+}
+
+/// Decode from Montgomery form to direct form.
+const fn decode(a: u32) -> u32 {
+    mul(1, a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mod_mul(a: u32, b: u32) -> u32 {
+        (a as u64 * b as u64 % P_U64) as u32
+    }
+
+    #[test]
+    fn montgomery_round_trip() {
+        for x in [0, 1, 2, P - 1, P / 2, 0x1234_5678 % P] {
+            assert_eq!(Elem::new(x).as_u32(), x);
+            assert!(Elem::new(x).is_reduced());
+        }
+        assert_eq!(Elem::new(P), Elem::ZERO);
+        assert_eq!(Elem::new(P + 5).as_u32(), 5);
+        // One in Montgomery form is 2^32 mod P.
+        assert_eq!(Elem::ONE.as_u32_montgomery() as u64, (1u64 << 32) % P_U64);
+    }
+
+    #[test]
+    fn arithmetic_matches_integers() {
+        let values = [0, 1, 2, P - 1, P - 2, P / 3, 0x0765_4321];
+        for a in values {
+            for b in values {
+                assert_eq!((Elem::new(a) + Elem::new(b)).as_u32(), (a + b) % P);
+                assert_eq!((Elem::new(a) * Elem::new(b)).as_u32(), mod_mul(a, b));
+            }
+        }
+        let mut x = Elem::new(P - 1);
+        x *= x;
+        assert_eq!(x, Elem::ONE);
+        x += Elem::new(P - 1);
+        assert_eq!(x, Elem::ZERO);
+    }
+}
@@ -0,0 +1,97 @@
+//! JavaScript views of the parts of a [ReceiptClaim](crate::ReceiptClaim) that are reported by
+//! receipt handles and verification reports.
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{Digestible, sha, Assumption, Digest, ExitCode, MaybePruned};
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen(typescript_custom_section)]
+const KINDS_TS: &str = r#"
+export type ReceiptKind = "Composite" | "Succinct" | "Groth16" | "Fake" | "SetInclusion";
+export type ExitCodeKind = "Halted" | "Paused" | "SystemSplit" | "SessionLimit";
+"#;
+
+/// JavaScript view of an [ExitCode], e.g. `{ kind: "Halted", code: 0 }`.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Debug)]
+pub struct ExitCodeInfo {
+    kind: &'static str,
+    code: Option<u32>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ExitCodeInfo {
+    /// One of "Halted", "Paused", "SystemSplit" or "SessionLimit".
+    #[cfg_attr(
+        feature = "wasm",
+        wasm_bindgen(getter, unchecked_return_type = "ExitCodeKind")
+    )]
+    pub fn kind(&self) -> String {
+        self.kind.to_string()
+    }
+
+    /// User exit code, undefined for system initiated exits.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn code(&self) -> Option<u32> {
+        self.code
+    }
+}
+
+impl From<ExitCode> for ExitCodeInfo {
+    fn from(exit_code: ExitCode) -> Self {
+        Self {
+            kind: exit_code.kind(),
+            code: exit_code.user_code(),
+        }
+    }
+}
+
+/// JavaScript view of an [Assumption] of the receipt claim, which may be pruned to its digest.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Debug)]
+pub struct AssumptionInfo {
+    digest: Digest,
+    assumption: Option<Assumption>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl AssumptionInfo {
+    /// Whether only the digest of the assumption is included in the receipt.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn pruned(&self) -> bool {
+        self.assumption.is_none()
+    }
+
+    /// Digest of the assumption.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn digest(&self) -> Vec<u8> {
+        self.digest.as_bytes().to_vec()
+    }
+
+    /// Digest of the claim assumed to be proven, undefined if pruned.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn claim(&self) -> Option<Vec<u8>> {
+        self.assumption
+            .as_ref()
+            .map(|assumption| assumption.claim.as_bytes().to_vec())
+    }
+
+    /// Control root the assumed claim must be proven under, undefined if pruned.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn control_root(&self) -> Option<Vec<u8>> {
+        self.assumption
+            .as_ref()
+            .map(|assumption| assumption.control_root.as_bytes().to_vec())
+    }
+}
+
+impl From<&MaybePruned<Assumption>> for AssumptionInfo {
+    fn from(assumption: &MaybePruned<Assumption>) -> Self {
+        Self {
+            digest: assumption.digest::<sha::Impl>(),
+            assumption: assumption.as_value().ok().cloned(),
+        }
+    }
+}
@@ -0,0 +1,1995 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::Serialize;
+
+use serde::de::{self,Deserializer, SeqAccess, Visitor};
+use core::fmt;
+#[cfg(feature = "borsh")]
+use borsh::{BorshDeserialize, BorshSerialize};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use bytemuck::{Pod, Zeroable};
+use core::borrow::Borrow;
+
+extern crate alloc;
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::ops::DerefMut;
+
+use ethereum::{abi_append_bytes, abi_word, WORD_BYTES};
+use hash::{hash_suite_from_name, HashFn};
+use encoder::{EvmSealEncoder, SealEncoder};
+use selector::{Selector, SELECTOR_BYTES};
+
+#[cfg(feature = "abi")]
+pub mod abi;
+#[cfg(feature = "std")]
+pub mod aggregation;
+#[cfg(feature = "std")]
+pub mod batch;
+#[cfg(feature = "std")]
+pub mod blob;
+#[cfg(feature = "json")]
+pub mod cosmwasm;
+pub mod encoder;
+pub mod ethereum;
+#[cfg(feature = "std")]
+pub mod explain;
+#[cfg(feature = "std")]
+pub mod groth16;
+pub mod hash;
+#[cfg(feature = "std")]
+pub mod info;
+pub mod limits;
+#[cfg(feature = "std")]
+pub mod move_vm;
+#[cfg(all(feature = "borsh", feature = "json"))]
+pub mod near;
+#[cfg(feature = "std")]
+pub mod options;
+#[cfg(feature = "rlp")]
+pub mod rlp;
+pub mod selector;
+pub mod sha;
+#[cfg(all(feature = "std", feature = "borsh"))]
+pub mod solana;
+#[cfg(feature = "std")]
+pub mod ssz;
+#[cfg(feature = "std")]
+pub mod starknet;
+#[cfg(feature = "std")]
+pub mod stats;
+#[cfg(feature = "std")]
+pub mod succinct;
+#[cfg(feature = "std")]
+pub mod verify;
+
+pub const DIGEST_WORDS: usize = 8;
+
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Hash,
+    Pod,
+    Debug,
+    Zeroable,
+)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[repr(transparent)]
+pub struct Digest([u32; DIGEST_WORDS]);
+
+impl Serialize for Digest {
+    /// Human-readable formats such as JSON get a `0x`-prefixed hex string, binary formats the
+    /// compact array of words.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_newtype_struct("Digest", &self.0)
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Digest {
+    /// Accepts a hex string as well as the array of words, so that JSON receipts written by other
+    /// tools keep decoding.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DigestVisitor;
+
+        impl<'de> Visitor<'de> for DigestVisitor {
+            type Value = Digest;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a hex string or an array of 8 words")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Digest, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_newtype_struct<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Digest, D::Error> {
+                <[u32; DIGEST_WORDS] as serde::Deserialize>::deserialize(deserializer).map(Digest)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Digest, A::Error> {
+                let mut words = [0u32; DIGEST_WORDS];
+                for (i, word) in words.iter_mut().enumerate() {
+                    *word = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                Ok(Digest(words))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(DigestVisitor)
+        } else {
+            deserializer.deserialize_newtype_struct("Digest", DigestVisitor)
+        }
+    }
+}
+
+impl AsRef<[u8; DIGEST_BYTES]> for Digest {
+    fn as_ref(&self) -> &[u8; DIGEST_BYTES] {
+        bytemuck::cast_ref(&self.0)
+    }
+}
+
+
+/// A `(sys_exit, user_exit)` pair that does not encode an [ExitCode].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid exit code pair ({0}, {1})")]
+pub struct InvalidExitCodeError(pub u32, pub u32);
+
+#[derive(Clone, Copy, Debug, Serialize,serde::Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+pub enum ExitCode {
+    Halted(u32),
+    Paused(u32),
+    SystemSplit,
+    SessionLimit,
+}
+
+impl ExitCode {
+    /// Convert this [ExitCode] into a pair representation, where the first number is the "system"
+    /// part, and the second is the "user" part. E.g. Halted(255) -> (0, 255)
+    pub fn into_pair(self) -> (u32, u32) {
+        match self {
+            ExitCode::Halted(user_exit) => (0, user_exit),
+            ExitCode::Paused(user_exit) => (1, user_exit),
+            ExitCode::SystemSplit => (2, 0),
+            ExitCode::SessionLimit => (2, 2),
+        }
+    }
+
+    /// Convert this [ExitCode] from its pair representation, where the first number is the "system"
+    /// part, and the second is the "user" part. E.g. (0, 255) -> Halted(255)
+    ///
+    /// System exits are told apart by their user part, as written by [ExitCode::into_pair]. Any
+    /// other user part is read as SystemSplit, which is how older encoders wrote every system
+    /// exit.
+    pub fn from_pair(sys_exit: u32, user_exit: u32) -> Result<ExitCode, InvalidExitCodeError> {
+        match (sys_exit, user_exit) {
+            (0, _) => Ok(ExitCode::Halted(user_exit)),
+            (1, _) => Ok(ExitCode::Paused(user_exit)),
+            (2, 2) => Ok(ExitCode::SessionLimit),
+            (2, _) => Ok(ExitCode::SystemSplit),
+            _ => Err(InvalidExitCodeError(sys_exit, user_exit)),
+        }
+    }
+
+    /// Same as [ExitCode::from_pair], but only accepts the exact pairs produced by
+    /// [ExitCode::into_pair], so that every accepted pair round-trips.
+    pub fn from_pair_strict(
+        sys_exit: u32,
+        user_exit: u32,
+    ) -> Result<ExitCode, InvalidExitCodeError> {
+        let exit_code = ExitCode::from_pair(sys_exit, user_exit)?;
+        if exit_code.into_pair() != (sys_exit, user_exit) {
+            return Err(InvalidExitCodeError(sys_exit, user_exit));
+        }
+        Ok(exit_code)
+    }
+
+    /// Whether the verifier should expect a non-empty output field. Exit codes Halted and Paused
+    /// produce can produce a non-empty outputs, whereas system initiated exits like SystemSplit do
+    /// not.
+    pub fn expects_output(&self) -> bool {
+        match self {
+            ExitCode::Halted(_) | ExitCode::Paused(_) => true,
+            ExitCode::SystemSplit | ExitCode::SessionLimit => false,
+        }
+    }
+
+    /// True if the exit code is Halted(0), indicating the program guest exited with an ok status.
+    pub fn is_ok(&self) -> bool {
+        matches!(self, ExitCode::Halted(0))
+    }
+
+    /// Name of this exit code variant, e.g. "Halted".
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ExitCode::Halted(_) => "Halted",
+            ExitCode::Paused(_) => "Paused",
+            ExitCode::SystemSplit => "SystemSplit",
+            ExitCode::SessionLimit => "SessionLimit",
+        }
+    }
+
+    /// The user exit code, for exits initiated by the guest.
+    pub fn user_code(&self) -> Option<u32> {
+        match self {
+            ExitCode::Halted(user_exit) | ExitCode::Paused(user_exit) => Some(*user_exit),
+            ExitCode::SystemSplit | ExitCode::SessionLimit => None,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, derive_more::with_trait::Debug,serde::Deserialize, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+pub struct SystemState {
+    /// The program counter.
+    pub pc: u32,
+
+    /// The root hash of a merkle tree which confirms the
+    /// integrity of the memory image.
+    pub merkle_root: Digest,
+}
+
+impl Digestible for SystemState {
+    /// Hash the [crate::SystemState] to get a digest of the struct.
+    fn digest<S: Sha256>(&self) -> Digest {
+        tagged_struct::<S>("risc0.SystemState", &[self.merkle_root], &[self.pc])
+    }
+}
+
+
+#[derive(Clone, Debug, Serialize,serde::Deserialize)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum Unknown {}
+
+#[cfg(feature = "borsh")]
+impl BorshSerialize for Unknown {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        match *self { /* unreachable  */ }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl BorshDeserialize for Unknown {
+    fn deserialize_reader<R: borsh::io::Read>(_reader: &mut R) -> borsh::io::Result<Self> {
+        Err(borsh::io::Error::new(
+            borsh::io::ErrorKind::InvalidData,
+            "Unknown is uninhabited and cannot be deserialized",
+        ))
+    }
+}
+
+impl Digestible for Unknown {
+    fn digest<S: Sha256>(&self) -> Digest {
+        match *self { /* unreachable  */ }
+    }
+}
+
+#[derive(Clone, Debug, Serialize,serde::Deserialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct Input {
+    // Private field to ensure this type cannot be constructed.
+    // By making this type uninhabited, it can be populated later without breaking backwards
+    // compatibility.
+    pub(crate) x: Unknown,
+}
+
+impl Digestible for Input {
+    /// Hash the [Input] to get a digest of the struct.
+    fn digest<S: Sha256>(&self) -> Digest {
+        match self.x { /* unreachable  */ }
+    }
+}
+
+#[derive(Clone, Debug, Serialize,serde::Deserialize, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+pub struct Assumption {
+    pub claim: Digest,
+    pub control_root: Digest,
+}
+
+impl Digestible for Assumption {
+    /// Hash the [Assumption] to get a digest of the struct.
+    fn digest<S: Sha256>(&self) -> Digest {
+        tagged_struct::<S>("risc0.Assumption", &[self.claim, self.control_root], &[])
+    }
+}
+
+
+#[derive(Clone, Default, Debug, Serialize,serde::Deserialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct Assumptions(
+    #[serde(deserialize_with = "limits::fields::assumptions")]
+    #[cfg_attr(feature = "borsh", borsh(deserialize_with = "limits::fields::assumptions_borsh"))]
+    pub Vec<MaybePruned<Assumption>>,
+);
+impl Digestible for Assumptions {
+    /// Hash the [Assumptions] to get a digest of the struct.
+    fn digest<S: Sha256>(&self) -> Digest {
+        tagged_list::<S>(
+            "risc0.Assumptions",
+            &self.0.iter().map(|a| a.digest::<S>()).collect::<Vec<_>>(),
+        )
+    }
+}
+
+pub fn tagged_list<S: Sha256>(tag: &str, list: &[impl Borrow<Digest>]) -> Digest {
+    tagged_iter::<S>(tag, list.iter().map(|x| x.borrow()))
+}
+pub fn tagged_iter<S: Sha256>(
+    tag: &str,
+    iter: impl DoubleEndedIterator<Item = impl Borrow<Digest>>,
+) -> Digest {
+    iter.rfold(Digest::ZERO, |list_digest, elem| {
+        tagged_list_cons::<S>(tag, elem.borrow(), &list_digest)
+    })
+}
+
+pub fn tagged_list_cons<S: Sha256>(tag: &str, head: &Digest, tail: &Digest) -> Digest {
+    tagged_struct::<S>(tag, &[head, tail], &[])
+}
+
+
+
+#[derive(Clone, derive_more::with_trait::Debug, Serialize,serde::Deserialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct Output {
+    /// The journal committed to by the guest execution.
+    #[debug("{}", fmt_debug_journal(journal))]
+    pub journal: MaybePruned<Vec<u8>>,
+
+    /// An ordered list of [ReceiptClaim] digests corresponding to the
+    /// calls to `env::verify` and `env::verify_integrity`.
+    ///
+    /// Verifying the integrity of a [crate::Receipt] corresponding to a [ReceiptClaim] with a
+    /// non-empty assumptions list does not guarantee unconditionally any of the claims over the
+    /// guest execution (i.e. if the assumptions list is non-empty, then the journal digest cannot
+    /// be trusted to correspond to a genuine execution). The claims can be checked by additional
+    /// verifying a [crate::Receipt] for every digest in the assumptions list.
+    pub assumptions: MaybePruned<Assumptions>,
+}
+
+impl Digestible for Output {
+    /// Hash the [Output] to get a digest of the struct.
+    fn digest<S: Sha256>(&self) -> Digest {
+        tagged_struct::<S>(
+            "risc0.Output",
+            &[self.journal.digest::<S>(), self.assumptions.digest::<S>()],
+            &[],
+        )
+    }
+}
+
+
+#[derive(Clone,serde::Deserialize, derive_more::with_trait::Debug, Serialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum MaybePruned<T>
+where
+    T: Clone + Serialize,
+{
+    /// Unpruned value.
+    Value(T),
+
+    /// Pruned value, which is a hash [Digest] of the value.
+    Pruned(Digest),
+}
+
+impl<T> MaybePruned<T>
+where
+    T: Clone + Serialize,
+{
+    /// Unwrap the value, or error if it was pruned.
+    pub fn as_value(&self) -> Result<&T, ConvertError> {
+        match self {
+            MaybePruned::Value(ref value) => Ok(value),
+            MaybePruned::Pruned(_) => Err(ConvertError::PrunedClaim),
+        }
+    }
+}
+
+impl<T> Digestible for MaybePruned<T>
+where
+    T: Digestible + Clone + Serialize,
+{
+    fn digest<S: Sha256>(&self) -> Digest {
+        match self {
+            MaybePruned::Value(ref val) => val.digest::<S>(),
+            MaybePruned::Pruned(digest) => *digest,
+        }
+    }
+}
+
+
+
+#[derive(Clone, Debug,serde::Deserialize, Serialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct ReceiptClaim {
+    /// The [SystemState] just before execution has begun.
+    pub pre: MaybePruned<SystemState>,
+
+    /// The [SystemState] just after execution has completed.
+    pub post: MaybePruned<SystemState>,
+
+    /// The exit code for the execution.
+    pub exit_code: ExitCode,
+
+    /// Input to the guest.
+    pub input: MaybePruned<Option<Input>>,
+
+    /// [Output] of the guest, including the journal and assumptions set during execution.
+    pub output: MaybePruned<Option<Output>>,
+}
+
+impl Digestible for ReceiptClaim {
+    /// Hash the [ReceiptClaim] to get a digest of the struct.
+    fn digest<S: Sha256>(&self) -> Digest {
+        let (sys_exit, user_exit) = self.exit_code.into_pair();
+        tagged_struct::<S>(
+            "risc0.ReceiptClaim",
+            &[
+                self.input.digest::<S>(),
+                self.pre.digest::<S>(),
+                self.post.digest::<S>(),
+                self.output.digest::<S>(),
+            ],
+            &[sys_exit, user_exit],
+        )
+    }
+}
+
+impl ReceiptClaim {
+    /// Assumptions made by the guest through composition, in order. Empty if the execution
+    /// produced no output.
+    pub fn assumptions(&self) -> Result<&[MaybePruned<Assumption>], ConvertError> {
+        match self.output.as_value()? {
+            None => Ok(&[]),
+            Some(output) => Ok(&output.assumptions.as_value()?.0),
+        }
+    }
+
+    /// Construct a [ReceiptClaim] representing a zkVM execution that ended normally (i.e.
+    /// Halted(0)) with the given image ID and journal.
+    pub fn ok(image_id: Digest, journal: Vec<u8>) -> Self {
+        ReceiptClaim {
+            pre: MaybePruned::Pruned(image_id),
+            post: MaybePruned::Value(SystemState { pc: 0, merkle_root: Digest::ZERO }),
+            exit_code: ExitCode::Halted(0),
+            input: MaybePruned::Value(None),
+            output: MaybePruned::Value(Some(Output {
+                journal: MaybePruned::Value(journal),
+                assumptions: MaybePruned::Value(Assumptions(Vec::new())),
+            })),
+        }
+    }
+}
+
+pub fn tagged_struct<S: Sha256>(tag: &str, down: &[impl Borrow<Digest>], data: &[u32]) -> Digest {
+    let tag_digest: Digest = *S::hash_bytes(tag.as_bytes());
+    #[allow(clippy::manual_slice_size_calculation)]
+    let mut all = Vec::<u8>::with_capacity(
+        DIGEST_BYTES * (down.len() + 1) + size_of::<u32>() * data.len() + size_of::<u16>(),
+    );
+    all.extend_from_slice(tag_digest.as_bytes());
+    for digest in down {
+        all.extend_from_slice(digest.borrow().as_ref());
+    }
+    for word in data.iter().copied() {
+        all.extend_from_slice(&word.to_le_bytes());
+    }
+    let down_count: u16 = down
+        .len()
+        .try_into()
+        .expect("struct defined with more than 2^16 fields");
+    all.extend_from_slice(&down_count.to_le_bytes());
+    *S::hash_bytes(&all)
+}
+
+#[derive(Clone, derive_more::with_trait::Debug,serde::Deserialize, Serialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[non_exhaustive]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct SegmentReceipt {
+    /// The cryptographic data attesting to the validity of the code execution.
+    ///
+    /// This data is used by the ZKP Verifier (as called by
+    /// [SegmentReceipt::verify_integrity_with_context]) to cryptographically prove that this
+    /// Segment was faithfully executed. It is largely opaque cryptographic data, but contains a
+    /// non-opaque claim component, which can be conveniently accessed with
+    /// [SegmentReceipt::claim].
+    #[debug("{} bytes", self.get_seal_bytes().len())]
+    #[serde(deserialize_with = "limits::fields::seal_words")]
+    #[cfg_attr(feature = "borsh", borsh(deserialize_with = "limits::fields::seal_words_borsh"))]
+    pub seal: Vec<u32>,
+
+    /// Segment index within the [Receipt](crate::Receipt)
+    pub index: u32,
+
+    /// Name of the hash function used to create this receipt.
+    pub hashfn: String,
+
+    /// A digest of the verifier parameters that can be used to verify this receipt.
+    ///
+    /// Acts as a fingerprint to identity differing proof system or circuit versions between a
+    /// prover and a verifier. Is not intended to contain the full verifier parameters, which must
+    /// be provided by a trusted source (e.g. packaged with the verifier code).
+    pub verifier_parameters: Digest,
+
+    /// [ReceiptClaim] containing information about the execution that this receipt proves.
+    pub claim: ReceiptClaim,
+}
+
+
+impl SegmentReceipt {
+    /// Construct a [SegmentReceipt] from its seal and the segment it proves.
+    pub fn new(
+        seal: Vec<u32>,
+        index: u32,
+        hashfn: String,
+        verifier_parameters: Digest,
+        claim: ReceiptClaim,
+    ) -> Self {
+        Self {
+            seal,
+            index,
+            hashfn,
+            verifier_parameters,
+            claim,
+        }
+    }
+
+    /// Return the seal for this receipt, as a vector of bytes.
+    pub fn get_seal_bytes(&self) -> Vec<u8> {
+        self.seal.iter().flat_map(|x| x.to_le_bytes()).collect()
+    }
+
+    /// Number of bytes used by the seal for this receipt.
+    pub fn seal_size(&self) -> usize {
+        core::mem::size_of_val(self.seal.as_slice())
+    }
+}
+
+
+#[derive(Clone, Debug,serde::Deserialize, Serialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(test, derive(PartialEq))]
+#[non_exhaustive]
+pub enum InnerAssumptionReceipt {
+    /// A non-succinct [CompositeReceipt], made up of one inner receipt per segment and assumption.
+    Composite(CompositeReceipt),
+
+    /// A [SuccinctReceipt], proving arbitrarily the claim with a single STARK.
+    Succinct(SuccinctReceipt<Unknown>),
+
+    /// A [Groth16Receipt], proving arbitrarily the claim with a single Groth16 SNARK.
+    Groth16(Groth16Receipt<Unknown>),
+
+    /// A [FakeReceipt], with no cryptographic integrity, used only for development.
+    Fake(FakeReceipt<Unknown>),
+
+    /// A [SetInclusionReceipt], proving the claim by inclusion in an aggregated set.
+    SetInclusion(SetInclusionReceipt<Unknown>),
+}
+
+impl InnerAssumptionReceipt {
+    /// Name of this receipt kind, e.g. "Groth16".
+    pub fn kind(&self) -> &'static str {
+        match self {
+            InnerAssumptionReceipt::Composite(_) => "Composite",
+            InnerAssumptionReceipt::Succinct(_) => "Succinct",
+            InnerAssumptionReceipt::Groth16(_) => "Groth16",
+            InnerAssumptionReceipt::Fake(_) => "Fake",
+            InnerAssumptionReceipt::SetInclusion(_) => "SetInclusion",
+        }
+    }
+
+    /// Digest of the claim proven by this receipt.
+    pub fn claim_digest(&self) -> Result<Digest, ConvertError> {
+        match self {
+            InnerAssumptionReceipt::Composite(receipt) => {
+                Ok(receipt.claim()?.digest::<sha::Impl>())
+            }
+            InnerAssumptionReceipt::Succinct(receipt) => Ok(receipt.claim.digest::<sha::Impl>()),
+            InnerAssumptionReceipt::Groth16(receipt) => Ok(receipt.claim.digest::<sha::Impl>()),
+            InnerAssumptionReceipt::Fake(receipt) => Ok(receipt.claim.digest::<sha::Impl>()),
+            InnerAssumptionReceipt::SetInclusion(receipt) => {
+                Ok(receipt.claim.digest::<sha::Impl>())
+            }
+        }
+    }
+
+    /// Digest of the verifier parameters this receipt was produced for.
+    pub fn verifier_parameters(&self) -> Digest {
+        match self {
+            InnerAssumptionReceipt::Composite(receipt) => receipt.verifier_parameters,
+            InnerAssumptionReceipt::Succinct(receipt) => receipt.verifier_parameters,
+            InnerAssumptionReceipt::Groth16(receipt) => receipt.verifier_parameters,
+            InnerAssumptionReceipt::Fake(_) => Digest::ZERO,
+            InnerAssumptionReceipt::SetInclusion(receipt) => receipt.verifier_parameters,
+        }
+    }
+
+    /// Total number of bytes used by the seals of this receipt.
+    pub fn seal_size(&self) -> usize {
+        match self {
+            InnerAssumptionReceipt::Composite(receipt) => receipt.seal_size(),
+            InnerAssumptionReceipt::Succinct(receipt) => receipt.seal_size(),
+            InnerAssumptionReceipt::Groth16(receipt) => receipt.seal_size(),
+            InnerAssumptionReceipt::Fake(_) => 0,
+            InnerAssumptionReceipt::SetInclusion(receipt) => receipt.seal_size(),
+        }
+    }
+}
+
+#[derive(Clone, Debug,serde::Deserialize, Serialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct CompositeReceipt {
+    /// Segment receipts forming the proof of an execution with continuations.
+    #[serde(deserialize_with = "limits::fields::segments")]
+    #[cfg_attr(feature = "borsh", borsh(deserialize_with = "limits::fields::segments_borsh"))]
+    pub segments: Vec<SegmentReceipt>,
+
+    /// An ordered list of assumptions, either proven or unresolved, made within
+    /// the continuation represented by the segment receipts. If any
+    /// assumptions are unresolved, this receipt is only _conditionally_
+    /// valid.
+    // TODO(#982): Allow for unresolved assumptions in this list.
+    #[serde(deserialize_with = "limits::fields::assumptions")]
+    #[cfg_attr(feature = "borsh", borsh(deserialize_with = "limits::fields::assumptions_borsh"))]
+    pub assumption_receipts: Vec<InnerAssumptionReceipt>,
+
+    /// A digest of the verifier parameters that can be used to verify this receipt.
+    ///
+    /// Acts as a fingerprint to identity differing proof system or circuit versions between a
+    /// prover and a verifier. Is not intended to contain the full verifier parameters, which must
+    /// be provided by a trusted source (e.g. packaged with the verifier code).
+    pub verifier_parameters: Digest,
+}
+
+impl CompositeReceipt {
+    /// Returns the [ReceiptClaim] for this [CompositeReceipt], built from the first and last
+    /// segment claims.
+    pub fn claim(&self) -> Result<ReceiptClaim, ConvertError> {
+        let first_claim = &self
+            .segments
+            .first()
+            .ok_or(ConvertError::MalformedReceipt("composite receipt has no segments"))?
+            .claim;
+        let last_claim = &self
+            .segments
+            .last()
+            .ok_or(ConvertError::MalformedReceipt("composite receipt has no segments"))?
+            .claim;
+
+        // Remove the assumptions from the last receipt claim, as the verify routine requires every
+        // assumption to have an associated verifiable receipt.
+        let output = last_claim.output.as_value()?.as_ref().map(|output| Output {
+            journal: output.journal.clone(),
+            assumptions: MaybePruned::Value(Assumptions::default()),
+        });
+
+        Ok(ReceiptClaim {
+            pre: first_claim.pre.clone(),
+            post: last_claim.post.clone(),
+            exit_code: last_claim.exit_code,
+            input: first_claim.input.clone(),
+            output: MaybePruned::Value(output),
+        })
+    }
+
+    /// Assumptions made by the guest, as recorded in the claim of the last segment. The
+    /// assumption at index `i` is resolved by the `i`-th entry of `assumption_receipts`.
+    pub fn assumptions(&self) -> Result<&[MaybePruned<Assumption>], ConvertError> {
+        self.segments
+            .last()
+            .ok_or(ConvertError::MalformedReceipt("composite receipt has no segments"))?
+            .claim
+            .assumptions()
+    }
+
+    /// Total number of bytes used by the seals of this receipt.
+    pub fn seal_size(&self) -> usize {
+        let segments: usize = self.segments.iter().map(SegmentReceipt::seal_size).sum();
+        let assumptions: usize = self
+            .assumption_receipts
+            .iter()
+            .map(InnerAssumptionReceipt::seal_size)
+            .sum();
+        segments + assumptions
+    }
+}
+
+#[derive(Clone, Debug,serde::Deserialize, Serialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+pub struct Journal {
+    /// The raw bytes of the journal.
+    #[serde(deserialize_with = "limits::fields::journal")]
+    #[cfg_attr(feature = "borsh", borsh(deserialize_with = "limits::fields::journal_borsh"))]
+    pub bytes: Vec<u8>,
+}
+
+impl Digestible for Journal {
+    /// Hash the [Journal] to get the SHA-256 digest committed to in the claim.
+    fn digest<S: Sha256>(&self) -> Digest {
+        *S::hash_bytes(&self.bytes)
+    }
+}
+
+#[non_exhaustive]
+#[derive(Clone, Debug, Serialize,serde::Deserialize, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+pub struct MerkleProof {
+    /// Index of the leaf for which inclusion is being proven.
+    pub index: u32,
+
+    /// Sibling digests on the path from the root to the leaf.
+    /// Does not include the root of the leaf.
+    pub digests: Vec<Digest>,
+}
+
+impl MerkleProof {
+    /// Construct a Merkle proof for the leaf at `index` from the sibling digests on its path.
+    pub fn new(index: u32, digests: Vec<Digest>) -> Self {
+        Self { index, digests }
+    }
+
+    /// Verify the Merkle inclusion proof against the given leaf and root.
+    pub fn verify(
+        &self,
+        leaf: &Digest,
+        root: &Digest,
+        hashfn: &dyn HashFn,
+    ) -> Result<(), VerificationError> {
+        if self.root(leaf, hashfn) != *root {
+            return Err(VerificationError::InvalidProof);
+        }
+        Ok(())
+    }
+
+    /// Calculate the root of this branch by iteratively hashing, starting from the leaf.
+    pub fn root(&self, leaf: &Digest, hashfn: &dyn HashFn) -> Digest {
+        let mut cur = *leaf;
+        let mut cur_index = self.index;
+        for sibling in &self.digests {
+            cur = if cur_index & 1 == 0 {
+                *hashfn.hash_pair(&cur, sibling)
+            } else {
+                *hashfn.hash_pair(sibling, &cur)
+            };
+            cur_index >>= 1;
+        }
+        cur
+    }
+}
+
+/// Read a [Digest] passed from JavaScript, naming it in the error if it has the wrong length.
+#[cfg(feature = "wasm")]
+pub fn digest_from_js(bytes: &[u8], name: &str) -> Result<Digest, JsError> {
+    Digest::try_from(bytes).map_err(|err| JsError::new(&format!("invalid {name}: {err}")))
+}
+
+/// Format bytes as a `0x`-prefixed lowercase hex string.
+pub fn hex_string(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 + bytes.len() * 2);
+    hex.push_str("0x");
+    for byte in bytes {
+        hex.push_str(&format!("{byte:02x}"));
+    }
+    hex
+}
+
+
+#[derive(Clone, derive_more::with_trait::Debug, Serialize,serde::Deserialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(test, derive(PartialEq))]
+#[non_exhaustive]
+pub struct SuccinctReceipt<Claim>
+where
+    Claim: Digestible + core::fmt::Debug + Clone + Serialize,
+{
+    #[debug("{} bytes", self.get_seal_bytes().len())]
+    #[serde(deserialize_with = "limits::fields::seal_words")]
+    #[cfg_attr(feature = "borsh", borsh(deserialize_with = "limits::fields::seal_words_borsh"))]
+    pub seal: Vec<u32>,
+    pub control_id: Digest,
+    pub claim: MaybePruned<Claim>,
+    pub hashfn: String,
+    pub verifier_parameters: Digest,
+    pub control_inclusion_proof: MerkleProof,
+}
+
+impl<Claim> SuccinctReceipt<Claim>
+where
+    Claim: Digestible + core::fmt::Debug + Clone + Serialize,
+{
+    /// Construct a [SuccinctReceipt] from its seal and the control ID and claim it attests to.
+    pub fn new(
+        seal: Vec<u32>,
+        control_id: Digest,
+        claim: MaybePruned<Claim>,
+        hashfn: String,
+        verifier_parameters: Digest,
+        control_inclusion_proof: MerkleProof,
+    ) -> Self {
+        Self {
+            seal,
+            control_id,
+            claim,
+            hashfn,
+            verifier_parameters,
+            control_inclusion_proof,
+        }
+    }
+
+    /// Return the seal for this receipt, as a vector of bytes.
+    pub fn get_seal_bytes(&self) -> Vec<u8> {
+        self.seal.iter().flat_map(|x| x.to_le_bytes()).collect()
+    }
+
+    /// Number of bytes used by the seal for this receipt.
+    pub fn seal_size(&self) -> usize {
+        core::mem::size_of_val(self.seal.as_slice())
+    }
+
+    /// Root of the Merkle tree of control IDs that the control inclusion proof commits to.
+    pub fn control_root(&self) -> Result<Digest, ConvertError> {
+        let hash_suite = hash_suite_from_name(&self.hashfn)
+            .ok_or_else(|| ConvertError::UnsupportedHashFunction(self.hashfn.clone()))?;
+        Ok(self
+            .control_inclusion_proof
+            .root(&self.control_id, hash_suite.hashfn.as_ref()))
+    }
+
+}
+
+
+#[derive(Clone, derive_more::with_trait::Debug,serde::Deserialize, Serialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(test, derive(PartialEq))]
+#[non_exhaustive]
+pub struct Groth16Receipt<Claim>
+where
+    Claim: Digestible + core::fmt::Debug + Clone + Serialize,
+{
+    /// A Groth16 proof of a zkVM execution with the associated claim.
+    #[debug("{} bytes", seal.len())]
+    #[serde(deserialize_with = "limits::fields::seal_bytes")]
+    #[cfg_attr(feature = "borsh", borsh(deserialize_with = "limits::fields::seal_bytes_borsh"))]
+    pub seal: Vec<u8>,
+
+    /// [ReceiptClaim][crate::ReceiptClaim] containing information about the execution that this
+    /// receipt proves.
+    pub claim: MaybePruned<Claim>,
+
+    /// A digest of the verifier parameters that can be used to verify this receipt.
+    ///
+    /// Acts as a fingerprint to identity differing proof system or circuit versions between a
+    /// prover and a verifier. Is not intended to contain the full verifier parameters, which must
+    /// be provided by a trusted source (e.g. packaged with the verifier code).
+    pub verifier_parameters: Digest,
+}
+
+impl<Claim> Groth16Receipt<Claim>
+where
+    Claim: Digestible + core::fmt::Debug + Clone + Serialize,
+{
+    /// Construct a [Groth16Receipt] from its seal and the claim it proves.
+    pub fn new(seal: Vec<u8>, claim: MaybePruned<Claim>, verifier_parameters: Digest) -> Self {
+        Self {
+            seal,
+            claim,
+            verifier_parameters,
+        }
+    }
+
+    /// Number of bytes used by the seal for this receipt.
+    pub fn seal_size(&self) -> usize {
+        self.seal.len()
+    }
+}
+
+/// Hashing of typed and structured data with a given [Sha256] implementation.
+pub trait Digestible {
+    /// Calculate a collision resistant hash for the typed and structured data.
+    fn digest<S: Sha256>(&self) -> Digest;
+}
+
+impl Digestible for [u8] {
+    fn digest<S: Sha256>(&self) -> Digest {
+        *S::hash_bytes(self)
+    }
+}
+
+impl Digestible for Vec<u8> {
+    fn digest<S: Sha256>(&self) -> Digest {
+        *S::hash_bytes(self)
+    }
+}
+
+impl<D: Digestible> Digestible for [D] {
+    /// A default incremental hashing algorithm for a slice of Digestible elements.
+    ///
+    /// This hashing routine may not be appropriate for add use cases. In particular, it is not a
+    /// PRF and cannot be used as a MAC. Given a digest of a list, anyone can compute the digest of
+    /// that list with additional elements appended to the front of the list. It also does not
+    /// domain separate typed data, and the digest of an empty slice is the zero digest.
+    fn digest<S: Sha256>(&self) -> Digest {
+        self.iter().rfold(Digest::ZERO, |accum, item| {
+            *S::hash_bytes(&[accum.as_bytes(), item.digest::<S>().as_bytes()].concat())
+        })
+    }
+}
+
+impl<T: Digestible> Digestible for Option<T> {
+    fn digest<S: Sha256>(&self) -> Digest {
+        match self {
+            Some(val) => val.digest::<S>(),
+            None => Digest::ZERO,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize,serde::Deserialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(test, derive(PartialEq))]
+#[non_exhaustive]
+pub struct FakeReceipt<Claim>
+where
+    Claim: Digestible + derive_more::with_trait::Debug + Clone + Serialize,
+{
+    /// Claim containing information about the computation that this receipt pretends to prove.
+    ///
+    /// The standard claim type is [ReceiptClaim], which represents a RISC-V zkVM execution.
+    pub claim: MaybePruned<Claim>,
+}
+
+impl<Claim> FakeReceipt<Claim>
+where
+    Claim: Digestible + derive_more::with_trait::Debug + Clone + Serialize,
+{
+    /// Construct a [FakeReceipt] for the given claim.
+    pub fn new(claim: MaybePruned<Claim>) -> Self {
+        Self { claim }
+    }
+}
+
+#[derive(Clone, Debug, Serialize,serde::Deserialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(test, derive(PartialEq))]
+#[non_exhaustive]
+pub struct SetInclusionReceipt<Claim>
+where
+    Claim: Digestible + core::fmt::Debug + Clone + Serialize,
+{
+    /// Claim proven by its inclusion in the aggregated set.
+    pub claim: MaybePruned<Claim>,
+
+    /// Sibling digests on the path from the claim leaf up to the set root.
+    pub merkle_path: Vec<Digest>,
+
+    /// Receipt proving the set root.
+    ///
+    /// May be omitted when the root has already been verified and cached by the on-chain
+    /// `RiscZeroSetVerifier`, in which case the encoded seal carries an empty root seal.
+    pub root: Option<Box<InnerAssumptionReceipt>>,
+
+    /// A digest of the verifier parameters of the set verifier, whose first four bytes form the
+    /// seal selector.
+    pub verifier_parameters: Digest,
+}
+
+impl<Claim> SetInclusionReceipt<Claim>
+where
+    Claim: Digestible + core::fmt::Debug + Clone + Serialize,
+{
+    /// Number of bytes used by the Merkle path and root seal of this receipt.
+    pub fn seal_size(&self) -> usize {
+        self.merkle_path.len() * DIGEST_BYTES
+            + self.root.as_ref().map_or(0, |root| root.seal_size())
+    }
+
+    /// Encode the seal expected by the `RiscZeroSetVerifier` contract, i.e. the selector followed
+    /// by `abi.encode(Seal(bytes32[] path, bytes rootSeal))`.
+    pub fn abi_encode_seal(&self) -> Result<Vec<u8>, ConvertError> {
+        let root_seal = match self.root.as_deref() {
+            None => Vec::new(),
+            Some(InnerAssumptionReceipt::Groth16(root)) => groth16_selector_seal(root),
+            Some(other) => return Err(ConvertError::UnsupportedReceiptKind(other.kind())),
+        };
+
+        let path_offset = 2 * WORD_BYTES;
+        let root_seal_offset = path_offset + WORD_BYTES * (1 + self.merkle_path.len());
+
+        let selector = &self.verifier_parameters.as_bytes()[..4];
+        let mut seal =
+            Vec::with_capacity(4 + WORD_BYTES + root_seal_offset + WORD_BYTES + root_seal.len());
+        seal.extend_from_slice(selector);
+        // Offset of the dynamic `Seal` tuple.
+        seal.extend_from_slice(&abi_word(WORD_BYTES));
+        seal.extend_from_slice(&abi_word(path_offset));
+        seal.extend_from_slice(&abi_word(root_seal_offset));
+        seal.extend_from_slice(&abi_word(self.merkle_path.len()));
+        for digest in self.merkle_path.iter() {
+            seal.extend_from_slice(digest.as_bytes());
+        }
+        abi_append_bytes(&mut seal, &root_seal);
+        Ok(seal)
+    }
+}
+
+#[derive(Clone, Debug,serde::Deserialize, Serialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+pub enum InnerReceipt {
+    /// A non-succinct [CompositeReceipt], made up of one inner receipt per segment.
+    Composite(CompositeReceipt),
+
+    /// A [SuccinctReceipt], proving arbitrarily long zkVM computions with a single STARK.
+    Succinct(SuccinctReceipt<ReceiptClaim>),
+
+    /// A [Groth16Receipt], proving arbitrarily long zkVM computions with a single Groth16 SNARK.
+    Groth16(Groth16Receipt<ReceiptClaim>),
+
+    /// A [FakeReceipt], with no cryptographic integrity, used only for development.
+    Fake(FakeReceipt<ReceiptClaim>),
+
+    /// A [SetInclusionReceipt], proving the claim by inclusion in an aggregated set.
+    SetInclusion(SetInclusionReceipt<ReceiptClaim>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, Serialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[non_exhaustive]
+pub struct ReceiptMetadata {
+    /// Information which can be used to decide whether a given verifier is compatible with this
+    /// receipt (i.e. that it may be able to verify it).
+    ///
+    /// It is intended to be used when there are multiple verifier implementations (e.g.
+    /// corresponding to multiple versions of a proof system or circuit) and it is ambiguous which
+    /// one should be used to attempt verification of a receipt.
+    pub verifier_parameters: Digest,
+}
+
+impl ReceiptMetadata {
+    /// Construct the metadata of a receipt verifiable with the given verifier parameters.
+    pub fn new(verifier_parameters: Digest) -> Self {
+        Self {
+            verifier_parameters,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, Serialize)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+pub struct Receipt {
+    pub inner: InnerReceipt,
+    pub journal: Journal,
+    pub metadata: ReceiptMetadata,
+}
+
+
+
+
+#[allow(dead_code)]
+fn fmt_debug_journal(journal: &MaybePruned<Vec<u8>>) -> alloc::string::String {
+    match journal {
+        MaybePruned::Value(bytes) => alloc::format!("{} bytes", bytes.len()),
+        MaybePruned::Pruned(_) => alloc::format!("{journal:?}"),
+    }
+}
+
+pub const DIGEST_BYTES: usize = DIGEST_WORDS * WORD_SIZE;
+pub const WORD_SIZE: usize = 4;
+
+impl Digest {
+    /// Digest of all zeroes.
+    pub const ZERO: Self = Self::new([0u32; DIGEST_WORDS]);
+
+    /// Constant constructor
+    pub const fn new(data: [u32; DIGEST_WORDS]) -> Self {
+        Self(data)
+    }
+
+    /// Construct a digest from a array of bytes in a const context.
+    /// Outside of const context, `Digest::from` is recommended.
+    pub const fn from_bytes(bytes: [u8; DIGEST_BYTES]) -> Self {
+        let mut digest: Digest = Digest::ZERO;
+        let mut i: usize = 0;
+        while i < DIGEST_WORDS {
+            let mut j = 0;
+            let mut word = 0u32;
+            while j < WORD_SIZE {
+                word <<= 8;
+                word |= bytes[i * WORD_SIZE + j] as u32;
+                j += 1;
+            }
+            word = u32::from_be(word);
+            digest.0[i] = word;
+            i += 1;
+        }
+        digest
+    }
+
+    /// Returns a reference to the [Digest] as a slice of words.
+    pub fn as_words(&self) -> &[u32] {
+        &self.0
+    }
+
+    /// Returns a reference to the [Digest] as a slice of bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.0)
+    }
+
+    /// Returns a mutable slice of words.
+    pub fn as_mut_words(&mut self) -> &mut [u32] {
+        &mut self.0
+    }
+
+    /// Returns a mutable slice of bytes.
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(&mut self.0)
+    }
+}
+
+/// Error converting a byte buffer of the wrong length into a [Digest].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("expected {DIGEST_BYTES} bytes, got {0}")]
+pub struct DigestLengthError(pub usize);
+
+impl From<[u8; DIGEST_BYTES]> for Digest {
+    fn from(bytes: [u8; DIGEST_BYTES]) -> Self {
+        Digest::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Digest {
+    type Error = DigestLengthError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; DIGEST_BYTES] =
+            bytes.try_into().map_err(|_| DigestLengthError(bytes.len()))?;
+        Ok(Digest::from_bytes(bytes))
+    }
+}
+
+impl TryFrom<Vec<u8>> for Digest {
+    type Error = DigestLengthError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Digest::try_from(bytes.as_slice())
+    }
+}
+
+/// Error parsing a [Digest] from a hex string.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DigestParseError {
+    /// The string does not encode exactly 32 bytes; holds the number of hex digits found.
+    #[error("expected {digits} hex digits, got {0}", digits = DIGEST_BYTES * 2)]
+    InvalidLength(usize),
+
+    /// The string contains a character that is not a hex digit.
+    #[error("invalid hex character {0:?}")]
+    InvalidCharacter(char),
+}
+
+/// Check that `hex` holds only hex digits, then decode it two digits at a time. A trailing
+/// unpaired digit is ignored, so callers check the length.
+pub fn hex_bytes(hex: &str) -> Result<impl Iterator<Item = u8> + '_, char> {
+    if let Some(c) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(c);
+    }
+    let nibble = |c: u8| (c as char).to_digit(16).unwrap_or_default() as u8;
+    Ok(hex.as_bytes().chunks_exact(2).map(move |pair| nibble(pair[0]) << 4 | nibble(pair[1])))
+}
+
+impl core::str::FromStr for Digest {
+    type Err = DigestParseError;
+
+    /// Parse a digest from 64 hex digits, optionally prefixed with `0x`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix("0x").unwrap_or(s);
+        let values = hex_bytes(hex).map_err(DigestParseError::InvalidCharacter)?;
+        if hex.len() != DIGEST_BYTES * 2 {
+            return Err(DigestParseError::InvalidLength(hex.len()));
+        }
+        let mut bytes = [0u8; DIGEST_BYTES];
+        for (byte, value) in bytes.iter_mut().zip(values) {
+            *byte = value;
+        }
+        Ok(Digest::from_bytes(bytes))
+    }
+}
+
+impl fmt::LowerHex for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for byte in self.as_bytes() {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Digest {
+    /// Formats the digest as `0x`-prefixed lowercase hex.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#x}")
+    }
+}
+
+pub const SHA256_INIT: Digest = Digest::new([
+    0x6a09e667_u32.to_be(),
+    0xbb67ae85_u32.to_be(),
+    0x3c6ef372_u32.to_be(),
+    0xa54ff53a_u32.to_be(),
+    0x510e527f_u32.to_be(),
+    0x9b05688c_u32.to_be(),
+    0x1f83d9ab_u32.to_be(),
+    0x5be0cd19_u32.to_be(),
+]);
+
+pub const BLOCK_WORDS: usize = DIGEST_WORDS * 2;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Pod, Zeroable, Serialize,serde::Deserialize)]
+#[repr(transparent)]
+pub struct Block([u32; BLOCK_WORDS]);
+
+pub trait Sha256 {
+    /// A pointer to the digest created as the result of a hashing operation.
+    ///
+    /// This may either be a `Box<Digest>` or some other pointer in case the
+    /// implementation wants to manage its own memory. Semantically, holding the
+    /// `DigestPtr` denotes ownership of the underlying value. (e.g. `DigestPtr`
+    /// does not implement `Copy` and the owner of `DigestPtr` can create a
+    /// mutable reference to the underlying digest).
+    type DigestPtr: DerefMut<Target = Digest> + derive_more::with_trait::Debug;
+
+    /// Generate a SHA-256 hash from a slice of bytes, padding to block size
+    /// and adding the SHA-256 hash trailer, as specified in FIPS 180-4.
+    fn hash_bytes(bytes: &[u8]) -> Self::DigestPtr;
+
+    /// Generate a SHA-256 hash from a slice of words, padding to block size
+    /// and adding the SHA-256 hash trailer, as specified in FIPS 180-4.
+    fn hash_words(words: &[u32]) -> Self::DigestPtr {
+        Self::hash_bytes(bytemuck::cast_slice(words))
+    }
+
+    /// Generate a hash from a pair of [Digest] using the SHA-256 compression
+    /// function. Note that the result is not a standard-compliant hash of any
+    /// known preimage.
+    fn hash_pair(a: &Digest, b: &Digest) -> Self::DigestPtr {
+        Self::compress(&SHA256_INIT, a, b)
+    }
+
+    /// Execute the SHA-256 compression function on a single block given as
+    /// two half-blocks and return a pointer to the result.
+    ///
+    /// NOTE: The half blocks do not need to be adjacent.
+    ///
+    /// DANGER: This is the low-level SHA-256 compression function. It is a
+    /// primitive used to construct SHA-256, but it is NOT the full
+    /// algorithm and should be used directly only with extreme caution.
+    fn compress(state: &Digest, block_half1: &Digest, block_half2: &Digest) -> Self::DigestPtr;
+
+    /// Execute the SHA-256 compression function on a slice of blocks following
+    /// the [Merkle–Damgård] construction and return a pointer to the result.
+    ///
+    /// DANGER: This is the low-level SHA-256 compression function. It is a
+    /// primitive used to construct SHA-256, but it is NOT the full
+    /// algorithm and should be used directly only with extreme caution.
+    ///
+    /// [Merkle–Damgård]: https://en.wikipedia.org/wiki/Merkle%E2%80%93Damg%C3%A5rd_construction
+    fn compress_slice(state: &Digest, blocks: &[Block]) -> Self::DigestPtr;
+
+    /// Generate a hash from a slice of anything that can be represented as
+    /// a slice of bytes. Pads up to the SHA-256 block boundary, but does not
+    /// add the standard SHA-256 trailer and so is not a standards compliant
+    /// hash.
+    fn hash_raw_data_slice<T: bytemuck::NoUninit>(data: &[T]) -> Self::DigestPtr;
+}
+
+fn describe_field(path: &str, expected: &str) -> String {
+    match path {
+        "" => expected.to_string(),
+        path => format!("`{path}` of type {expected}"),
+    }
+}
+
+/// Reasons a receipt can fail to convert into [ProofData].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConvertError {
+    /// The input bytes could not be decoded into a [Receipt].
+    #[error("failed to decode receipt: {0}")]
+    DecodeError(String),
+
+    /// The input bytes are malformed at the given offset.
+    #[error(
+        "failed to decode {format} receipt at byte {offset}, reading {}: {message}",
+        describe_field(.path, .expected)
+    )]
+    InvalidInput {
+        format: &'static str,
+        /// Byte offset at which decoding stopped.
+        offset: usize,
+        /// Path of the field being decoded, e.g. `inner.Groth16.seal[88]`. Empty if unknown.
+        path: String,
+        /// Type of the field being decoded.
+        expected: String,
+        message: String,
+    },
+
+    /// The value could not be encoded into the requested format.
+    #[error("failed to encode receipt: {0}")]
+    EncodeError(String),
+
+    /// The serialization format is not enabled by the features of this build.
+    #[error("receipt format {0} is not enabled in this build")]
+    FormatDisabled(&'static str),
+
+    /// The named serialization format is not supported.
+    #[error("unknown receipt format: {0}")]
+    UnknownFormat(String),
+
+    /// The [InnerReceipt] kind has no on-chain seal encoding.
+    #[error("unsupported receipt type: {0}")]
+    UnsupportedReceiptKind(&'static str),
+
+    /// A claim needed for the conversion was pruned from the receipt.
+    #[error("receipt claim is pruned")]
+    PrunedClaim,
+
+    /// The receipt is structurally invalid.
+    #[error("malformed receipt: {0}")]
+    MalformedReceipt(&'static str),
+
+    /// The exit code of the receipt claim is not a valid [ExitCode].
+    #[error(transparent)]
+    InvalidExitCode(#[from] InvalidExitCodeError),
+
+    /// The receipt names a hash function with no registered [hash::HashSuite].
+    #[error("unsupported hash function: {0}")]
+    UnsupportedHashFunction(String),
+
+    /// No seal encoder is registered for the target chain.
+    #[error("unknown target chain: {0}")]
+    UnknownTarget(String),
+
+    /// Solidity ABI data could not be decoded.
+    #[error("ABI decoding failed: {0}")]
+    AbiError(String),
+
+    /// The execution was split or hit the session limit, so the receipt carries no output.
+    #[error(
+        "receipt with exit code {0:?} carries no output and cannot be verified on-chain; only \
+         halted or paused executions can be posted"
+    )]
+    NoOutput(ExitCode),
+
+    /// The receipt failed a consistency check.
+    #[error(transparent)]
+    Verification(#[from] VerificationError),
+
+    /// The input could not be compressed or decompressed.
+    #[error("compression failed: {0}")]
+    CompressionError(String),
+
+    /// EIP-4844 blobs do not hold data packed by [blob::pack_blobs].
+    #[error("invalid blob: {0}")]
+    InvalidBlob(String),
+
+    /// A field of the input exceeds its [limits::DecodeLimits] cap.
+    #[error("receipt exceeds the decoding limit of {limit} {field}")]
+    LimitExceeded {
+        field: limits::LimitedField,
+        limit: usize,
+    },
+}
+
+/// Reasons a receipt can fail verification.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum VerificationError {
+    /// The seal or other receipt data is malformed.
+    #[error("receipt format error")]
+    ReceiptFormatError,
+
+    /// The seal does not attest to the receipt claim.
+    #[error("invalid proof")]
+    InvalidProof,
+
+    /// The [InnerReceipt] kind cannot be verified.
+    #[error("unsupported receipt type: {0}")]
+    UnsupportedReceiptKind(&'static str),
+
+    /// A claim needed for verification was pruned from the receipt.
+    #[error("receipt claim is pruned")]
+    PrunedClaim,
+
+    /// The receipt was produced by a guest other than the expected one.
+    #[error("image ID mismatch: expected {expected}, receipt was produced by {received}")]
+    ImageIdMismatch { expected: Digest, received: Digest },
+
+    /// The receipt was produced for verifier parameters other than the expected ones.
+    #[error("verifier parameters mismatch: expected {expected}, receipt has {received}")]
+    VerifierParametersMismatch { expected: Digest, received: Digest },
+
+    /// The journal of the receipt is not the one committed to by its claim.
+    #[error("journal digest mismatch: expected {expected}, receipt has {received}")]
+    JournalDigestMismatch { expected: Digest, received: Digest },
+
+    /// The guest did not exit successfully, i.e. with `Halted(0)`.
+    #[error("unexpected exit code: {0:?}")]
+    UnexpectedExitCode(ExitCode),
+
+    /// The verifier parameters of the receipt are not accepted by the verifier context.
+    #[error("verifier parameters {0} are not accepted")]
+    UnacceptedVerifierParameters(Digest),
+
+    /// The receipt uses a hash function not allowed by the verifier context.
+    #[error("hash function {0} is not allowed")]
+    UnsupportedHashFunction(String),
+
+    /// The control ID of a STARK seal is not included in an accepted control root.
+    #[error("control ID {control_id} is not accepted")]
+    ControlVerificationError { control_id: Digest },
+
+    /// A segment of a composite receipt does not start from the state its predecessor ended in.
+    #[error("segment {0} does not continue from the previous segment")]
+    SegmentContinuityError(u32),
+
+    /// A [FakeReceipt] was verified without dev-mode enabled.
+    #[error("fake receipts are only accepted in dev-mode")]
+    DevModeDisabled,
+
+    /// The claim does not match the claim of a successful execution of the expected guest.
+    #[error("claim digest mismatch: expected {expected}, receipt has {received}")]
+    ClaimDigestMismatch { expected: Digest, received: Digest },
+}
+
+impl From<ConvertError> for VerificationError {
+    fn from(err: ConvertError) -> Self {
+        match err {
+            ConvertError::PrunedClaim => VerificationError::PrunedClaim,
+            ConvertError::Verification(err) => err,
+            _ => VerificationError::ReceiptFormatError,
+        }
+    }
+}
+
+impl InnerReceipt {
+    /// Name of this receipt kind, e.g. "Groth16".
+    pub fn kind(&self) -> &'static str {
+        match self {
+            InnerReceipt::Composite(_) => "Composite",
+            InnerReceipt::Succinct(_) => "Succinct",
+            InnerReceipt::Groth16(_) => "Groth16",
+            InnerReceipt::Fake(_) => "Fake",
+            InnerReceipt::SetInclusion(_) => "SetInclusion",
+        }
+    }
+
+    /// Returns the [ReceiptClaim] proven by this receipt.
+    pub fn claim(&self) -> Result<MaybePruned<ReceiptClaim>, ConvertError> {
+        match self {
+            InnerReceipt::Composite(inner) => Ok(MaybePruned::Value(inner.claim()?)),
+            InnerReceipt::Succinct(inner) => Ok(inner.claim.clone()),
+            InnerReceipt::Groth16(inner) => Ok(inner.claim.clone()),
+            InnerReceipt::Fake(inner) => Ok(inner.claim.clone()),
+            InnerReceipt::SetInclusion(inner) => Ok(inner.claim.clone()),
+        }
+    }
+
+    /// Digest of the verifier parameters this receipt was produced for.
+    pub fn verifier_parameters(&self) -> Digest {
+        match self {
+            InnerReceipt::Composite(inner) => inner.verifier_parameters,
+            InnerReceipt::Succinct(inner) => inner.verifier_parameters,
+            InnerReceipt::Groth16(inner) => inner.verifier_parameters,
+            InnerReceipt::Fake(_) => Digest::ZERO,
+            InnerReceipt::SetInclusion(inner) => inner.verifier_parameters,
+        }
+    }
+
+    /// Total number of bytes used by the seals of this receipt.
+    pub fn seal_size(&self) -> usize {
+        match self {
+            InnerReceipt::Composite(receipt) => receipt.seal_size(),
+            InnerReceipt::Succinct(receipt) => receipt.seal_size(),
+            InnerReceipt::Groth16(receipt) => receipt.seal_size(),
+            InnerReceipt::Fake(_) => 0,
+            InnerReceipt::SetInclusion(receipt) => receipt.seal_size(),
+        }
+    }
+}
+
+impl Receipt {
+    /// Returns the [ReceiptClaim] proven by this receipt.
+    pub fn claim(&self) -> Result<MaybePruned<ReceiptClaim>, ConvertError> {
+        self.inner.claim()
+    }
+
+    /// Assumptions made by the guest, in order, including those resolved by the receipt itself.
+    pub fn assumptions(&self) -> Result<Vec<MaybePruned<Assumption>>, ConvertError> {
+        match &self.inner {
+            InnerReceipt::Composite(inner) => Ok(inner.assumptions()?.to_vec()),
+            inner => Ok(inner.claim()?.as_value()?.assumptions()?.to_vec()),
+        }
+    }
+
+    /// Assumptions that are not resolved by an assumption receipt carried within this receipt.
+    pub fn unresolved_assumptions(&self) -> Result<Vec<MaybePruned<Assumption>>, ConvertError> {
+        let mut assumptions = self.assumptions()?;
+        if let InnerReceipt::Composite(inner) = &self.inner {
+            let resolved = inner.assumption_receipts.len().min(assumptions.len());
+            assumptions.drain(..resolved);
+        }
+        Ok(assumptions)
+    }
+
+    /// Whether this receipt is only conditionally valid, i.e. its claim depends on assumptions
+    /// which must be proven separately before the journal can be trusted.
+    pub fn is_conditional(&self) -> Result<bool, ConvertError> {
+        Ok(!self.unresolved_assumptions()?.is_empty())
+    }
+
+    /// Total number of bytes used by the seals of this receipt.
+    pub fn seal_size(&self) -> usize {
+        self.inner.seal_size()
+    }
+
+    /// Check that the journal of this receipt is the one committed to by the output of its claim.
+    ///
+    /// Parts of the claim may be pruned down to their digest. The check is skipped if the journal
+    /// digest cannot be recovered from what remains.
+    pub fn check_journal(&self) -> Result<(), VerificationError> {
+        let claim = self.inner.claim()?;
+        let MaybePruned::Value(claim) = &claim else {
+            return Ok(());
+        };
+        let journal_digest = self.journal.digest::<sha::Impl>();
+        let committed = match &claim.output {
+            MaybePruned::Value(Some(output)) => output.journal.digest::<sha::Impl>(),
+            // An execution without output commits to an empty journal.
+            MaybePruned::Value(None) => Vec::<u8>::new().digest::<sha::Impl>(),
+            MaybePruned::Pruned(_) => return Ok(()),
+        };
+        if committed != journal_digest {
+            return Err(VerificationError::JournalDigestMismatch {
+                expected: committed,
+                received: journal_digest,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Layout of the seal produced by [encode_seal_with_format].
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SealFormat {
+    /// Seal prefixed with the 4-byte verifier selector, as taken by `IRiscZeroVerifier.verify`.
+    #[default]
+    Selector,
+    /// Bare Groth16 seal taken by the pre-1.0 `RiscZeroGroth16Verifier`, which is verified
+    /// together with the post state digest and journal digest instead of a selector.
+    Legacy,
+}
+
+pub fn encode_seal(receipt: &Receipt) -> Result<Vec<u8>, ConvertError> {
+    encode_seal_with_format(receipt, SealFormat::Selector)
+}
+
+/// Same as [encode_seal], prefixing the seal with the given selector instead of the one derived
+/// from the verifier parameters of the receipt, e.g. for a verifier deployed with custom
+/// parameters.
+pub fn encode_seal_with_selector(
+    receipt: &Receipt,
+    selector: Selector,
+) -> Result<Vec<u8>, ConvertError> {
+    let mut seal = encode_seal(receipt)?;
+    seal[..SELECTOR_BYTES].copy_from_slice(&selector.0);
+    Ok(seal)
+}
+
+/// Encode the seal of `receipt` in the given [SealFormat].
+///
+/// The legacy format only exists for Groth16 receipts.
+pub fn encode_seal_with_format(
+    receipt: &Receipt,
+    format: SealFormat,
+) -> Result<Vec<u8>, ConvertError> {
+    EvmSealEncoder { format }.encode_seal(receipt)
+}
+
+/// Prefix the Groth16 seal with the selector taken from its verifier parameters.
+pub(crate) fn groth16_selector_seal<Claim>(receipt: &Groth16Receipt<Claim>) -> Vec<u8>
+where
+    Claim: Digestible + core::fmt::Debug + Clone + Serialize,
+{
+    let selector = &receipt.verifier_parameters.as_bytes()[..4];
+    // Create a new vector with the capacity to hold both selector and seal
+    let mut selector_seal = Vec::with_capacity(selector.len() + receipt.seal.len());
+    selector_seal.extend_from_slice(selector);
+    selector_seal.extend_from_slice(receipt.seal.as_ref());
+    selector_seal
+}
+
+fn write_into(bytes: &[u8], out: &mut [u8]) -> usize {
+    if let Some(out) = out.get_mut(..bytes.len()) {
+        out.copy_from_slice(bytes);
+    }
+    bytes.len()
+}
+
+/// Seal and journal of a receipt, encoded for on-chain verification, and the digests committed
+/// to by its claim.
+///
+/// The buffers live in wasm memory until the JavaScript object is garbage collected, or until
+/// [ProofData::dispose] or the generated `free()` is called.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone)]
+pub struct ProofData {
+    seal: Vec<u8>,
+    journal: Vec<u8>,
+    claim_digest: Digest,
+    image_id: Option<Digest>,
+    post_state_digest: Option<Digest>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ProofData {
+    // Provide getter methods for each field.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn seal(&self) -> Vec<u8> {
+        self.seal.clone()
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn journal(&self) -> Vec<u8> {
+        self.journal.clone()
+    }
+
+    /// View of the seal in wasm memory, without copying it.
+    ///
+    /// The view is only valid until the next call into the module, which may grow the wasm
+    /// memory, or until this [ProofData] is freed. Copy it with `slice()` to keep it longer.
+    #[cfg(feature = "wasm")]
+    pub fn seal_view(&self) -> js_sys::Uint8Array {
+        // SAFETY: the view is handed to JavaScript, which must not keep it past the next
+        // allocation, as documented.
+        unsafe { js_sys::Uint8Array::view(&self.seal) }
+    }
+
+    /// View of the journal in wasm memory, without copying it. Same caveats as
+    /// [ProofData::seal_view].
+    #[cfg(feature = "wasm")]
+    pub fn journal_view(&self) -> js_sys::Uint8Array {
+        // SAFETY: as for `seal_view`.
+        unsafe { js_sys::Uint8Array::view(&self.journal) }
+    }
+
+    /// Length of the seal in bytes.
+    pub fn seal_len(&self) -> usize {
+        self.seal.len()
+    }
+
+    /// Length of the journal in bytes.
+    pub fn journal_len(&self) -> usize {
+        self.journal.len()
+    }
+
+    /// Copy the seal into `out` and return its length. Nothing is written if `out` is shorter
+    /// than the seal, so a returned length greater than `out.len()` is the buffer size needed.
+    pub fn write_seal_into(&self, out: &mut [u8]) -> usize {
+        write_into(&self.seal, out)
+    }
+
+    /// Copy the journal into `out` and return its length, as [ProofData::write_seal_into].
+    pub fn write_journal_into(&self, out: &mut [u8]) -> usize {
+        write_into(&self.journal, out)
+    }
+
+    /// The seal as a `0x`-prefixed hex string.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn seal_hex(&self) -> String {
+        hex_string(&self.seal)
+    }
+
+    /// The journal as a `0x`-prefixed hex string.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn journal_hex(&self) -> String {
+        hex_string(&self.journal)
+    }
+
+    /// The seal as an array of 32-byte words in `0x`-prefixed hex, for verifiers taking a
+    /// `bytes32[]` instead of `bytes`. The last word is right-padded with zeros, as in
+    /// `abi.encode`, when the seal length is not a multiple of 32.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn seal_words(&self) -> Vec<String> {
+        self.seal
+            .chunks(32)
+            .map(|chunk| {
+                let mut word = [0u8; 32];
+                word[..chunk.len()].copy_from_slice(chunk);
+                hex_string(&word)
+            })
+            .collect()
+    }
+
+    /// The seal as a standard padded base64 string.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn seal_base64(&self) -> String {
+        BASE64.encode(&self.seal)
+    }
+
+    /// Digest of the [ReceiptClaim] proven by the receipt.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn claim_digest(&self) -> Vec<u8> {
+        self.claim_digest.as_bytes().to_vec()
+    }
+
+    /// SHA-256 digest of the journal, as taken by `IRiscZeroVerifier.verify`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn journal_digest(&self) -> Vec<u8> {
+        self.journal.digest::<sha::Impl>().as_bytes().to_vec()
+    }
+
+    /// Image ID of the guest that produced the receipt, i.e. the digest of the pre-execution
+    /// [SystemState]. Undefined if the whole claim was pruned from the receipt.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn image_id(&self) -> Option<Vec<u8>> {
+        self.image_id.map(|image_id| image_id.as_bytes().to_vec())
+    }
+
+    /// Release the seal and journal immediately instead of waiting for the garbage collector.
+    /// The object, and any view returned by [ProofData::seal_view] or
+    /// [ProofData::journal_view], cannot be used afterwards.
+    pub fn dispose(self) {}
+
+    /// Digest of the post-execution [SystemState], as taken by the pre-1.0
+    /// `RiscZeroGroth16Verifier`. Undefined if the whole claim was pruned from the receipt.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn post_state_digest(&self) -> Option<Vec<u8>> {
+        self.post_state_digest.map(|digest| digest.as_bytes().to_vec())
+    }
+}
+
+impl ProofData {
+    /// Move the seal and journal out of the proof data, without the copies made by the getters.
+    pub fn into_parts(self) -> (Vec<u8>, Vec<u8>) {
+        (self.seal, self.journal)
+    }
+
+    /// Build the proof data of `receipt` with its seal encoded in the given [SealFormat].
+    pub fn from_receipt(receipt: Receipt, format: SealFormat) -> Result<Self, ConvertError> {
+        Self::from_receipt_with_encoder(receipt, &EvmSealEncoder { format })
+    }
+
+    /// Build the proof data of `receipt` with its seal encoded by the given [SealEncoder].
+    pub fn from_receipt_with_encoder(
+        receipt: Receipt,
+        encoder: &dyn SealEncoder,
+    ) -> Result<Self, ConvertError> {
+        receipt.check_journal()?;
+        let seal = encoder.encode_seal(&receipt)?;
+        let claim = receipt.claim()?;
+        let claim_digest = claim.digest::<sha::Impl>();
+        let image_id = claim.as_value().ok().map(|claim| claim.pre.digest::<sha::Impl>());
+        let post_state_digest = claim.as_value().ok().map(|claim| claim.post.digest::<sha::Impl>());
+        Ok(ProofData {
+            seal,
+            journal: receipt.journal.bytes,
+            claim_digest,
+            image_id,
+            post_state_digest,
+        })
+    }
+}
+
+impl TryFrom<Receipt> for ProofData {
+    type Error = ConvertError;
+
+    fn try_from(receipt: Receipt) -> Result<Self, Self::Error> {
+        ProofData::from_receipt(receipt, SealFormat::Selector)
+    }
+}
+
+/// Seal and identifying data of a single [SegmentReceipt] within a [CompositeReceipt].
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Debug)]
+pub struct SegmentSealInfo {
+    index: u32,
+    hashfn: String,
+    seal: Vec<u8>,
+    claim_digest: Digest,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl SegmentSealInfo {
+    /// Segment index within the receipt.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Name of the hash function used to create the segment receipt.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn hashfn(&self) -> String {
+        self.hashfn.clone()
+    }
+
+    /// Seal of the segment receipt, as little-endian bytes.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn seal(&self) -> Vec<u8> {
+        self.seal.clone()
+    }
+
+    /// Digest of the segment [ReceiptClaim].
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn claim_digest(&self) -> Vec<u8> {
+        self.claim_digest.as_bytes().to_vec()
+    }
+}
+
+impl From<&SegmentReceipt> for SegmentSealInfo {
+    fn from(segment: &SegmentReceipt) -> Self {
+        SegmentSealInfo {
+            index: segment.index,
+            hashfn: segment.hashfn.clone(),
+            seal: segment.get_seal_bytes(),
+            claim_digest: segment.claim.digest::<sha::Impl>(),
+        }
+    }
+}
+
+impl CompositeReceipt {
+    /// Seal and claim digest of every segment, in segment order.
+    pub fn segment_seals(&self) -> Vec<SegmentSealInfo> {
+        #[cfg(feature = "std")]
+        {
+            batch::map_items(&self.segments, |segment| SegmentSealInfo::from(segment))
+        }
+        #[cfg(not(feature = "std"))]
+        self.segments.iter().map(SegmentSealInfo::from).collect()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+
+    const HEX: &str = "0x000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    fn digest() -> Digest {
+        Digest::from_bytes(hex!(
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        ))
+    }
+
+    #[test]
+    fn digest_hex_round_trip() {
+        assert_eq!(digest().to_string(), HEX);
+        assert_eq!(HEX.parse(), Ok(digest()));
+        // The `0x` prefix is optional, and upper case digits are accepted.
+        assert_eq!(HEX[2..].parse(), Ok(digest()));
+        assert_eq!(HEX.to_uppercase().replace("0X", "0x").parse(), Ok(digest()));
+    }
+
+    #[test]
+    fn digest_hex_errors() {
+        assert_eq!(
+            HEX[..HEX.len() - 2].parse::<Digest>(),
+            Err(DigestParseError::InvalidLength(62))
+        );
+        assert_eq!(
+            format!("{HEX}00").parse::<Digest>(),
+            Err(DigestParseError::InvalidLength(66))
+        );
+        assert_eq!(
+            "0x".parse::<Digest>(),
+            Err(DigestParseError::InvalidLength(0))
+        );
+        // Only a lower case prefix is stripped.
+        assert_eq!(
+            HEX.replace("0x", "0X").parse::<Digest>(),
+            Err(DigestParseError::InvalidCharacter('X'))
+        );
+        assert_eq!(
+            HEX.replace('a', "g").parse::<Digest>(),
+            Err(DigestParseError::InvalidCharacter('g'))
+        );
+        assert_eq!(
+            format!(" {}", &HEX[2..]).parse::<Digest>(),
+            Err(DigestParseError::InvalidCharacter(' '))
+        );
+    }
+
+    #[test]
+    fn digest_try_from_bytes() {
+        let bytes = digest().as_bytes().to_vec();
+        assert_eq!(Digest::try_from(bytes.as_slice()), Ok(digest()));
+        assert_eq!(Digest::try_from(bytes.clone()), Ok(digest()));
+        assert_eq!(Digest::try_from(&bytes[..31]), Err(DigestLengthError(31)));
+        assert_eq!(
+            Digest::try_from([bytes, vec![0]].concat()),
+            Err(DigestLengthError(33))
+        );
+        assert_eq!(Digest::try_from(Vec::new()), Err(DigestLengthError(0)));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn digest_serde() {
+        // Human-readable formats use the hex string, and also accept the array of words.
+        let json = serde_json::to_string(&digest()).unwrap();
+        assert_eq!(json, format!("\"{HEX}\""));
+        assert_eq!(serde_json::from_str::<Digest>(&json).unwrap(), digest());
+        let words = serde_json::to_string(digest().as_words()).unwrap();
+        assert_eq!(serde_json::from_str::<Digest>(&words).unwrap(), digest());
+        assert!(serde_json::from_str::<Digest>("\"0x00\"").is_err());
+        assert!(serde_json::from_str::<Digest>("[1, 2, 3]").is_err());
+
+        // Binary formats use the words, each little-endian in BCS.
+        let bcs = bcs::to_bytes(&digest()).unwrap();
+        assert_eq!(bcs, digest().as_bytes());
+        assert_eq!(bcs::from_bytes::<Digest>(&bcs).unwrap(), digest());
+    }
+
+    #[test]
+    fn exit_code_pairs() {
+        for exit_code in [
+            ExitCode::Halted(0),
+            ExitCode::Halted(255),
+            ExitCode::Paused(7),
+            ExitCode::SystemSplit,
+            ExitCode::SessionLimit,
+        ] {
+            let (sys_exit, user_exit) = exit_code.into_pair();
+            assert_eq!(ExitCode::from_pair(sys_exit, user_exit), Ok(exit_code));
+            assert_eq!(
+                ExitCode::from_pair_strict(sys_exit, user_exit),
+                Ok(exit_code)
+            );
+        }
+
+        // Older encoders wrote every system exit with an arbitrary user part.
+        assert_eq!(ExitCode::from_pair(2, 1), Ok(ExitCode::SystemSplit));
+        assert_eq!(
+            ExitCode::from_pair_strict(2, 1),
+            Err(InvalidExitCodeError(2, 1))
+        );
+
+        for (sys_exit, user_exit) in [(3, 0), (u32::MAX, 2)] {
+            let err = Err(InvalidExitCodeError(sys_exit, user_exit));
+            assert_eq!(ExitCode::from_pair(sys_exit, user_exit), err);
+            assert_eq!(ExitCode::from_pair_strict(sys_exit, user_exit), err);
+        }
+    }
+}
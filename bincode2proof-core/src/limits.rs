@@ -0,0 +1,452 @@
+//! Hard caps on the size of decoded receipts, so that a malicious input cannot exhaust the memory
+//! of the host.
+//!
+//! The caps are checked while the length-prefixed fields of a [Receipt](crate::Receipt) are
+//! decoded, before their elements are allocated. Receipts are decoded with the
+//! [DecodeLimits::default] caps unless others are put in effect with [DecodeLimits::scope].
+//! Without the `std` feature the default caps always apply.
+
+use alloc::{format, vec::Vec};
+#[cfg(feature = "std")]
+use core::cell::Cell;
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "borsh")]
+use borsh::{io, BorshDeserialize};
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "std")]
+use crate::ConvertError;
+
+/// A field of a [Receipt](crate::Receipt) whose length is capped by [DecodeLimits].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LimitedField {
+    /// Bytes of the journal.
+    Journal,
+    /// 32-bit words of a STARK seal, or bytes of a Groth16 seal divided by four.
+    Seal,
+    /// Segment receipts of a composite receipt.
+    Segments,
+    /// Assumptions of a claim, or assumption receipts of a composite receipt.
+    Assumptions,
+    /// Bytes of an encoded receipt, after decompression.
+    Encoded,
+}
+
+impl fmt::Display for LimitedField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LimitedField::Journal => "journal bytes",
+            LimitedField::Seal => "seal words",
+            LimitedField::Segments => "segments",
+            LimitedField::Assumptions => "assumptions",
+            LimitedField::Encoded => "encoded bytes",
+        })
+    }
+}
+
+/// Caps on the length of the fields of a decoded [Receipt](crate::Receipt).
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecodeLimits {
+    /// Maximum length of the journal, in bytes.
+    pub max_journal_bytes: u32,
+    /// Maximum length of a seal, in 32-bit words.
+    pub max_seal_words: u32,
+    /// Maximum number of segments of a composite receipt.
+    pub max_segments: u32,
+    /// Maximum number of assumptions of a claim or composite receipt.
+    pub max_assumptions: u32,
+    /// Maximum length of an encoded receipt, after decompression, in bytes.
+    pub max_encoded_bytes: u32,
+}
+
+impl Default for DecodeLimits {
+    /// Caps well above any receipt produced by the zkVM: a 64 MiB journal, 64 MiB seals, 65536
+    /// segments, 65536 assumptions and 256 MiB encoded receipts.
+    fn default() -> Self {
+        Self {
+            max_journal_bytes: 1 << 26,
+            max_seal_words: 1 << 24,
+            max_segments: 1 << 16,
+            max_assumptions: 1 << 16,
+            max_encoded_bytes: 1 << 28,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static ACTIVE: Cell<DecodeLimits> = Cell::new(DecodeLimits::default());
+    static EXCEEDED: Cell<Option<(LimitedField, usize)>> = const { Cell::new(None) };
+    static SKIP_SEALS: Cell<bool> = const { Cell::new(false) };
+    static TALLY: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl DecodeLimits {
+    /// The default caps.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new() -> DecodeLimits {
+        Self::default()
+    }
+}
+
+impl DecodeLimits {
+    /// Cap on the number of elements of `field`.
+    pub fn limit(&self, field: LimitedField) -> usize {
+        match field {
+            LimitedField::Journal => self.max_journal_bytes as usize,
+            LimitedField::Seal => self.max_seal_words as usize,
+            LimitedField::Segments => self.max_segments as usize,
+            LimitedField::Assumptions => self.max_assumptions as usize,
+            LimitedField::Encoded => self.max_encoded_bytes as usize,
+        }
+    }
+
+    /// Run `decode` with these caps in effect instead of the default ones.
+    #[cfg(feature = "std")]
+    pub fn scope<T>(&self, decode: impl FnOnce() -> T) -> T {
+        let previous = ACTIVE.with(|active| active.replace(*self));
+        let result = decode();
+        ACTIVE.with(|active| active.set(previous));
+        result
+    }
+}
+
+/// Run `decode`, turning a decoding error caused by a cap into [ConvertError::LimitExceeded].
+#[cfg(feature = "std")]
+pub fn enforce<T>(
+    decode: impl FnOnce() -> Result<T, ConvertError>,
+) -> Result<T, ConvertError> {
+    EXCEEDED.with(|exceeded| exceeded.set(None));
+    let result = decode();
+    match EXCEEDED.with(Cell::take) {
+        Some((field, limit)) if result.is_err() => {
+            Err(ConvertError::LimitExceeded { field, limit })
+        }
+        _ => result,
+    }
+}
+
+/// Active cap on the number of elements of `field`.
+pub fn limit(field: LimitedField) -> usize {
+    active().limit(field)
+}
+
+/// Check that `len` elements of `field` are within the active cap.
+#[cfg(feature = "std")]
+pub fn check_len(field: LimitedField, len: usize) -> Result<(), ConvertError> {
+    check(field, len, 1).map_err(|(field, limit)| ConvertError::LimitExceeded { field, limit })
+}
+
+/// Run `decode` with seals read but not stored, so that decoded receipts have empty seals. The
+/// caps still apply.
+#[cfg(feature = "std")]
+pub fn skipping_seals<T>(decode: impl FnOnce() -> T) -> T {
+    let previous = SKIP_SEALS.with(|skip| skip.replace(true));
+    let result = decode();
+    SKIP_SEALS.with(|skip| skip.set(previous));
+    result
+}
+
+/// Run `decode`, also returning the number of bytes allocated for the elements of the capped
+/// fields, as given by their length prefixes.
+#[cfg(feature = "std")]
+pub fn tallying<T>(decode: impl FnOnce() -> T) -> (T, usize) {
+    let previous = TALLY.with(|tally| tally.replace(Some(0)));
+    let result = decode();
+    let allocated = TALLY
+        .with(|tally| tally.replace(previous))
+        .unwrap_or_default();
+    (result, allocated)
+}
+
+/// Record the allocation of `len` elements of type `T` while tallying.
+#[cfg(feature = "std")]
+fn tally<T>(len: usize) {
+    TALLY.with(|tally| {
+        if let Some(allocated) = tally.get() {
+            tally.set(Some(
+                allocated.saturating_add(len.saturating_mul(size_of::<T>())),
+            ));
+        }
+    });
+}
+
+#[cfg(not(feature = "std"))]
+fn tally<T>(_len: usize) {}
+
+#[cfg(feature = "std")]
+fn skipped(field: LimitedField) -> bool {
+    field == LimitedField::Seal && SKIP_SEALS.with(Cell::get)
+}
+
+#[cfg(not(feature = "std"))]
+fn skipped(_field: LimitedField) -> bool {
+    false
+}
+
+/// The caps in effect, set by [DecodeLimits::scope].
+#[cfg(feature = "std")]
+fn active() -> DecodeLimits {
+    ACTIVE.with(Cell::get)
+}
+
+#[cfg(not(feature = "std"))]
+fn active() -> DecodeLimits {
+    DecodeLimits::default()
+}
+
+/// Check `len` elements of `field` against the active cap, recording the cap if exceeded.
+fn check(field: LimitedField, len: usize, scale: usize) -> Result<(), (LimitedField, usize)> {
+    let limit = active().limit(field);
+    if len > limit.saturating_mul(scale) {
+        #[cfg(feature = "std")]
+        EXCEEDED.with(|exceeded| exceeded.set(Some((field, limit))));
+        return Err((field, limit));
+    }
+    Ok(())
+}
+
+struct BoundedVisitor<T> {
+    field: LimitedField,
+    scale: usize,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: de::Deserialize<'de>> Visitor<'de> for BoundedVisitor<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of {}", self.field)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let exceeded = |(field, limit)| de::Error::custom(format!("{field} exceed {limit}"));
+        let hint = seq.size_hint().unwrap_or(0);
+        check(self.field, hint, self.scale).map_err(exceeded)?;
+        tally::<T>(hint);
+        if skipped(self.field) {
+            let mut len = 0;
+            while seq.next_element::<T>()?.is_some() {
+                len += 1;
+                check(self.field, len, self.scale).map_err(exceeded)?;
+            }
+            return Ok(Vec::new());
+        }
+        let mut values = Vec::with_capacity(hint.min(4096));
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+            check(self.field, values.len(), self.scale).map_err(exceeded)?;
+        }
+        Ok(values)
+    }
+}
+
+fn bounded_seq<'de, D, T>(
+    deserializer: D,
+    field: LimitedField,
+    scale: usize,
+) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: de::Deserialize<'de>,
+{
+    deserializer.deserialize_seq(BoundedVisitor {
+        field,
+        scale,
+        marker: PhantomData,
+    })
+}
+
+#[cfg(feature = "borsh")]
+fn bounded_vec<R: io::Read, T: BorshDeserialize>(
+    reader: &mut R,
+    field: LimitedField,
+    scale: usize,
+) -> io::Result<Vec<T>> {
+    let len = u32::deserialize_reader(reader)? as usize;
+    check(field, len, scale).map_err(|(field, limit)| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{field} exceed {limit}"),
+        )
+    })?;
+    tally::<T>(len);
+    if skipped(field) {
+        for _ in 0..len {
+            T::deserialize_reader(reader)?;
+        }
+        return Ok(Vec::new());
+    }
+    (0..len).map(|_| T::deserialize_reader(reader)).collect()
+}
+
+/// Serde and Borsh deserializers of the capped fields, for `deserialize_with` attributes.
+pub mod fields {
+    use super::*;
+
+    pub fn journal<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        bounded_seq(deserializer, LimitedField::Journal, 1)
+    }
+
+    #[cfg(feature = "borsh")]
+    pub fn journal_borsh<R: io::Read>(reader: &mut R) -> io::Result<Vec<u8>> {
+        bounded_vec(reader, LimitedField::Journal, 1)
+    }
+
+    pub fn seal_words<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u32>, D::Error> {
+        bounded_seq(deserializer, LimitedField::Seal, 1)
+    }
+
+    #[cfg(feature = "borsh")]
+    pub fn seal_words_borsh<R: io::Read>(reader: &mut R) -> io::Result<Vec<u32>> {
+        bounded_vec(reader, LimitedField::Seal, 1)
+    }
+
+    pub fn seal_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        bounded_seq(deserializer, LimitedField::Seal, 4)
+    }
+
+    #[cfg(feature = "borsh")]
+    pub fn seal_bytes_borsh<R: io::Read>(reader: &mut R) -> io::Result<Vec<u8>> {
+        bounded_vec(reader, LimitedField::Seal, 4)
+    }
+
+    pub fn segments<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: de::Deserialize<'de>,
+    {
+        bounded_seq(deserializer, LimitedField::Segments, 1)
+    }
+
+    #[cfg(feature = "borsh")]
+    pub fn segments_borsh<R: io::Read, T: BorshDeserialize>(reader: &mut R) -> io::Result<Vec<T>> {
+        bounded_vec(reader, LimitedField::Segments, 1)
+    }
+
+    pub fn assumptions<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: de::Deserialize<'de>,
+    {
+        bounded_seq(deserializer, LimitedField::Assumptions, 1)
+    }
+
+    #[cfg(feature = "borsh")]
+    pub fn assumptions_borsh<R: io::Read, T: BorshDeserialize>(
+        reader: &mut R,
+    ) -> io::Result<Vec<T>> {
+        bounded_vec(reader, LimitedField::Assumptions, 1)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use serde::de::{value, IntoDeserializer};
+
+    use super::*;
+
+    const FIELDS: [LimitedField; 5] = [
+        LimitedField::Journal,
+        LimitedField::Seal,
+        LimitedField::Segments,
+        LimitedField::Assumptions,
+        LimitedField::Encoded,
+    ];
+
+    const TIGHT: DecodeLimits = DecodeLimits {
+        max_journal_bytes: 4,
+        max_seal_words: 2,
+        max_segments: 3,
+        max_assumptions: 1,
+        max_encoded_bytes: 16,
+    };
+
+    fn bytes(len: usize) -> value::SeqDeserializer<alloc::vec::IntoIter<u8>, value::Error> {
+        vec![7; len].into_deserializer()
+    }
+
+    #[test]
+    fn scope_restores_limits() {
+        for field in FIELDS {
+            assert_eq!(limit(field), DecodeLimits::default().limit(field));
+        }
+        TIGHT.scope(|| {
+            for field in FIELDS {
+                let cap = TIGHT.limit(field);
+                assert_eq!(limit(field), cap);
+                assert_eq!(check_len(field, cap), Ok(()));
+                assert_eq!(
+                    check_len(field, cap + 1),
+                    Err(ConvertError::LimitExceeded { field, limit: cap })
+                );
+            }
+            DecodeLimits::default().scope(|| {
+                assert_eq!(check_len(LimitedField::Journal, 5), Ok(()));
+            });
+            assert_eq!(limit(LimitedField::Journal), 4);
+        });
+        for field in FIELDS {
+            assert_eq!(limit(field), DecodeLimits::default().limit(field));
+        }
+    }
+
+    #[test]
+    fn bounded_visitor_rejects_long_sequences() {
+        TIGHT.scope(|| {
+            assert_eq!(fields::journal(bytes(4)), Ok(vec![7; 4]));
+            assert!(fields::journal(bytes(5)).is_err());
+            assert_eq!(
+                enforce(|| fields::journal(bytes(5))
+                    .map_err(|err| ConvertError::DecodeError(err.to_string()))),
+                Err(ConvertError::LimitExceeded {
+                    field: LimitedField::Journal,
+                    limit: 4,
+                })
+            );
+
+            // Seals in bytes are capped at four bytes per word.
+            assert_eq!(fields::seal_bytes(bytes(8)), Ok(vec![7; 8]));
+            assert!(fields::seal_bytes(bytes(9)).is_err());
+            assert_eq!(
+                skipping_seals(|| fields::seal_bytes(bytes(8))),
+                Ok(Vec::new())
+            );
+            assert!(skipping_seals(|| fields::seal_bytes(bytes(9))).is_err());
+        });
+        assert_eq!(fields::journal(bytes(5)), Ok(vec![7; 5]));
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn bounded_vec_rejects_long_lengths() {
+        let prefixed = |len: u32, elements: usize| {
+            let mut bytes = len.to_le_bytes().to_vec();
+            bytes.resize(4 + elements, 7);
+            bytes
+        };
+        TIGHT.scope(|| {
+            assert_eq!(
+                fields::journal_borsh(&mut prefixed(4, 4).as_slice()).unwrap(),
+                [7; 4]
+            );
+            let err = fields::journal_borsh(&mut prefixed(5, 5).as_slice()).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+            // The length prefix is rejected before any element is read.
+            assert!(fields::journal_borsh(&mut prefixed(u32::MAX, 0).as_slice()).is_err());
+
+            let words = fields::seal_words_borsh(&mut prefixed(2, 8).as_slice());
+            assert_eq!(words.unwrap(), [0x07070707; 2]);
+            assert!(fields::seal_words_borsh(&mut prefixed(3, 12).as_slice()).is_err());
+        });
+        assert_eq!(
+            fields::journal_borsh(&mut prefixed(5, 5).as_slice()).unwrap(),
+            [7; 5]
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! BCS encoding of proofs for Move verifier modules on Aptos and Sui.
+
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{Digestible, sha, ConvertError, ProofData, ReceiptClaim};
+
+/// [ProofData] as a Move struct. Digests are `vector<u8>` holding their 32 bytes, as Move has no
+/// fixed-size arrays, and absent digests are `option::none()`.
+#[derive(Serialize)]
+struct MoveProofData<'a> {
+    seal: &'a [u8],
+    journal: &'a [u8],
+    journal_digest: Vec<u8>,
+    claim_digest: Vec<u8>,
+    image_id: Option<Vec<u8>>,
+    post_state_digest: Option<Vec<u8>>,
+}
+
+impl ProofData {
+    /// BCS encoding of the proof data, in field order `seal, journal, journal_digest,
+    /// claim_digest, image_id, post_state_digest`.
+    pub fn bcs_bytes(&self) -> Result<Vec<u8>, ConvertError> {
+        let proof = MoveProofData {
+            seal: &self.seal,
+            journal: &self.journal,
+            journal_digest: self.journal.digest::<sha::Impl>().as_bytes().to_vec(),
+            claim_digest: self.claim_digest.as_bytes().to_vec(),
+            image_id: self.image_id.map(|digest| digest.as_bytes().to_vec()),
+            post_state_digest: self
+                .post_state_digest
+                .map(|digest| digest.as_bytes().to_vec()),
+        };
+        bcs::to_bytes(&proof).map_err(|err| ConvertError::EncodeError(err.to_string()))
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ProofData {
+    /// BCS encoding of the proof data, for Move verifier modules on Aptos and Sui.
+    #[cfg(feature = "wasm")]
+    pub fn to_bcs(&self) -> Result<Vec<u8>, JsError> {
+        Ok(self.bcs_bytes()?)
+    }
+}
+
+/// BCS encoding of a [ReceiptClaim], following its serde layout. Digests are eight
+/// little-endian `u32` words.
+pub fn claim_bcs_bytes(claim: &ReceiptClaim) -> Result<Vec<u8>, ConvertError> {
+    bcs::to_bytes(claim).map_err(|err| ConvertError::EncodeError(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+    use crate::Digest;
+
+    #[test]
+    fn bcs_layout() {
+        let proof = ProofData {
+            seal: vec![1, 2, 3],
+            journal: b"hi".to_vec(),
+            claim_digest: Digest::from_bytes([0x11; 32]),
+            image_id: Some(Digest::from_bytes([0x22; 32])),
+            post_state_digest: None,
+        };
+        let expected = [
+            // Vectors are prefixed with their ULEB128 length.
+            &[3, 1, 2, 3][..],
+            &[2, b'h', b'i'],
+            &[32],
+            &hex!("8f434346648f6b96df89dda901c5176b10a6d83961dd3c1ac88b59b2dc327aa4"),
+            &[32],
+            &[0x11; 32],
+            // `option::some` is tagged 1 and `option::none` 0.
+            &[1, 32],
+            &[0x22; 32],
+            &[0],
+        ]
+        .concat();
+        assert_eq!(proof.bcs_bytes().unwrap(), expected);
+    }
+}
@@ -0,0 +1,64 @@
+//! Packaging of proofs as Borsh encoded arguments of NEAR contract calls.
+
+use borsh::BorshSerialize;
+
+use crate::{
+    encoder::{EvmSealEncoder, SealEncoder},
+    Digestible, sha, ConvertError, Receipt, DIGEST_BYTES,
+};
+
+/// Gas attached to a verifier call when none is given: 30 TGas.
+pub const DEFAULT_GAS: u64 = 30_000_000_000_000;
+
+/// Arguments of a NEAR verifier contract call.
+#[derive(BorshSerialize)]
+struct NearVerifyArgs {
+    seal: Vec<u8>,
+    journal_digest: [u8; DIGEST_BYTES],
+    image_id: [u8; DIGEST_BYTES],
+}
+
+/// Encodes the Borsh argument blob of a NEAR verifier call: the seal, prefixed with its
+/// selector, the journal digest and the image ID. The claim must not be pruned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NearArgsEncoder;
+
+impl SealEncoder for NearArgsEncoder {
+    fn encode_seal(&self, receipt: &Receipt) -> Result<Vec<u8>, ConvertError> {
+        let seal = EvmSealEncoder::default().encode_seal(receipt)?;
+        let image_id = receipt.claim()?.as_value()?.pre.digest::<sha::Impl>();
+        let args = NearVerifyArgs {
+            seal,
+            journal_digest: *receipt.journal.digest::<sha::Impl>().as_ref(),
+            image_id: *image_id.as_ref(),
+        };
+        borsh::to_vec(&args).map_err(|err| ConvertError::EncodeError(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+    use crate::{groth16::receipt_from_seal, Digest};
+
+    #[test]
+    fn borsh_args() {
+        let raw: Vec<u8> = (0..=255).collect();
+        let seal = [&[0xbb, 0x00, 0x1d, 0x44][..], &raw].concat();
+        let receipt =
+            receipt_from_seal(&seal, b"hi".to_vec(), Digest::from_bytes([0x22; 32])).unwrap();
+
+        let expected = [
+            // Length of the seal as a little-endian u32, then the seal.
+            &[0x04, 0x01, 0x00, 0x00][..],
+            &seal,
+            // SHA-256 of "hi".
+            &hex!("8f434346648f6b96df89dda901c5176b10a6d83961dd3c1ac88b59b2dc327aa4"),
+            &[0x22; 32],
+        ]
+        .concat();
+        assert_eq!(NearArgsEncoder.encode_seal(&receipt).unwrap(), expected);
+    }
+}
@@ -0,0 +1,76 @@
+//! Options controlling which receipts [ConvertOptions::convert] accepts and how their seals are
+//! encoded.
+
+use core::fmt;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    encoder::{EvmSealEncoder, SealEncoder},
+    ConvertError, ExitCode, ProofData, Receipt, VerificationError,
+};
+
+/// Policy applied to the exit code of a receipt before encoding it, and the encoder used.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Default)]
+pub struct ConvertOptions {
+    /// Accept receipts of paused executions, e.g. for resumable workflows.
+    pub allow_paused: bool,
+    /// Only accept receipts of executions that halted with exit code 0.
+    pub require_ok: bool,
+    encoder: Option<Box<dyn SealEncoder>>,
+}
+
+impl fmt::Debug for ConvertOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConvertOptions")
+            .field("allow_paused", &self.allow_paused)
+            .field("require_ok", &self.require_ok)
+            .field("custom_encoder", &self.encoder.is_some())
+            .finish()
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ConvertOptions {
+    /// Options accepting halted executions with any exit code.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new() -> ConvertOptions {
+        Self::default()
+    }
+}
+
+impl ConvertOptions {
+    /// Encode seals with the given [SealEncoder] instead of the default [EvmSealEncoder].
+    pub fn with_encoder(mut self, encoder: Box<dyn SealEncoder>) -> Self {
+        self.encoder = Some(encoder);
+        self
+    }
+
+    /// Check `receipt` against these options and build its [ProofData].
+    pub fn convert(&self, receipt: Receipt) -> Result<ProofData, ConvertError> {
+        self.check(&receipt)?;
+        match &self.encoder {
+            Some(encoder) => ProofData::from_receipt_with_encoder(receipt, encoder.as_ref()),
+            None => ProofData::from_receipt_with_encoder(receipt, &EvmSealEncoder::default()),
+        }
+    }
+
+    /// Check the exit code of `receipt` against these options. Receipts whose claim is pruned
+    /// cannot be checked and are rejected.
+    pub fn check(&self, receipt: &Receipt) -> Result<(), ConvertError> {
+        let exit_code = receipt.claim()?.as_value()?.exit_code;
+        let accepted = match exit_code {
+            ExitCode::Halted(code) => !self.require_ok || code == 0,
+            ExitCode::Paused(_) => self.allow_paused && !self.require_ok,
+            ExitCode::SystemSplit | ExitCode::SessionLimit => {
+                return Err(ConvertError::NoOutput(exit_code))
+            }
+        };
+        if !accepted {
+            return Err(VerificationError::UnexpectedExitCode(exit_code).into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+//! RLP encoding of `(seal, journal, imageId)` proof payloads, for embedding proofs in
+//! Ethereum-native envelopes such as transactions or L2 messages.
+
+use alloy_rlp::{Encodable, Header};
+
+use crate::Digest;
+
+/// Encode the RLP list `[seal, journal, imageId]`, with the image ID as a 32-byte string.
+pub fn encode_proof(seal: &[u8], journal: &[u8], image_id: &Digest) -> Vec<u8> {
+    let image_id: &[u8; 32] = image_id.as_ref();
+    let payload_length = seal.length() + journal.length() + image_id.length();
+    let mut out = Vec::with_capacity(payload_length + alloy_rlp::length_of_length(payload_length));
+    Header {
+        list: true,
+        payload_length,
+    }
+    .encode(&mut out);
+    seal.encode(&mut out);
+    journal.encode(&mut out);
+    image_id.encode(&mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rlp_list() {
+        let image_id = Digest::from_bytes([0x11; 32]);
+        // A short list of a 2-byte string, a single byte below 0x80 and a 32-byte string.
+        let expected = [&[0xe5, 0x82, 0xaa, 0xbb, 0x05, 0xa0][..], &[0x11; 32]].concat();
+        assert_eq!(encode_proof(&[0xaa, 0xbb], &[0x05], &image_id), expected);
+
+        // A list of more than 55 bytes has its length in a separate byte.
+        let seal = [0xaa; 56];
+        let expected = [
+            &[0xf8, 0x5c, 0xb8, 0x38][..],
+            &seal,
+            &[0x80, 0xa0],
+            &[0x11; 32],
+        ]
+        .concat();
+        assert_eq!(encode_proof(&seal, &[], &image_id), expected);
+    }
+}
@@ -0,0 +1,184 @@
+//! Registry of the known verifier selectors, i.e. the first four bytes of the verifier
+//! parameters digest that prefix an encoded seal.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{ConvertError, Digest};
+
+/// Size in bytes of a seal selector.
+pub const SELECTOR_BYTES: usize = 4;
+
+/// The 4-byte prefix of an encoded seal, routing it to a verifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Selector(pub [u8; SELECTOR_BYTES]);
+
+impl Selector {
+    /// Lowercase hex encoding of the selector, without `0x` prefix.
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// The known verifier targeted by this selector, if any.
+    pub fn info(&self) -> Option<VersionInfo> {
+        lookup(self.0)
+    }
+}
+
+impl From<Digest> for Selector {
+    /// The selector of a verifier parameters digest, i.e. its first four bytes.
+    fn from(digest: Digest) -> Self {
+        let mut selector = [0u8; SELECTOR_BYTES];
+        selector.copy_from_slice(&digest.as_bytes()[..SELECTOR_BYTES]);
+        Selector(selector)
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", self.to_hex())
+    }
+}
+
+/// Split an encoded seal into its selector and the raw seal that follows it, e.g. the 256-byte
+/// Groth16 proof.
+pub fn split_seal(seal: &[u8]) -> Result<(Selector, &[u8]), ConvertError> {
+    let (selector, raw) =
+        seal.split_first_chunk::<SELECTOR_BYTES>()
+            .ok_or(ConvertError::MalformedReceipt(
+                "seal is shorter than its selector",
+            ))?;
+    Ok((Selector(*selector), raw))
+}
+
+/// The verifier contract and risc0 release targeted by a seal selector.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VersionInfo {
+    selector: [u8; SELECTOR_BYTES],
+    verifier: &'static str,
+    version: &'static str,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl VersionInfo {
+    /// The 4-byte selector.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn selector(&self) -> Vec<u8> {
+        self.selector.to_vec()
+    }
+
+    /// Kind of verifier the selector routes to, e.g. "Groth16".
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn verifier(&self) -> String {
+        self.verifier.to_string()
+    }
+
+    /// The risc0 release whose verifier parameters produce the selector.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn version(&self) -> String {
+        self.version.to_string()
+    }
+}
+
+const fn entry(
+    selector: [u8; SELECTOR_BYTES],
+    verifier: &'static str,
+    version: &'static str,
+) -> VersionInfo {
+    VersionInfo {
+        selector,
+        verifier,
+        version,
+    }
+}
+
+/// Selectors of the verifiers deployed behind the `RiscZeroVerifierRouter`.
+pub const KNOWN_SELECTORS: &[VersionInfo] = &[
+    entry([0x31, 0x0f, 0xe5, 0x98], "Groth16", "1.0"),
+    entry([0x50, 0xbd, 0x17, 0x69], "Groth16", "1.1"),
+    entry([0xc1, 0x01, 0xb4, 0x2b], "Groth16", "1.2"),
+    entry([0x9f, 0x39, 0x69, 0x6c], "Groth16", "2.0"),
+    // Digest of the default `Groth16ReceiptVerifierParameters` of this crate.
+    entry([0xbb, 0x00, 0x1d, 0x44], "Groth16", "2.2"),
+    // Selector of the seals built from dev-mode receipts by `encode_seal`.
+    entry([0x00, 0x00, 0x00, 0x00], "FakeReceipt", "dev-mode"),
+    // Selector of the `RiscZeroMockVerifier` used by risc0-ethereum since 1.1.
+    entry([0xff, 0xff, 0xff, 0xff], "FakeReceipt", "dev-mode"),
+];
+
+/// Look up a selector in [KNOWN_SELECTORS].
+pub fn lookup(selector: [u8; SELECTOR_BYTES]) -> Option<VersionInfo> {
+    KNOWN_SELECTORS
+        .iter()
+        .find(|info| info.selector == selector)
+        .copied()
+}
+
+/// An encoded seal split into its selector and raw seal.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct SplitSeal {
+    selector: Selector,
+    raw: Vec<u8>,
+}
+
+impl SplitSeal {
+    /// Split an encoded seal into its selector and raw seal, see [split_seal].
+    pub fn new(seal: &[u8]) -> Result<Self, ConvertError> {
+        let (selector, raw) = split_seal(seal)?;
+        Ok(Self {
+            selector,
+            raw: raw.to_vec(),
+        })
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl SplitSeal {
+    /// The 4-byte selector.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn selector(&self) -> Vec<u8> {
+        self.selector.0.to_vec()
+    }
+
+    /// The selector as lowercase hex, without `0x` prefix.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn selector_hex(&self) -> String {
+        self.selector.to_hex()
+    }
+
+    /// The seal without its selector, e.g. the 256-byte Groth16 proof.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn raw(&self) -> Vec<u8> {
+        self.raw.clone()
+    }
+
+    /// The known verifier targeted by the selector, undefined if not in the registry.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn info(&self) -> Option<VersionInfo> {
+        self.selector.info()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{groth16::Groth16ReceiptVerifierParameters, sha, Digestible};
+
+    #[test]
+    fn default_groth16_selector() {
+        let digest = Groth16ReceiptVerifierParameters::default().digest::<sha::Impl>();
+        assert_eq!(&digest.as_bytes()[..4], [0xbb, 0x00, 0x1d, 0x44]);
+        assert_eq!(
+            Selector::from(digest).info().map(|info| info.version),
+            Some("2.2")
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Software SHA-256 implementation of the [Sha256] trait, backed by the `sha2` crate, and
+//! convenience wrappers using it as the default hasher.
+
+use alloc::boxed::Box;
+use core::borrow::Borrow;
+
+#[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+use sha2::digest::generic_array::GenericArray;
+use sha2::Digest as _;
+
+use crate::{Block, Digest, Sha256, DIGEST_BYTES, SHA256_INIT};
+
+#[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+mod simd;
+#[cfg(feature = "webcrypto")]
+pub mod webcrypto;
+
+const BLOCK_BYTES: usize = DIGEST_BYTES * 2;
+
+/// CPU implementation of [Sha256].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Impl;
+
+impl Impl {
+    /// Run the compression function over a slice of 64-byte blocks.
+    fn compress_bytes(state: &Digest, blocks: &[u8]) -> Box<Digest> {
+        let mut words = [0u32; 8];
+        for (word, state_word) in words.iter_mut().zip(state.as_words()) {
+            *word = u32::from_be(*state_word);
+        }
+        #[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+        simd::compress256(&mut words, blocks);
+        #[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+        {
+            let blocks: alloc::vec::Vec<_> = blocks
+                .chunks_exact(BLOCK_BYTES)
+                .map(GenericArray::clone_from_slice)
+                .collect();
+            sha2::compress256(&mut words, &blocks);
+        }
+        for word in words.iter_mut() {
+            *word = word.to_be();
+        }
+        Box::new(Digest::new(words))
+    }
+}
+
+impl Sha256 for Impl {
+    type DigestPtr = Box<Digest>;
+
+    fn hash_bytes(bytes: &[u8]) -> Self::DigestPtr {
+        let hash: [u8; DIGEST_BYTES] = sha2::Sha256::digest(bytes).into();
+        Box::new(Digest::from_bytes(hash))
+    }
+
+    fn compress(state: &Digest, block_half1: &Digest, block_half2: &Digest) -> Self::DigestPtr {
+        let block = [block_half1.as_bytes(), block_half2.as_bytes()].concat();
+        Self::compress_bytes(state, &block)
+    }
+
+    fn compress_slice(state: &Digest, blocks: &[Block]) -> Self::DigestPtr {
+        Self::compress_bytes(state, bytemuck::cast_slice(blocks))
+    }
+
+    fn hash_raw_data_slice<T: bytemuck::NoUninit>(data: &[T]) -> Self::DigestPtr {
+        let bytes: &[u8] = bytemuck::cast_slice(data);
+        let full_len = bytes.len() - bytes.len() % BLOCK_BYTES;
+        let mut state = Self::compress_bytes(&SHA256_INIT, &bytes[..full_len]);
+        let remainder = &bytes[full_len..];
+        if !remainder.is_empty() {
+            let mut last_block = [0u8; BLOCK_BYTES];
+            last_block[..remainder.len()].copy_from_slice(remainder);
+            state = Self::compress_bytes(&state, &last_block);
+        }
+        state
+    }
+}
+
+/// Hashing of structured data with the default [Impl] hasher.
+///
+/// Implemented for every [crate::Digestible] type, so that `value.digest()` can be called
+/// without naming a [Sha256] implementation.
+pub trait Digestible {
+    /// Calculate a collision resistant hash for the typed and structured data.
+    fn digest(&self) -> Digest;
+}
+
+impl<T: crate::Digestible + ?Sized> Digestible for T {
+    fn digest(&self) -> Digest {
+        crate::Digestible::digest::<Impl>(self)
+    }
+}
+
+/// SHA-256 hash of a slice of bytes.
+pub fn hash_bytes(bytes: &[u8]) -> Digest {
+    *Impl::hash_bytes(bytes)
+}
+
+/// [crate::tagged_struct] with the default [Impl] hasher.
+pub fn tagged_struct(tag: &str, down: &[impl Borrow<Digest>], data: &[u32]) -> Digest {
+    crate::tagged_struct::<Impl>(tag, down, data)
+}
+
+/// [crate::tagged_list] with the default [Impl] hasher.
+pub fn tagged_list(tag: &str, list: &[impl Borrow<Digest>]) -> Digest {
+    crate::tagged_list::<Impl>(tag, list)
+}
@@ -0,0 +1,144 @@
+//! SHA-256 compression function using the wasm `simd128` instructions.
+//!
+//! The message schedule is expanded four words at a time and the round constants are added to
+//! it in vector registers. The rounds themselves are inherently sequential and stay scalar.
+
+use core::arch::wasm32::{
+    u32x4, u32x4_add, u32x4_extract_lane, u32x4_shl, u32x4_shr, v128, v128_or, v128_xor,
+};
+
+const BLOCK_BYTES: usize = 64;
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+#[inline(always)]
+fn load(words: &[u32]) -> v128 {
+    u32x4(words[0], words[1], words[2], words[3])
+}
+
+#[inline(always)]
+fn store(vector: v128) -> [u32; 4] {
+    [
+        u32x4_extract_lane::<0>(vector),
+        u32x4_extract_lane::<1>(vector),
+        u32x4_extract_lane::<2>(vector),
+        u32x4_extract_lane::<3>(vector),
+    ]
+}
+
+#[inline(always)]
+fn rotr(x: v128, n: u32) -> v128 {
+    v128_or(u32x4_shr(x, n), u32x4_shl(x, 32 - n))
+}
+
+/// `σ0` of four consecutive schedule words.
+#[inline(always)]
+fn small_sigma0(x: v128) -> v128 {
+    v128_xor(v128_xor(rotr(x, 7), rotr(x, 18)), u32x4_shr(x, 3))
+}
+
+#[inline(always)]
+fn small_sigma1(x: u32) -> u32 {
+    x.rotate_right(17) ^ x.rotate_right(19) ^ (x >> 10)
+}
+
+/// Expand a block into the 64-word message schedule, with the round constants added.
+fn schedule(block: &[u8]) -> [u32; 64] {
+    let mut w = [0u32; 64];
+    for (word, bytes) in w.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+    for t in (16..64).step_by(4) {
+        // W[t-16] + σ0(W[t-15]) + W[t-7] only depends on words before t, so it is computed for
+        // four words at once. σ1(W[t-2]) depends on the words computed in this step.
+        let partial = store(u32x4_add(
+            u32x4_add(load(&w[t - 16..]), small_sigma0(load(&w[t - 15..]))),
+            load(&w[t - 7..]),
+        ));
+        for (i, partial) in partial.into_iter().enumerate() {
+            w[t + i] = partial.wrapping_add(small_sigma1(w[t + i - 2]));
+        }
+    }
+    let mut wk = [0u32; 64];
+    for t in (0..64).step_by(4) {
+        wk[t..t + 4].copy_from_slice(&store(u32x4_add(load(&w[t..]), load(&K[t..]))));
+    }
+    wk
+}
+
+/// Run the SHA-256 compression function over a slice of 64-byte blocks.
+pub(super) fn compress256(state: &mut [u32; 8], blocks: &[u8]) {
+    for block in blocks.chunks_exact(BLOCK_BYTES) {
+        let wk = schedule(block);
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+        for wk in wk {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(wk);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+        for (word, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *word = word.wrapping_add(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::digest::generic_array::GenericArray;
+
+    use super::*;
+
+    /// Blocks from a xorshift stream, so that a failing seed can be replayed.
+    fn random_bytes(seed: u64, len: usize) -> Vec<u8> {
+        let mut x = seed;
+        (0..len)
+            .map(|_| {
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                x as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_sha2() {
+        for seed in 1..=64u64 {
+            let blocks = random_bytes(seed, BLOCK_BYTES * (seed as usize % 4 + 1));
+            let state_bytes = random_bytes(!seed, 32);
+            let mut state = [0u32; 8];
+            for (word, bytes) in state.iter_mut().zip(state_bytes.chunks_exact(4)) {
+                *word = u32::from_le_bytes(bytes.try_into().unwrap());
+            }
+
+            let mut expected = state;
+            let generic: Vec<_> = blocks
+                .chunks_exact(BLOCK_BYTES)
+                .map(GenericArray::clone_from_slice)
+                .collect();
+            sha2::compress256(&mut expected, &generic);
+            compress256(&mut state, &blocks);
+            assert_eq!(state, expected, "seed {seed}");
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! SHA-256 backed by the WebCrypto `crypto.subtle.digest` API.
+//!
+//! WebCrypto is asynchronous, so it cannot implement the [Sha256] trait used for structured
+//! hashing. It is used instead for one-shot hashing of large buffers such as journals, falling
+//! back to the software [Impl] when `crypto.subtle` is not available.
+
+use js_sys::{Promise, Reflect, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+use super::Impl;
+use crate::{Digest, Sha256, DIGEST_BYTES};
+
+#[wasm_bindgen]
+extern "C" {
+    type SubtleCrypto;
+
+    #[wasm_bindgen(method, catch)]
+    fn digest(this: &SubtleCrypto, algorithm: &str, data: &Uint8Array) -> Result<Promise, JsValue>;
+}
+
+/// Look up `crypto.subtle` on the global object, which exists in browsers, workers and Node.js.
+fn subtle_crypto() -> Option<SubtleCrypto> {
+    let crypto = Reflect::get(&js_sys::global(), &"crypto".into()).ok()?;
+    let subtle = Reflect::get(&crypto, &"subtle".into()).ok()?;
+    if subtle.is_undefined() || subtle.is_null() {
+        return None;
+    }
+    Some(subtle.unchecked_into())
+}
+
+/// Whether hashing is delegated to WebCrypto in this environment.
+pub fn available() -> bool {
+    subtle_crypto().is_some()
+}
+
+/// SHA-256 hash of a slice of bytes, computed with WebCrypto when available.
+pub async fn hash_bytes(bytes: &[u8]) -> Result<Digest, JsValue> {
+    let Some(subtle) = subtle_crypto() else {
+        return Ok(*Impl::hash_bytes(bytes));
+    };
+    let data = Uint8Array::from(bytes);
+    let buffer = JsFuture::from(subtle.digest("SHA-256", &data)?).await?;
+    let hash: [u8; DIGEST_BYTES] = Uint8Array::new(&buffer)
+        .to_vec()
+        .try_into()
+        .map_err(|_| JsValue::from_str("unexpected SHA-256 digest length"))?;
+    Ok(Digest::from_bytes(hash))
+}
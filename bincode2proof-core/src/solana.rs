@@ -0,0 +1,219 @@
+//! Borsh encoding of [ProofData] in the instruction layout of the RISC Zero Solana verifier
+//! programs of [risc0-solana](https://github.com/risc0/risc0-solana).
+
+use ark_ff::{BigInteger, PrimeField};
+use borsh::BorshSerialize;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    encoder::{EvmSealEncoder, SealEncoder},
+    groth16::{decode_proof, SEAL_BYTES},
+    selector::{split_seal, SELECTOR_BYTES},
+    Digestible, sha, ConvertError, Digest, ProofData, Receipt, DIGEST_BYTES,
+};
+
+/// Groth16 proof in the layout of the risc0-solana `groth_16_verifier` program: big-endian
+/// coordinates, with those of `pi_b` listing the imaginary part first. `pi_a` is negated, as the
+/// program checks the pairing equation with `-A`.
+#[derive(BorshSerialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Proof {
+    /// The negated `A` point as `x || y`.
+    pub pi_a: [u8; 64],
+    /// The `B` point as `x1 || x0 || y1 || y0`.
+    pub pi_b: [u8; 128],
+    /// The `C` point as `x || y`.
+    pub pi_c: [u8; 64],
+}
+
+impl Proof {
+    /// Convert a raw 256-byte Groth16 seal, as it follows the selector of an encoded seal.
+    pub fn from_seal(seal: &[u8]) -> Result<Self, ConvertError> {
+        let a = -decode_proof(seal)?.a;
+        let mut proof = Proof {
+            pi_a: [0; 64],
+            pi_b: [0; 128],
+            pi_c: [0; 64],
+        };
+        proof.pi_a[..32].copy_from_slice(&a.x.into_bigint().to_bytes_be());
+        proof.pi_a[32..].copy_from_slice(&a.y.into_bigint().to_bytes_be());
+        proof.pi_b.copy_from_slice(&seal[64..192]);
+        proof.pi_c.copy_from_slice(&seal[192..]);
+        Ok(proof)
+    }
+}
+
+/// Arguments of the `verify` instruction of the risc0-solana `verifier_router` program, which
+/// follow its 8-byte Anchor discriminator in the instruction data.
+#[derive(BorshSerialize)]
+struct SolanaProof {
+    selector: [u8; SELECTOR_BYTES],
+    proof: Proof,
+    image_id: [u8; DIGEST_BYTES],
+    journal_digest: [u8; DIGEST_BYTES],
+}
+
+impl SolanaProof {
+    /// Arguments for a seal prefixed with its selector, which must be a Groth16 seal.
+    fn new(seal: &[u8], image_id: Digest, journal: &[u8]) -> Result<Self, ConvertError> {
+        let (selector, raw) = split_seal(seal)?;
+        if raw.len() != SEAL_BYTES {
+            return Err(ConvertError::MalformedReceipt(
+                "Solana verifiers only accept Groth16 seals",
+            ));
+        }
+        Ok(SolanaProof {
+            selector: selector.0,
+            proof: Proof::from_seal(raw)?,
+            image_id: *image_id.as_ref(),
+            journal_digest: *journal.digest::<sha::Impl>().as_ref(),
+        })
+    }
+}
+
+/// [SolanaProof] followed by the claim digest, for programs that check the claim without
+/// recomputing it.
+#[derive(BorshSerialize)]
+struct SolanaClaimProof {
+    proof: SolanaProof,
+    claim_digest: [u8; DIGEST_BYTES],
+}
+
+impl ProofData {
+    fn solana_proof(&self) -> Result<SolanaProof, ConvertError> {
+        let image_id = self.image_id.ok_or(ConvertError::PrunedClaim)?;
+        SolanaProof::new(&self.seal, image_id, &self.journal)
+    }
+
+    /// Borsh encoding of the selector, proof, image ID and journal digest, in that order. Digests
+    /// are raw bytes in the order of their `[u8; 32]` representation. Fails if the seal is not a
+    /// Groth16 seal, or if the claim was pruned from the receipt, leaving the image ID unknown.
+    pub fn borsh_bytes(&self) -> Result<Vec<u8>, ConvertError> {
+        Ok(borsh::to_vec(&self.solana_proof()?).expect("writing to a vector cannot fail"))
+    }
+
+    /// Borsh encoding of [ProofData::borsh_bytes] followed by the claim digest.
+    pub fn borsh_bytes_with_claim(&self) -> Result<Vec<u8>, ConvertError> {
+        Ok(borsh::to_vec(&SolanaClaimProof {
+            proof: self.solana_proof()?,
+            claim_digest: *self.claim_digest.as_ref(),
+        })
+        .expect("writing to a vector cannot fail"))
+    }
+}
+
+/// Encodes the Borsh arguments of the risc0-solana router `verify` instruction, as
+/// [ProofData::borsh_bytes]. The receipt must be a Groth16 receipt whose claim is not pruned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SolanaArgsEncoder;
+
+impl SealEncoder for SolanaArgsEncoder {
+    fn encode_seal(&self, receipt: &Receipt) -> Result<Vec<u8>, ConvertError> {
+        let seal = EvmSealEncoder::default().encode_seal(receipt)?;
+        let image_id = receipt.claim()?.as_value()?.pre.digest::<sha::Impl>();
+        let args = SolanaProof::new(&seal, image_id, &receipt.journal.bytes)?;
+        borsh::to_vec(&args).map_err(|err| ConvertError::EncodeError(err.to_string()))
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl ProofData {
+    /// Borsh encoded arguments of the risc0-solana router `verify` instruction: the selector,
+    /// proof, image ID and journal digest.
+    pub fn to_borsh(&self) -> Result<Vec<u8>, JsError> {
+        Ok(self.borsh_bytes()?)
+    }
+
+    /// Borsh encoded instruction arguments followed by the claim digest.
+    pub fn to_borsh_with_claim(&self) -> Result<Vec<u8>, JsError> {
+        Ok(self.borsh_bytes_with_claim()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+
+    /// Generators of G1 and G2, as `A = C = G1` and `B = G2`.
+    const A_X: [u8; 32] = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+    const A_Y: [u8; 32] = hex!("0000000000000000000000000000000000000000000000000000000000000002");
+    const B: [u8; 128] = hex!(
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"
+        "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"
+        "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"
+        "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+    );
+    /// `-2` in the BN254 base field.
+    const NEG_A_Y: [u8; 32] =
+        hex!("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45");
+
+    fn proof_data() -> ProofData {
+        let mut seal = vec![0xbb, 0x00, 0x1d, 0x44];
+        seal.extend_from_slice(&A_X);
+        seal.extend_from_slice(&A_Y);
+        seal.extend_from_slice(&B);
+        seal.extend_from_slice(&A_X);
+        seal.extend_from_slice(&A_Y);
+        ProofData {
+            seal,
+            journal: Vec::new(),
+            claim_digest: Digest::from_bytes([0x11; 32]),
+            image_id: Some(Digest::from_bytes([0x22; 32])),
+            post_state_digest: None,
+        }
+    }
+
+    #[test]
+    fn borsh_layout() {
+        let bytes = proof_data().borsh_bytes_with_claim().unwrap();
+        let (selector, rest) = bytes.split_at(4);
+        let (pi_a, rest) = rest.split_at(64);
+        let (pi_b, rest) = rest.split_at(128);
+        let (pi_c, rest) = rest.split_at(64);
+        let (image_id, rest) = rest.split_at(32);
+        let (journal_digest, claim_digest) = rest.split_at(32);
+
+        assert_eq!(selector, [0xbb, 0x00, 0x1d, 0x44]);
+        assert_eq!(pi_a, [A_X, NEG_A_Y].concat());
+        assert_eq!(pi_b, B);
+        assert_eq!(pi_c, [A_X, A_Y].concat());
+        assert_eq!(image_id, [0x22; 32]);
+        // SHA-256 of the empty journal.
+        assert_eq!(
+            journal_digest,
+            hex!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+        );
+        assert_eq!(claim_digest, [0x11; 32]);
+        assert_eq!(
+            proof_data().borsh_bytes().unwrap(),
+            bytes[..bytes.len() - 32]
+        );
+    }
+
+    #[test]
+    fn rejects_other_seals() {
+        let mut pruned = proof_data();
+        pruned.image_id = None;
+        assert!(matches!(
+            pruned.borsh_bytes(),
+            Err(ConvertError::PrunedClaim)
+        ));
+
+        let mut set_inclusion = proof_data();
+        set_inclusion.seal.truncate(100);
+        assert!(matches!(
+            set_inclusion.borsh_bytes(),
+            Err(ConvertError::MalformedReceipt(_))
+        ));
+
+        let mut off_curve = proof_data();
+        off_curve.seal[4 + 63] = 3;
+        assert!(matches!(
+            off_curve.borsh_bytes(),
+            Err(ConvertError::Verification(_))
+        ));
+    }
+}
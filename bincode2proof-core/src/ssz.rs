@@ -0,0 +1,281 @@
+//! SimpleSerialize (SSZ) encoding and Merkleization of [ProofData] and [ReceiptClaim], for
+//! consensus-layer integrations that standardize on SSZ containers.
+//!
+//! The schemas are:
+//!
+//! ```text
+//! class ProofData(Container):
+//!     seal: ByteList[MAX_SEAL_BYTES]
+//!     journal: ByteList[MAX_JOURNAL_BYTES]
+//!     claim_digest: Bytes32
+//!     image_id: Bytes32
+//!     post_state_digest: Bytes32
+//!
+//! class ReceiptClaim(Container):
+//!     pre: Bytes32
+//!     post: Bytes32
+//!     sys_exit: uint32
+//!     user_exit: uint32
+//!     input: Bytes32
+//!     output: Bytes32
+//! ```
+//!
+//! where the fields of the claim are the digests of its possibly pruned parts.
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    Digestible, sha, ConvertError, Digest, ProofData, ReceiptClaim, DIGEST_BYTES,
+};
+
+/// Maximum length of the seal of an SSZ encoded [ProofData].
+pub const MAX_SEAL_BYTES: usize = 1 << 16;
+
+/// Maximum length of the journal of an SSZ encoded [ProofData].
+pub const MAX_JOURNAL_BYTES: usize = 1 << 24;
+
+/// Size in bytes of an SSZ offset.
+const OFFSET_BYTES: usize = 4;
+
+/// Size in bytes of a Merkleization chunk.
+const CHUNK_BYTES: usize = 32;
+
+fn hash_pair(left: &Digest, right: &Digest) -> Digest {
+    sha::hash_bytes(&[left.as_bytes(), right.as_bytes()].concat())
+}
+
+/// Merkleize a layer of chunks into a tree with room for `limit` chunks, padding with zero
+/// chunks.
+fn merkleize(mut layer: Vec<Digest>, limit: usize) -> Digest {
+    let depth = limit.next_power_of_two().trailing_zeros();
+    let mut zero = Digest::ZERO;
+    for _ in 0..depth {
+        if layer.len() % 2 == 1 {
+            layer.push(zero);
+        }
+        layer = layer
+            .chunks_exact(2)
+            .map(|pair| hash_pair(&pair[0], &pair[1]))
+            .collect();
+        zero = hash_pair(&zero, &zero);
+    }
+    layer.first().copied().unwrap_or(zero)
+}
+
+/// Hash tree root of a `uint32`.
+fn uint32_root(value: u32) -> Digest {
+    let mut chunk = [0u8; CHUNK_BYTES];
+    chunk[..4].copy_from_slice(&value.to_le_bytes());
+    Digest::from_bytes(chunk)
+}
+
+/// Hash tree root of a `ByteList[limit]`.
+fn byte_list_root(bytes: &[u8], limit: usize) -> Digest {
+    let chunks = bytes
+        .chunks(CHUNK_BYTES)
+        .map(|chunk| {
+            let mut padded = [0u8; CHUNK_BYTES];
+            padded[..chunk.len()].copy_from_slice(chunk);
+            Digest::from_bytes(padded)
+        })
+        .collect();
+    let root = merkleize(chunks, limit.div_ceil(CHUNK_BYTES));
+    hash_pair(&root, &uint32_root(bytes.len() as u32))
+}
+
+fn check_length(bytes: &[u8], limit: usize, field: &'static str) -> Result<(), ConvertError> {
+    if bytes.len() > limit {
+        return Err(ConvertError::EncodeError(format!(
+            "{field} of {} bytes exceeds the SSZ limit of {limit} bytes",
+            bytes.len()
+        )));
+    }
+    Ok(())
+}
+
+impl ProofData {
+    fn ssz_digests(&self) -> Result<[Digest; 3], ConvertError> {
+        check_length(&self.seal, MAX_SEAL_BYTES, "seal")?;
+        check_length(&self.journal, MAX_JOURNAL_BYTES, "journal")?;
+        let image_id = self.image_id.ok_or(ConvertError::PrunedClaim)?;
+        let post_state_digest = self.post_state_digest.ok_or(ConvertError::PrunedClaim)?;
+        Ok([self.claim_digest, image_id, post_state_digest])
+    }
+
+    /// SSZ encoding of the proof data. Fails if the claim was pruned from the receipt or the seal
+    /// or journal exceed their limits.
+    pub fn ssz_bytes(&self) -> Result<Vec<u8>, ConvertError> {
+        let digests = self.ssz_digests()?;
+        let fixed = 2 * OFFSET_BYTES + digests.len() * DIGEST_BYTES;
+        let mut out = Vec::with_capacity(fixed + self.seal.len() + self.journal.len());
+        out.extend_from_slice(&(fixed as u32).to_le_bytes());
+        out.extend_from_slice(&((fixed + self.seal.len()) as u32).to_le_bytes());
+        for digest in &digests {
+            out.extend_from_slice(digest.as_bytes());
+        }
+        out.extend_from_slice(&self.seal);
+        out.extend_from_slice(&self.journal);
+        Ok(out)
+    }
+
+    /// SSZ hash tree root of the proof data.
+    pub fn ssz_hash_tree_root(&self) -> Result<Digest, ConvertError> {
+        let [claim_digest, image_id, post_state_digest] = self.ssz_digests()?;
+        let fields = vec![
+            byte_list_root(&self.seal, MAX_SEAL_BYTES),
+            byte_list_root(&self.journal, MAX_JOURNAL_BYTES),
+            claim_digest,
+            image_id,
+            post_state_digest,
+        ];
+        let limit = fields.len();
+        Ok(merkleize(fields, limit))
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ProofData {
+    /// SSZ encoding of the proof data.
+    #[cfg(feature = "wasm")]
+    pub fn to_ssz(&self) -> Result<Vec<u8>, JsError> {
+        Ok(self.ssz_bytes()?)
+    }
+
+    /// SSZ hash tree root of the proof data.
+    #[cfg(feature = "wasm")]
+    pub fn hash_tree_root(&self) -> Result<Vec<u8>, JsError> {
+        Ok(self.ssz_hash_tree_root()?.as_bytes().to_vec())
+    }
+}
+
+impl ReceiptClaim {
+    fn ssz_fields(&self) -> ([Digest; 4], (u32, u32)) {
+        let digests = [
+            self.pre.digest::<sha::Impl>(),
+            self.post.digest::<sha::Impl>(),
+            self.input.digest::<sha::Impl>(),
+            self.output.digest::<sha::Impl>(),
+        ];
+        (digests, self.exit_code.into_pair())
+    }
+
+    /// SSZ encoding of the claim.
+    pub fn ssz_bytes(&self) -> Vec<u8> {
+        let ([pre, post, input, output], (sys_exit, user_exit)) = self.ssz_fields();
+        let mut out = Vec::with_capacity(4 * DIGEST_BYTES + 2 * 4);
+        out.extend_from_slice(pre.as_bytes());
+        out.extend_from_slice(post.as_bytes());
+        out.extend_from_slice(&sys_exit.to_le_bytes());
+        out.extend_from_slice(&user_exit.to_le_bytes());
+        out.extend_from_slice(input.as_bytes());
+        out.extend_from_slice(output.as_bytes());
+        out
+    }
+
+    /// SSZ hash tree root of the claim.
+    pub fn ssz_hash_tree_root(&self) -> Digest {
+        let ([pre, post, input, output], (sys_exit, user_exit)) = self.ssz_fields();
+        let fields = vec![
+            pre,
+            post,
+            uint32_root(sys_exit),
+            uint32_root(user_exit),
+            input,
+            output,
+        ];
+        let limit = fields.len();
+        merkleize(fields, limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+    use crate::{ExitCode, MaybePruned};
+
+    fn proof_data(seal: Vec<u8>, journal: Vec<u8>) -> ProofData {
+        ProofData {
+            seal,
+            journal,
+            claim_digest: Digest::from_bytes([0x11; 32]),
+            image_id: Some(Digest::from_bytes([0x22; 32])),
+            post_state_digest: Some(Digest::from_bytes([0x33; 32])),
+        }
+    }
+
+    #[test]
+    fn merkleize_pads_with_zero_hashes() {
+        let zero_hash = hex!("f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a92759fb4b");
+        assert_eq!(merkleize(Vec::new(), 1), Digest::ZERO);
+        assert_eq!(merkleize(Vec::new(), 2), Digest::from_bytes(zero_hash));
+        assert_eq!(
+            merkleize(vec![Digest::ZERO], 2),
+            Digest::from_bytes(zero_hash)
+        );
+    }
+
+    #[test]
+    fn proof_data_hash_tree_root() {
+        let proof = proof_data((0..100).collect(), b"hello".to_vec());
+        assert_eq!(
+            proof.ssz_hash_tree_root().unwrap(),
+            Digest::from_bytes(hex!(
+                "09763b745f679980f9fa412e73ac6a1ade66d6a08f5fab0b692f9990ccf68789"
+            ))
+        );
+        let empty = proof_data(Vec::new(), Vec::new());
+        assert_eq!(
+            empty.ssz_hash_tree_root().unwrap(),
+            Digest::from_bytes(hex!(
+                "d343fd11ca7ca100bdc0454f2bcf6f3580b13e05fcd7d9dbc49769f77f84c558"
+            ))
+        );
+    }
+
+    #[test]
+    fn proof_data_ssz_bytes() {
+        let bytes = proof_data(vec![0xaa; 3], vec![0xbb; 2])
+            .ssz_bytes()
+            .unwrap();
+        assert_eq!(bytes.len(), 2 * OFFSET_BYTES + 3 * DIGEST_BYTES + 5);
+        assert_eq!(bytes[..4], 104u32.to_le_bytes());
+        assert_eq!(bytes[4..8], 107u32.to_le_bytes());
+        assert_eq!(bytes[8..40], [0x11; 32]);
+        assert_eq!(bytes[104..], [0xaa, 0xaa, 0xaa, 0xbb, 0xbb]);
+
+        let oversized = proof_data(vec![0; MAX_SEAL_BYTES + 1], Vec::new());
+        assert!(matches!(
+            oversized.ssz_bytes(),
+            Err(ConvertError::EncodeError(_))
+        ));
+        let mut pruned = proof_data(Vec::new(), Vec::new());
+        pruned.image_id = None;
+        assert!(matches!(
+            pruned.ssz_hash_tree_root(),
+            Err(ConvertError::PrunedClaim)
+        ));
+    }
+
+    #[test]
+    fn claim_hash_tree_root() {
+        let claim = ReceiptClaim {
+            pre: MaybePruned::Pruned(Digest::from_bytes([1; 32])),
+            post: MaybePruned::Pruned(Digest::from_bytes([2; 32])),
+            exit_code: ExitCode::Halted(7),
+            input: MaybePruned::Pruned(Digest::from_bytes([3; 32])),
+            output: MaybePruned::Pruned(Digest::from_bytes([4; 32])),
+        };
+        assert_eq!(
+            claim.ssz_hash_tree_root(),
+            Digest::from_bytes(hex!(
+                "013261dc598124ac1f69c328909af60ba707cfead52c0d0856ca669238e25546"
+            ))
+        );
+        let bytes = claim.ssz_bytes();
+        assert_eq!(bytes.len(), 4 * DIGEST_BYTES + 8);
+        assert_eq!(bytes[64..72], [0, 0, 0, 0, 7, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,161 @@
+//! Encoding of Groth16 proofs as felt252 calldata for the RISC Zero verifier on StarkNet.
+//!
+//! The layout is that of the `full_proof_with_hints` argument of `verify_groth16_proof_bn254` in
+//! the RISC Zero verifier contract of [Garaga](https://github.com/keep-starknet-strange/garaga)
+//! (`src/contracts/groth16_example_risc0`): the proof points, the image ID and the journal, from
+//! which the contract recomputes the claim digest. The pairing and MSM hints that follow them in
+//! the argument depend only on the proof points and are appended by Garaga's tooling.
+
+use crate::{
+    encoder::SealEncoder, groth16::Limb, sha, ConvertError, Digest, Digestible, InnerReceipt,
+    Receipt,
+};
+
+/// Size in bytes of a felt252 in the byte encoding produced by [StarknetSealEncoder].
+pub const FELT_BYTES: usize = 32;
+
+/// Number of felts taken by the proof points of a Groth16 seal: eight 256-bit coordinates, each
+/// split into the four 96-bit limbs of a Garaga `u384`.
+pub const PROOF_FELTS: usize = 32;
+
+/// Size in bytes of a limb of a Garaga `u384`.
+const U384_LIMB_BYTES: usize = 12;
+
+/// Split a big-endian 256-bit word into the limbs of a Garaga `u384`, least significant first.
+fn u384_felts(word: &Limb) -> [u128; 4] {
+    let mut padded = [0u8; 4 * U384_LIMB_BYTES];
+    padded[4 * U384_LIMB_BYTES - 32..].copy_from_slice(word);
+    let mut felts = [0u128; 4];
+    for (felt, limb) in felts.iter_mut().zip(padded.rchunks_exact(U384_LIMB_BYTES)) {
+        let mut bytes = [0u8; 16];
+        bytes[16 - U384_LIMB_BYTES..].copy_from_slice(limb);
+        *felt = u128::from_be_bytes(bytes);
+    }
+    felts
+}
+
+/// Felts of the Groth16 proof points of `receipt`, in the order `A.x, A.y, B.x0, B.x1, B.y0,
+/// B.y1, C.x, C.y`. G2 coordinates list the real part first, unlike the Solidity verifier.
+pub fn proof_felts(receipt: &Receipt) -> Result<Vec<u128>, ConvertError> {
+    let points = match &receipt.inner {
+        InnerReceipt::Groth16(inner) => inner.points()?,
+        other => return Err(ConvertError::UnsupportedReceiptKind(other.kind())),
+    };
+    let [a_x, a_y] = points.a_limbs();
+    let [[b_x1, b_x0], [b_y1, b_y0]] = points.b_limbs();
+    let [c_x, c_y] = points.c_limbs();
+    let coordinates: [&Limb; 8] = [a_x, a_y, b_x0, b_x1, b_y0, b_y1, c_x, c_y];
+    Ok(coordinates.into_iter().flat_map(u384_felts).collect())
+}
+
+/// Felts of `digest` as a Cairo `Span<u32>`: its length, then its big-endian 32-bit words, as
+/// hashed by the Cairo SHA-256 implementation.
+pub fn digest_felts(digest: &Digest) -> Vec<u128> {
+    let words = digest
+        .as_bytes()
+        .chunks_exact(4)
+        .map(|word| u32::from_be_bytes(word.try_into().unwrap()).into());
+    core::iter::once(8).chain(words).collect()
+}
+
+/// Calldata of the StarkNet verifier for `receipt`: the [proof_felts], the [digest_felts] of the
+/// image ID, and the journal as a Cairo `Span<u8>`. The claim must not be pruned.
+pub fn calldata_felts(receipt: &Receipt) -> Result<Vec<u128>, ConvertError> {
+    let image_id = receipt.claim()?.as_value()?.pre.digest::<sha::Impl>();
+    let mut felts = proof_felts(receipt)?;
+    felts.extend(digest_felts(&image_id));
+    felts.push(receipt.journal.bytes.len() as u128);
+    felts.extend(receipt.journal.bytes.iter().map(|&byte| u128::from(byte)));
+    Ok(felts)
+}
+
+/// Encodes the Groth16 proof points as felts, each a 32-byte big-endian word.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StarknetSealEncoder;
+
+impl SealEncoder for StarknetSealEncoder {
+    fn encode_seal(&self, receipt: &Receipt) -> Result<Vec<u8>, ConvertError> {
+        let felts = proof_felts(receipt)?;
+        let mut seal = Vec::with_capacity(PROOF_FELTS * FELT_BYTES);
+        for felt in felts {
+            seal.extend_from_slice(&[0u8; FELT_BYTES - 16]);
+            seal.extend_from_slice(&felt.to_be_bytes());
+        }
+        Ok(seal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+    use crate::groth16::receipt_from_seal;
+
+    #[test]
+    fn garaga_calldata() {
+        // A = C = the generator of G1, and B the generator of G2, as `x1 || x0 || y1 || y0`.
+        let g1 = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+        );
+        let g2 = hex!(
+            "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"
+            "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"
+            "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"
+            "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+        );
+        let seal = [&[0xbb, 0x00, 0x1d, 0x44][..], &g1, &g2, &g1].concat();
+        let image_id = Digest::from_bytes(hex!(
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        ));
+        let receipt = receipt_from_seal(&seal, b"hi".to_vec(), image_id).unwrap();
+
+        let expected: Vec<u128> = [
+            // A.x, A.y
+            &[1, 0, 0, 0][..],
+            &[2, 0, 0, 0],
+            // B.x0, B.x1
+            &[
+                0xf75edadd46debd5cd992f6ed,
+                0x426a00665e5c4479674322d4,
+                0x1800deef121f1e76,
+                0,
+            ],
+            &[
+                0x35a9e71297e485b7aef312c2,
+                0x7260bfb731fb5d25f1aa4933,
+                0x198e9393920d483a,
+                0,
+            ],
+            // B.y0, B.y1
+            &[
+                0x0c43d37b4ce6cc0166fa7daa,
+                0x4aab71808dcb408fe3d1e769,
+                0x12c85ea5db8c6deb,
+                0,
+            ],
+            &[
+                0x70b38ef355acdadcd122975b,
+                0xec9e99ad690c3395bc4b3133,
+                0x090689d0585ff075,
+                0,
+            ],
+            // C.x, C.y
+            &[1, 0, 0, 0],
+            &[2, 0, 0, 0],
+            // Image ID
+            &[8, 0x00010203, 0x04050607, 0x08090a0b, 0x0c0d0e0f],
+            &[0x10111213, 0x14151617, 0x18191a1b, 0x1c1d1e1f],
+            // Journal
+            &[2, u128::from(b'h'), u128::from(b'i')],
+        ]
+        .concat();
+        assert_eq!(calldata_felts(&receipt).unwrap(), expected);
+
+        let seal = StarknetSealEncoder.encode_seal(&receipt).unwrap();
+        assert_eq!(seal.len(), PROOF_FELTS * FELT_BYTES);
+        assert_eq!(seal[..FELT_BYTES], [&[0; 31][..], &[1]].concat());
+        assert_eq!(seal[FELT_BYTES..2 * FELT_BYTES], [0; FELT_BYTES]);
+    }
+}
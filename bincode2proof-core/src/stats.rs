@@ -0,0 +1,88 @@
+//! Size metrics of receipts, for tracking proof sizes across a fleet of provers.
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{InnerReceipt, Receipt};
+
+/// Size metrics of a [Receipt]. Seal sizes are taken from the lengths of the decoded seals,
+/// without copying them.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReceiptStats {
+    kind: &'static str,
+    segment_count: u32,
+    total_seal_bytes: u32,
+    journal_bytes: u32,
+    assumption_count: Option<u32>,
+    hashfn: Option<String>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ReceiptStats {
+    /// Kind of the inner receipt, e.g. "Groth16".
+    #[cfg_attr(
+        feature = "wasm",
+        wasm_bindgen(getter, unchecked_return_type = "ReceiptKind")
+    )]
+    pub fn kind(&self) -> String {
+        self.kind.to_string()
+    }
+
+    /// Number of segment receipts, zero unless the receipt is composite.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn segment_count(&self) -> u32 {
+        self.segment_count
+    }
+
+    /// Total number of bytes used by the seals of the receipt.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn total_seal_bytes(&self) -> u32 {
+        self.total_seal_bytes
+    }
+
+    /// Length of the journal in bytes.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn journal_bytes(&self) -> u32 {
+        self.journal_bytes
+    }
+
+    /// Number of assumptions made by the guest, undefined if they were pruned from the claim.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn assumption_count(&self) -> Option<u32> {
+        self.assumption_count
+    }
+
+    /// Hash function of the STARK seals, undefined for receipts without one.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn hashfn(&self) -> Option<String> {
+        self.hashfn.clone()
+    }
+}
+
+impl From<&Receipt> for ReceiptStats {
+    fn from(receipt: &Receipt) -> Self {
+        let (segment_count, hashfn) = match &receipt.inner {
+            InnerReceipt::Composite(composite) => (
+                composite.segments.len(),
+                composite
+                    .segments
+                    .first()
+                    .map(|segment| segment.hashfn.clone()),
+            ),
+            InnerReceipt::Succinct(succinct) => (0, Some(succinct.hashfn.clone())),
+            _ => (0, None),
+        };
+        ReceiptStats {
+            kind: receipt.inner.kind(),
+            segment_count: segment_count as u32,
+            total_seal_bytes: receipt.seal_size() as u32,
+            journal_bytes: receipt.journal.bytes.len() as u32,
+            assumption_count: receipt
+                .assumptions()
+                .ok()
+                .map(|assumptions| assumptions.len() as u32),
+            hashfn,
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Verifier parameters of [SuccinctReceipt] and [SegmentReceipt](crate::SegmentReceipt) STARKs.
+
+#[cfg(feature = "stark")]
+mod stark;
+
+use alloc::collections::BTreeSet;
+use core::fmt::Debug;
+
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    groth16::{Groth16ReceiptVerifierParameters, ALLOWED_CONTROL_ROOT}, Digestible, sha, tagged_iter, tagged_struct,
+    Digest, Sha256, SuccinctReceipt, VerificationError,
+};
+
+/// A fixed-size string identifying a proof system or circuit version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProtocolInfo(pub [u8; 16]);
+
+/// Version of the STARK proof system.
+pub const PROOF_SYSTEM_INFO: ProtocolInfo = ProtocolInfo(*b"RISC0_STARK:v1__");
+
+/// Version of the recursion circuit.
+pub const RECURSION_CIRCUIT_INFO: ProtocolInfo = ProtocolInfo(*b"RECURSION:rev1v1");
+
+/// Version of the RISC-V circuit proving [SegmentReceipt](crate::SegmentReceipt)s.
+pub const RV32IM_CIRCUIT_INFO: ProtocolInfo = ProtocolInfo(*b"RV32IM:v2_______");
+
+/// Parameters of the succinct receipt verifier. Their digest is the `verifier_parameters` field
+/// of a [SuccinctReceipt].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SuccinctReceiptVerifierParameters {
+    /// Control root used to verify the control ID binding the executed recursion program.
+    pub control_root: Digest,
+    /// Control root used to verify the recursive control root in the output of the receipt.
+    /// `None` means it is equal to `control_root`.
+    pub inner_control_root: Option<Digest>,
+    /// Version of the proof system under which the receipt should verify.
+    pub proof_system_info: ProtocolInfo,
+    /// Version of the circuit with which the receipt should verify.
+    pub circuit_info: ProtocolInfo,
+}
+
+impl Default for SuccinctReceiptVerifierParameters {
+    /// Parameters of the release this crate was built against.
+    fn default() -> Self {
+        SuccinctReceiptVerifierParameters {
+            control_root: ALLOWED_CONTROL_ROOT,
+            inner_control_root: None,
+            proof_system_info: PROOF_SYSTEM_INFO,
+            circuit_info: RECURSION_CIRCUIT_INFO,
+        }
+    }
+}
+
+impl Digestible for SuccinctReceiptVerifierParameters {
+    /// Hash the [SuccinctReceiptVerifierParameters] to get a digest of the struct.
+    fn digest<S: Sha256>(&self) -> Digest {
+        tagged_struct::<S>(
+            "risc0.SuccinctReceiptVerifierParameters",
+            &[
+                self.control_root,
+                self.inner_control_root.unwrap_or(self.control_root),
+                *S::hash_bytes(&self.proof_system_info.0),
+                *S::hash_bytes(&self.circuit_info.0),
+            ],
+            &[],
+        )
+    }
+}
+
+impl<Claim> SuccinctReceipt<Claim>
+where
+    Claim: Digestible + Debug + Clone + Serialize,
+{
+    /// Check that this receipt was produced for the given verifier parameters.
+    pub fn check_verifier_parameters(
+        &self,
+        params: &SuccinctReceiptVerifierParameters,
+    ) -> Result<(), VerificationError> {
+        let expected = params.digest::<sha::Impl>();
+        if self.verifier_parameters != expected {
+            return Err(VerificationError::VerifierParametersMismatch {
+                expected,
+                received: self.verifier_parameters,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Parameters of the segment receipt verifier. Their digest is the `verifier_parameters` field
+/// of a [SegmentReceipt](crate::SegmentReceipt).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SegmentReceiptVerifierParameters {
+    /// Set of control IDs with which the receipt is expected to verify.
+    pub control_ids: BTreeSet<Digest>,
+    /// Version of the proof system under which the receipt should verify.
+    pub proof_system_info: ProtocolInfo,
+    /// Version of the circuit with which the receipt should verify.
+    pub circuit_info: ProtocolInfo,
+}
+
+impl Default for SegmentReceiptVerifierParameters {
+    /// Parameters of the release this crate was built against.
+    fn default() -> Self {
+        SegmentReceiptVerifierParameters {
+            control_ids: BTreeSet::new(),
+            proof_system_info: PROOF_SYSTEM_INFO,
+            circuit_info: RV32IM_CIRCUIT_INFO,
+        }
+    }
+}
+
+impl Digestible for SegmentReceiptVerifierParameters {
+    /// Hash the [SegmentReceiptVerifierParameters] to get a digest of the struct.
+    fn digest<S: Sha256>(&self) -> Digest {
+        tagged_struct::<S>(
+            "risc0.SegmentReceiptVerifierParameters",
+            &[
+                tagged_iter::<S>("risc0.ControlIdSet", self.control_ids.iter()),
+                *S::hash_bytes(&self.proof_system_info.0),
+                *S::hash_bytes(&self.circuit_info.0),
+            ],
+            &[],
+        )
+    }
+}
+
+/// Parameters of the composite receipt verifier, combining those of the segment receipts and of
+/// the succinct and Groth16 receipts resolving its assumptions. Their digest is the
+/// `verifier_parameters` field of a [CompositeReceipt](crate::CompositeReceipt).
+#[derive(Clone, Debug)]
+pub struct CompositeReceiptVerifierParameters {
+    /// Parameters with which every segment receipt is expected to verify.
+    pub segment: SegmentReceiptVerifierParameters,
+    /// Parameters with which succinct assumption receipts are expected to verify.
+    pub succinct: SuccinctReceiptVerifierParameters,
+    /// Parameters with which Groth16 assumption receipts are expected to verify.
+    pub groth16: Groth16ReceiptVerifierParameters,
+}
+
+impl Default for CompositeReceiptVerifierParameters {
+    /// Parameters of the release this crate was built against.
+    fn default() -> Self {
+        CompositeReceiptVerifierParameters {
+            segment: SegmentReceiptVerifierParameters::default(),
+            succinct: SuccinctReceiptVerifierParameters::default(),
+            groth16: Groth16ReceiptVerifierParameters::default(),
+        }
+    }
+}
+
+impl Digestible for CompositeReceiptVerifierParameters {
+    /// Hash the [CompositeReceiptVerifierParameters] to get a digest of the struct.
+    fn digest<S: Sha256>(&self) -> Digest {
+        tagged_struct::<S>(
+            "risc0.CompositeReceiptVerifierParameters",
+            &[
+                self.segment.digest::<S>(),
+                self.succinct.digest::<S>(),
+                self.groth16.digest::<S>(),
+            ],
+            &[],
+        )
+    }
+}
+
+/// Digest of the default [SuccinctReceiptVerifierParameters], against which the
+/// `verifier_parameters` of a [SuccinctReceipt] can be compared.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn succinct_verifier_parameters() -> Vec<u8> {
+    SuccinctReceiptVerifierParameters::default()
+        .digest::<sha::Impl>()
+        .as_bytes()
+        .to_vec()
+}
+
+/// Digest of the default [SegmentReceiptVerifierParameters], against which the
+/// `verifier_parameters` of each segment of a [CompositeReceipt](crate::CompositeReceipt) can be
+/// compared.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn segment_verifier_parameters() -> Vec<u8> {
+    SegmentReceiptVerifierParameters::default()
+        .digest::<sha::Impl>()
+        .as_bytes()
+        .to_vec()
+}
+
+/// Digest of the default [CompositeReceiptVerifierParameters], against which the
+/// `verifier_parameters` of a [CompositeReceipt](crate::CompositeReceipt) can be compared.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn composite_verifier_parameters() -> Vec<u8> {
+    CompositeReceiptVerifierParameters::default()
+        .digest::<sha::Impl>()
+        .as_bytes()
+        .to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+
+    #[test]
+    fn default_composite_parameters_match_risc0() {
+        assert_eq!(
+            CompositeReceiptVerifierParameters::default().digest::<sha::Impl>(),
+            Digest::from_bytes(hex!(
+                "12d12ff8404824a12d4242993527a6ae5b15037a454e9a8fa682e00b62fdc6f5"
+            ))
+        );
+    }
+}
@@ -0,0 +1,469 @@
+//! STARK verification of [SuccinctReceipt] seals with the recursion circuit, and of
+//! [SegmentReceipt] seals with the RISC-V circuit.
+
+use alloc::collections::VecDeque;
+use core::fmt::Debug;
+
+use risc0_circuit_recursion::{CircuitImpl, CIRCUIT};
+use risc0_circuit_rv32im::{HighLowU16, Rv32imV2Claim};
+use risc0_zkp::{
+    adapter::{CircuitInfo, TapsProvider},
+    core::{digest::Digest as ZkpDigest, log2_ceil},
+    taps::TapSet,
+    FRI_FOLD, INV_RATE, MAX_CYCLES_PO2, QUERIES,
+};
+use serde::Serialize;
+
+use crate::{
+    hash::baby_bear::{Elem, P}, Digestible, sha, verify::VerifierContext, Digest, ExitCode,
+    MaybePruned, ReceiptClaim, SegmentReceipt, SuccinctReceipt, SystemState, VerificationError,
+};
+
+/// Halt type of a guest that terminated.
+const HALT_TERMINATE: u16 = 0;
+/// Halt type of a guest that paused.
+const HALT_PAUSE: u16 = 1;
+
+fn digest_from_zkp(digest: &ZkpDigest) -> Digest {
+    Digest::new((*digest).into())
+}
+
+/// Base field elements in an element of the extension field.
+const EXT_SIZE: usize = 4;
+/// Base field elements in a row of the check polynomial.
+const CHECK_SIZE: usize = INV_RATE * EXT_SIZE;
+/// Words in a digest of the seal's Merkle trees.
+const DIGEST_WORDS: usize = 8;
+/// Degree at which FRI stops folding, as in the risc0 verifier.
+const FRI_MIN_DEGREE: usize = 256;
+/// log2 of [FRI_FOLD].
+const FRI_FOLD_PO2: usize = FRI_FOLD.ilog2() as usize;
+
+// The succinct receipt output is the inner control root and the claim digest, 16 words each.
+const _: () = assert!(CircuitImpl::OUTPUT_SIZE >= 32);
+
+/// A run of seal words, read by the risc0 verifier either as Baby Bear field elements or as
+/// digests.
+#[derive(Clone, Copy)]
+enum Region {
+    Elems(usize),
+    Digests(usize),
+}
+
+/// Words of a seal not yet accounted for by [walk_seal].
+struct SealCursor<'a>(&'a [u32]);
+
+impl<'a> SealCursor<'a> {
+    /// Take the next region, checking that field elements are reduced, as the risc0 verifier
+    /// casts them with `bytemuck::checked`.
+    fn read(&mut self, region: Region) -> Result<&'a [u32], VerificationError> {
+        let words = match region {
+            Region::Elems(count) => count,
+            Region::Digests(count) => count * DIGEST_WORDS,
+        };
+        if self.0.len() < words {
+            return Err(VerificationError::ReceiptFormatError);
+        }
+        let (head, rest) = self.0.split_at(words);
+        if matches!(region, Region::Elems(_)) && head.iter().any(|&word| word >= P) {
+            return Err(VerificationError::ReceiptFormatError);
+        }
+        self.0 = rest;
+        Ok(head)
+    }
+}
+
+/// Size of the top layer of a Merkle tree with `2^rows_po2` rows, which is sent once, and the
+/// number of sibling digests in each branch below it.
+fn merkle_shape(rows_po2: usize) -> (usize, usize) {
+    let top_po2 = (1..rows_po2)
+        .take_while(|&layer| 1 << layer <= QUERIES)
+        .last()
+        .unwrap_or(0);
+    (1 << top_po2, rows_po2 - top_po2)
+}
+
+/// Visit the regions of a seal following its globals, for a circuit with the given taps run
+/// over `2^po2` cycles, in the order `risc0_zkp::verify::verify` reads them.
+fn walk_seal(
+    taps: &TapSet,
+    po2: usize,
+    mut read: impl FnMut(Region) -> Result<(), VerificationError>,
+) -> Result<(), VerificationError> {
+    if po2 > MAX_CYCLES_PO2 {
+        return Err(VerificationError::ReceiptFormatError);
+    }
+    let domain_po2 = po2 + INV_RATE.ilog2() as usize;
+    let (top_size, branch) = merkle_shape(domain_po2);
+
+    // Top layers of each register group, then of the check polynomial, and its coefficients.
+    read(Region::Digests((taps.num_groups() + 1) * top_size))?;
+    read(Region::Elems((taps.tap_size() + CHECK_SIZE) * EXT_SIZE))?;
+
+    let mut degree = 1usize << po2;
+    let mut rounds = 0;
+    while degree > FRI_MIN_DEGREE {
+        rounds += 1;
+        read(Region::Digests(
+            merkle_shape(domain_po2 - rounds * FRI_FOLD_PO2).0,
+        ))?;
+        degree /= FRI_FOLD;
+    }
+    // The verifier asserts that it preallocated more rounds than it needed.
+    if rounds >= log2_ceil((1usize << po2).div_ceil(FRI_FOLD).div_ceil(FRI_FOLD_PO2)) {
+        return Err(VerificationError::ReceiptFormatError);
+    }
+    read(Region::Elems(EXT_SIZE * degree))?;
+
+    for _ in 0..QUERIES {
+        for group in 0..taps.num_groups() {
+            read(Region::Elems(taps.group_size(group)))?;
+            read(Region::Digests(branch))?;
+        }
+        read(Region::Elems(CHECK_SIZE))?;
+        read(Region::Digests(branch))?;
+        for round in 1..=rounds {
+            read(Region::Elems(FRI_FOLD * EXT_SIZE))?;
+            read(Region::Digests(
+                merkle_shape(domain_po2 - round * FRI_FOLD_PO2).1,
+            ))?;
+        }
+    }
+    Ok(())
+}
+
+/// Check that `seal` has exactly the layout the risc0 verifier reads for `circuit`.
+///
+/// The verifier slices the seal without bounds checks and asserts that it is fully consumed, so
+/// a seal of any other length would panic rather than fail verification.
+fn check_seal<C: CircuitInfo + TapsProvider>(
+    circuit: &C,
+    seal: &[u32],
+) -> Result<(), VerificationError> {
+    let mut cursor = SealCursor(seal);
+    let globals = cursor.read(Region::Elems(C::OUTPUT_SIZE + 1))?;
+    let po2 = globals[C::OUTPUT_SIZE] as usize;
+    walk_seal(circuit.get_taps(), po2, |region| {
+        cursor.read(region).map(|_| ())
+    })?;
+    if !cursor.0.is_empty() {
+        return Err(VerificationError::ReceiptFormatError);
+    }
+    Ok(())
+}
+
+/// Read a SHA-256 digest encoded as 16 half words.
+fn read_sha_halfs(flat: &mut VecDeque<u32>) -> Result<Digest, VerificationError> {
+    if flat.len() < 16 {
+        return Err(VerificationError::ReceiptFormatError);
+    }
+    let mut bytes = [0u8; 32];
+    for (pair, half) in bytes.chunks_exact_mut(2).zip(flat.drain(0..16)) {
+        let half = u16::try_from(half).map_err(|_| VerificationError::ReceiptFormatError)?;
+        pair.copy_from_slice(&half.to_le_bytes());
+    }
+    Ok(Digest::from_bytes(bytes))
+}
+
+impl<Claim> SuccinctReceipt<Claim>
+where
+    Claim: Digestible + Debug + Clone + Serialize,
+{
+    /// Verify the integrity of this receipt, ensuring the claim is attested to by the seal.
+    ///
+    /// The control root of the receipt must be one of the control roots of the given
+    /// [VerifierContext], and the recursive control root committed to in its output one of its
+    /// inner control roots.
+    pub fn verify_integrity_with_context(
+        &self,
+        ctx: &VerifierContext,
+    ) -> Result<(), VerificationError> {
+        ctx.check_hash_suite(&self.hashfn)?;
+        let suite = risc0_zkp::core::hash::hash_suite_from_name(&self.hashfn)
+            .ok_or_else(|| VerificationError::UnsupportedHashFunction(self.hashfn.clone()))?;
+
+        let control_root = self
+            .control_root()
+            .map_err(|_| VerificationError::UnsupportedHashFunction(self.hashfn.clone()))?;
+        if !ctx.control_roots().contains(&control_root) {
+            return Err(VerificationError::ControlVerificationError {
+                control_id: self.control_id,
+            });
+        }
+
+        let check_code = |_, control_id: &ZkpDigest| {
+            if digest_from_zkp(control_id) != self.control_id {
+                return Err(
+                    risc0_zkp::verify::VerificationError::ControlVerificationError {
+                        control_id: *control_id,
+                    },
+                );
+            }
+            Ok(())
+        };
+        check_seal(&CIRCUIT, &self.seal)?;
+        risc0_zkp::verify::verify(&CIRCUIT, &suite, &self.seal, check_code).map_err(
+            |err| match err {
+                risc0_zkp::verify::VerificationError::ReceiptFormatError => {
+                    VerificationError::ReceiptFormatError
+                }
+                risc0_zkp::verify::VerificationError::ControlVerificationError { .. } => {
+                    VerificationError::ControlVerificationError {
+                        control_id: self.control_id,
+                    }
+                }
+                _ => VerificationError::InvalidProof,
+            },
+        )?;
+
+        // The verified seal starts with the output of the recursion program: the control root it
+        // was run under, then the digest of the claim.
+        let mut output: VecDeque<u32> = self
+            .seal
+            .get(..CircuitImpl::OUTPUT_SIZE)
+            .ok_or(VerificationError::ReceiptFormatError)?
+            .iter()
+            .map(|&word| Elem::new_raw(word).as_u32())
+            .collect();
+
+        // A Poseidon2 digest takes 8 elements, interspersed with padding to fill 16.
+        let inner_control_root: Vec<u32> = output.drain(0..16).step_by(2).collect();
+        let inner_control_root = Digest::new(
+            inner_control_root
+                .try_into()
+                .map_err(|_| VerificationError::ReceiptFormatError)?,
+        );
+        if !ctx.inner_control_roots().contains(&inner_control_root) {
+            return Err(VerificationError::ControlVerificationError {
+                control_id: inner_control_root,
+            });
+        }
+
+        let claim_digest = read_sha_halfs(&mut output)?;
+        let expected = self.claim.digest::<sha::Impl>();
+        if claim_digest != expected {
+            return Err(VerificationError::ClaimDigestMismatch {
+                expected,
+                received: claim_digest,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Decode the [ReceiptClaim] committed to in the globals of a RISC-V circuit seal.
+fn decode_segment_claim(seal: &[u32]) -> Result<ReceiptClaim, VerificationError> {
+    // The version word, then the globals.
+    if seal.len() <= risc0_circuit_rv32im::CircuitImpl::OUTPUT_SIZE {
+        return Err(VerificationError::ReceiptFormatError);
+    }
+    let claim = Rv32imV2Claim::decode(seal).map_err(|_| VerificationError::ReceiptFormatError)?;
+    let exit_code = match claim.terminate_state {
+        None => ExitCode::SystemSplit,
+        Some(term) => match term.a0 {
+            HighLowU16(user_exit, HALT_TERMINATE) => ExitCode::Halted(user_exit.into()),
+            HighLowU16(user_exit, HALT_PAUSE) => ExitCode::Paused(user_exit.into()),
+            _ => return Err(VerificationError::ReceiptFormatError),
+        },
+    };
+    let post_state = match exit_code {
+        ExitCode::Halted(_) => Digest::ZERO,
+        _ => digest_from_zkp(&claim.post_state),
+    };
+    Ok(ReceiptClaim {
+        pre: MaybePruned::Value(SystemState {
+            pc: 0,
+            merkle_root: digest_from_zkp(&claim.pre_state),
+        }),
+        post: MaybePruned::Value(SystemState {
+            pc: 0,
+            merkle_root: post_state,
+        }),
+        exit_code,
+        input: MaybePruned::Pruned(digest_from_zkp(&claim.input)),
+        output: MaybePruned::Pruned(claim.output.as_ref().map_or(Digest::ZERO, digest_from_zkp)),
+    })
+}
+
+impl SegmentReceipt {
+    /// Verify the integrity of this receipt, ensuring the claim is attested to by the seal.
+    pub fn verify_integrity_with_context(
+        &self,
+        ctx: &VerifierContext,
+    ) -> Result<(), VerificationError> {
+        // The RISC-V circuit is only ever proven with Poseidon2.
+        if self.hashfn != "poseidon2" {
+            return Err(VerificationError::UnsupportedHashFunction(
+                self.hashfn.clone(),
+            ));
+        }
+        ctx.check_hash_suite(&self.hashfn)?;
+
+        // The RISC-V seal is prefixed with its version.
+        let stark = self
+            .seal
+            .get(1..)
+            .ok_or(VerificationError::ReceiptFormatError)?;
+        check_seal(&risc0_circuit_rv32im::CircuitImpl, stark)?;
+        risc0_circuit_rv32im::verify(&self.seal).map_err(|err| match err {
+            risc0_zkp::verify::VerificationError::ReceiptFormatError => {
+                VerificationError::ReceiptFormatError
+            }
+            _ => VerificationError::InvalidProof,
+        })?;
+
+        let decoded = decode_segment_claim(&self.seal)?.digest::<sha::Impl>();
+        let expected = self.claim.digest::<sha::Impl>();
+        if decoded != expected {
+            return Err(VerificationError::ClaimDigestMismatch {
+                expected,
+                received: decoded,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{groth16::ALLOWED_CONTROL_ROOT, MerkleProof, Unknown};
+
+    fn succinct(seal: Vec<u32>, hashfn: &str) -> SuccinctReceipt<Unknown> {
+        SuccinctReceipt {
+            seal,
+            control_id: ALLOWED_CONTROL_ROOT,
+            claim: MaybePruned::Pruned(Digest::new([1; 8])),
+            hashfn: hashfn.to_string(),
+            verifier_parameters: Digest::ZERO,
+            control_inclusion_proof: MerkleProof {
+                index: 0,
+                digests: Vec::new(),
+            },
+        }
+    }
+
+    fn segment(seal: Vec<u32>, hashfn: &str) -> SegmentReceipt {
+        SegmentReceipt {
+            seal,
+            index: 0,
+            hashfn: hashfn.to_string(),
+            verifier_parameters: Digest::ZERO,
+            claim: ReceiptClaim::ok(Digest::ZERO, Vec::new()),
+        }
+    }
+
+    #[test]
+    fn read_sha_halfs_round_trip() {
+        let digest = Digest::new([0x0102_0304, 0xa0b0_c0d0, 0, u32::MAX, 1, 2, 3, 4]);
+        let mut flat: VecDeque<u32> = digest
+            .as_bytes()
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]).into())
+            .chain([7])
+            .collect();
+        assert_eq!(read_sha_halfs(&mut flat).unwrap(), digest);
+        assert_eq!(flat, [7]);
+
+        let mut short: VecDeque<u32> = (0..15).collect();
+        assert!(matches!(
+            read_sha_halfs(&mut short),
+            Err(VerificationError::ReceiptFormatError)
+        ));
+        let mut wide: VecDeque<u32> = (0..15).chain([0x1_0000]).collect();
+        assert!(matches!(
+            read_sha_halfs(&mut wide),
+            Err(VerificationError::ReceiptFormatError)
+        ));
+    }
+
+    #[test]
+    fn rejects_unsupported_hash_functions() {
+        let ctx = VerifierContext::default();
+        assert!(matches!(
+            succinct(vec![0; 64], "keccak").verify_integrity_with_context(&ctx),
+            Err(VerificationError::UnsupportedHashFunction(name)) if name == "keccak"
+        ));
+        // Segments are never proven with SHA-256, even though the context accepts it.
+        assert!(matches!(
+            segment(vec![0; 64], "sha-256").verify_integrity_with_context(&ctx),
+            Err(VerificationError::UnsupportedHashFunction(name)) if name == "sha-256"
+        ));
+    }
+
+    #[test]
+    fn rejects_unaccepted_control_roots() {
+        let ctx = VerifierContext::default();
+        let mut receipt = succinct(vec![0; 64], "poseidon2");
+        receipt.control_id = Digest::new([9; 8]);
+        assert!(matches!(
+            receipt.verify_integrity_with_context(&ctx),
+            Err(VerificationError::ControlVerificationError { control_id })
+                if control_id == Digest::new([9; 8])
+        ));
+    }
+
+    /// A seal of zeros with the layout of `circuit` run over `2^po2` cycles.
+    fn zero_seal<C: CircuitInfo + TapsProvider>(circuit: &C, po2: usize) -> Vec<u32> {
+        let mut len = C::OUTPUT_SIZE + 1;
+        walk_seal(circuit.get_taps(), po2, |region| {
+            len += match region {
+                Region::Elems(count) => count,
+                Region::Digests(count) => count * DIGEST_WORDS,
+            };
+            Ok(())
+        })
+        .unwrap();
+        let mut seal = vec![0; len];
+        seal[C::OUTPUT_SIZE] = po2 as u32;
+        seal
+    }
+
+    #[test]
+    fn check_seal_requires_exact_layout() {
+        let seal = zero_seal(&CIRCUIT, 18);
+        assert!(check_seal(&CIRCUIT, &seal).is_ok());
+        assert!(check_seal(&CIRCUIT, &seal[..seal.len() - 1]).is_err());
+        let mut long = seal.clone();
+        long.push(0);
+        assert!(check_seal(&CIRCUIT, &long).is_err());
+        // The globals are field elements, which must be reduced.
+        let mut unreduced = seal.clone();
+        unreduced[0] = P;
+        assert!(check_seal(&CIRCUIT, &unreduced).is_err());
+        // Cycle counts beyond the maximum, or too small for FRI, are rejected outright.
+        let mut too_long = seal;
+        too_long[CircuitImpl::OUTPUT_SIZE] = MAX_CYCLES_PO2 as u32 + 1;
+        assert!(check_seal(&CIRCUIT, &too_long).is_err());
+        assert!(walk_seal(CIRCUIT.get_taps(), 0, |_| Ok(())).is_err());
+    }
+
+    #[test]
+    fn well_formed_seals_fail_without_panicking() {
+        let ctx = VerifierContext::default();
+        assert!(matches!(
+            succinct(zero_seal(&CIRCUIT, 18), "poseidon2").verify_integrity_with_context(&ctx),
+            Err(VerificationError::ControlVerificationError { .. }
+                | VerificationError::InvalidProof)
+        ));
+        let mut seal = vec![risc0_circuit_rv32im::RV32IM_SEAL_VERSION];
+        seal.extend(zero_seal(&risc0_circuit_rv32im::CircuitImpl, 16));
+        assert!(matches!(
+            segment(seal, "poseidon2").verify_integrity_with_context(&ctx),
+            Err(VerificationError::InvalidProof)
+        ));
+    }
+
+    #[test]
+    fn malformed_seals_do_not_panic() {
+        let ctx = VerifierContext::default();
+        for len in [0, 1, 10, 100, 1000] {
+            assert!(succinct(vec![1; len], "poseidon2")
+                .verify_integrity_with_context(&ctx)
+                .is_err());
+            assert!(segment(vec![1; len], "poseidon2")
+                .verify_integrity_with_context(&ctx)
+                .is_err());
+        }
+    }
+}
@@ -0,0 +1,562 @@
+//! End-to-end verification of a [Receipt] against an expected image ID.
+
+use core::fmt::Debug;
+
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "wasm")]
+use crate::digest_from_js;
+use crate::{
+    groth16::{Groth16ReceiptVerifierParameters, ALLOWED_CONTROL_ROOT},
+    info::ExitCodeInfo,
+    hash::hash_suite_from_name,
+    Digestible, sha,
+    succinct::{
+        CompositeReceiptVerifierParameters, SegmentReceiptVerifierParameters,
+        SuccinctReceiptVerifierParameters,
+    },
+    Digest, ExitCode, FakeReceipt, InnerAssumptionReceipt, InnerReceipt, MaybePruned, Receipt,
+    ReceiptClaim, VerificationError,
+};
+
+/// Parameters accepted when verifying a receipt.
+///
+/// A deployment that must accept receipts from several risc0 releases at once can accept the
+/// verifier parameters and control roots of each of them.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Debug)]
+pub struct VerifierContext {
+    verifier_parameters: Vec<Digest>,
+    segment_verifier_parameters: Vec<Digest>,
+    control_roots: Vec<Digest>,
+    inner_control_roots: Vec<Digest>,
+    hash_suites: Vec<String>,
+    dev_mode: bool,
+}
+
+impl Default for VerifierContext {
+    /// Context accepting the parameters of the release this crate was built against.
+    fn default() -> Self {
+        Self {
+            verifier_parameters: vec![
+                Groth16ReceiptVerifierParameters::default().digest::<sha::Impl>(),
+                SuccinctReceiptVerifierParameters::default().digest::<sha::Impl>(),
+                CompositeReceiptVerifierParameters::default().digest::<sha::Impl>(),
+            ],
+            segment_verifier_parameters: vec![
+                SegmentReceiptVerifierParameters::default().digest::<sha::Impl>()
+            ],
+            control_roots: vec![ALLOWED_CONTROL_ROOT],
+            inner_control_roots: vec![ALLOWED_CONTROL_ROOT],
+            hash_suites: vec!["poseidon2".to_string()],
+            dev_mode: false,
+        }
+    }
+}
+
+impl VerifierContext {
+    /// Context accepting nothing, to be filled with the `with_*` methods.
+    pub fn empty() -> Self {
+        Self {
+            verifier_parameters: Vec::new(),
+            segment_verifier_parameters: Vec::new(),
+            control_roots: Vec::new(),
+            inner_control_roots: Vec::new(),
+            hash_suites: Vec::new(),
+            dev_mode: false,
+        }
+    }
+
+    /// Additionally accept receipts produced for the verifier parameters with the given digest.
+    pub fn with_verifier_parameters(mut self, digest: Digest) -> Self {
+        self.verifier_parameters.push(digest);
+        self
+    }
+
+    /// Additionally accept segments of a [CompositeReceipt](crate::CompositeReceipt) produced for
+    /// the segment verifier parameters with the given digest.
+    pub fn with_segment_verifier_parameters(mut self, digest: Digest) -> Self {
+        self.segment_verifier_parameters.push(digest);
+        self
+    }
+
+    /// Additionally accept seals produced under the given recursion control root, both as the
+    /// control root of a receipt and as the recursive control root committed to in its output.
+    pub fn with_control_root(mut self, control_root: Digest) -> Self {
+        self.control_roots.push(control_root);
+        self.inner_control_roots.push(control_root);
+        self
+    }
+
+    /// Additionally accept STARK seals produced with the named hash suite.
+    pub fn with_hash_suite(mut self, name: impl Into<String>) -> Self {
+        self.hash_suites.push(name.into());
+        self
+    }
+
+    /// Whether [FakeReceipt](crate::FakeReceipt)s are accepted.
+    pub fn with_dev_mode(mut self, dev_mode: bool) -> Self {
+        self.dev_mode = dev_mode;
+        self
+    }
+
+    /// Accepted verifier parameter digests.
+    pub fn verifier_parameters(&self) -> &[Digest] {
+        &self.verifier_parameters
+    }
+
+    /// Accepted segment verifier parameter digests.
+    pub fn segment_verifier_parameters(&self) -> &[Digest] {
+        &self.segment_verifier_parameters
+    }
+
+    /// Accepted recursion control roots.
+    pub fn control_roots(&self) -> &[Digest] {
+        &self.control_roots
+    }
+
+    /// Accepted recursive control roots, committed to in the output of a succinct receipt.
+    pub fn inner_control_roots(&self) -> &[Digest] {
+        &self.inner_control_roots
+    }
+
+    /// Names of the accepted hash suites.
+    pub fn hash_suites(&self) -> &[String] {
+        &self.hash_suites
+    }
+
+    /// Whether dev-mode is enabled.
+    pub fn dev_mode(&self) -> bool {
+        self.dev_mode
+    }
+
+    /// Context accepting succinct receipts produced under the given control root, with the inner
+    /// control roots, hash suites and dev-mode setting of this context. Used to verify an
+    /// assumption receipt whose control root was chosen by the guest.
+    pub fn for_control_root(&self, control_root: Digest) -> Self {
+        let verifier_parameters = self
+            .inner_control_roots
+            .iter()
+            .map(|&inner_control_root| {
+                SuccinctReceiptVerifierParameters {
+                    control_root,
+                    inner_control_root: Some(inner_control_root),
+                    ..Default::default()
+                }
+                .digest::<sha::Impl>()
+            })
+            .collect();
+        Self {
+            verifier_parameters,
+            segment_verifier_parameters: self.segment_verifier_parameters.clone(),
+            control_roots: vec![control_root],
+            inner_control_roots: self.inner_control_roots.clone(),
+            hash_suites: self.hash_suites.clone(),
+            dev_mode: self.dev_mode,
+        }
+    }
+
+    /// Check that the verifier parameters with the given digest are accepted.
+    pub fn check_verifier_parameters(&self, digest: &Digest) -> Result<(), VerificationError> {
+        if !self.verifier_parameters.contains(digest) {
+            return Err(VerificationError::UnacceptedVerifierParameters(*digest));
+        }
+        Ok(())
+    }
+
+    /// Check that the segment verifier parameters with the given digest are accepted.
+    pub fn check_segment_verifier_parameters(
+        &self,
+        digest: &Digest,
+    ) -> Result<(), VerificationError> {
+        if !self.segment_verifier_parameters.contains(digest) {
+            return Err(VerificationError::UnacceptedVerifierParameters(*digest));
+        }
+        Ok(())
+    }
+
+    /// Check that the named hash suite is both accepted and available.
+    pub fn check_hash_suite(&self, name: &str) -> Result<(), VerificationError> {
+        if !self.hash_suites.iter().any(|suite| suite == name)
+            || hash_suite_from_name(name).is_none()
+        {
+            return Err(VerificationError::UnsupportedHashFunction(name.to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl VerifierContext {
+    /// Context accepting the parameters of the release this crate was built against.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new() -> VerifierContext {
+        Self::default()
+    }
+
+    /// Additionally accept receipts produced for the verifier parameters with the given digest.
+    #[cfg(feature = "wasm")]
+    pub fn accept_verifier_parameters(&mut self, digest: Vec<u8>) -> Result<(), JsError> {
+        let digest = digest_from_js(&digest, "verifier parameters")?;
+        self.verifier_parameters.push(digest);
+        Ok(())
+    }
+
+    /// Additionally accept composite receipt segments produced for the segment verifier parameters
+    /// with the given digest.
+    #[cfg(feature = "wasm")]
+    pub fn accept_segment_verifier_parameters(&mut self, digest: Vec<u8>) -> Result<(), JsError> {
+        let digest = digest_from_js(&digest, "segment verifier parameters")?;
+        self.segment_verifier_parameters.push(digest);
+        Ok(())
+    }
+
+    /// Additionally accept seals produced under the given recursion control root.
+    #[cfg(feature = "wasm")]
+    pub fn accept_control_root(&mut self, control_root: Vec<u8>) -> Result<(), JsError> {
+        let control_root = digest_from_js(&control_root, "control root")?;
+        self.control_roots.push(control_root);
+        self.inner_control_roots.push(control_root);
+        Ok(())
+    }
+
+    /// Additionally accept STARK seals produced with the named hash suite.
+    pub fn accept_hash_suite(&mut self, name: String) {
+        self.hash_suites.push(name);
+    }
+
+    /// Whether fake receipts produced in dev-mode are accepted.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter = dev_mode))]
+    pub fn js_dev_mode(&self) -> bool {
+        self.dev_mode
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(setter))]
+    pub fn set_dev_mode(&mut self, dev_mode: bool) {
+        self.dev_mode = dev_mode;
+    }
+}
+
+impl InnerReceipt {
+    /// Verify the integrity of this receipt, ensuring the claim is attested to by the seal.
+    pub fn verify_integrity(&self) -> Result<(), VerificationError> {
+        self.verify_integrity_with_context(&VerifierContext::default())
+    }
+
+    /// Verify the integrity of this receipt under the given [VerifierContext].
+    ///
+    /// [SuccinctReceipt](crate::SuccinctReceipt) seals are only checked with the `stark` feature.
+    pub fn verify_integrity_with_context(
+        &self,
+        ctx: &VerifierContext,
+    ) -> Result<(), VerificationError> {
+        if let InnerReceipt::Fake(inner) = self {
+            inner.verify_integrity_with_context(ctx)?;
+            inner.claim.as_value()?;
+            return Ok(());
+        }
+        ctx.check_verifier_parameters(&self.verifier_parameters())?;
+        match self {
+            InnerReceipt::Groth16(inner) => inner.verify_integrity_with_context(ctx),
+            #[cfg(feature = "stark")]
+            InnerReceipt::Succinct(inner) => inner.verify_integrity_with_context(ctx),
+            #[cfg(feature = "stark")]
+            InnerReceipt::Composite(inner) => inner.verify_integrity_with_context(ctx),
+            other => Err(VerificationError::UnsupportedReceiptKind(other.kind())),
+        }
+    }
+}
+
+impl InnerAssumptionReceipt {
+    /// Verify the integrity of this receipt under the given [VerifierContext].
+    pub fn verify_integrity_with_context(
+        &self,
+        ctx: &VerifierContext,
+    ) -> Result<(), VerificationError> {
+        if let InnerAssumptionReceipt::Fake(inner) = self {
+            return inner.verify_integrity_with_context(ctx);
+        }
+        ctx.check_verifier_parameters(&self.verifier_parameters())?;
+        match self {
+            InnerAssumptionReceipt::Groth16(inner) => inner.verify_integrity_with_context(ctx),
+            #[cfg(feature = "stark")]
+            InnerAssumptionReceipt::Succinct(inner) => inner.verify_integrity_with_context(ctx),
+            #[cfg(feature = "stark")]
+            InnerAssumptionReceipt::Composite(inner) => inner.verify_integrity_with_context(ctx),
+            other => Err(VerificationError::UnsupportedReceiptKind(other.kind())),
+        }
+    }
+}
+
+impl<Claim> FakeReceipt<Claim>
+where
+    Claim: Digestible + Debug + Clone + Serialize,
+{
+    /// Accept this receipt if the context is in dev-mode. A fake receipt carries no seal, so
+    /// nothing attests to its claim.
+    pub fn verify_integrity_with_context(
+        &self,
+        ctx: &VerifierContext,
+    ) -> Result<(), VerificationError> {
+        if !ctx.dev_mode() {
+            return Err(VerificationError::DevModeDisabled);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "stark")]
+impl crate::CompositeReceipt {
+    /// Verify the integrity of this receipt: the seal of every segment, the chaining of each
+    /// segment to the next, and the assumption receipts resolving every assumption.
+    pub fn verify_integrity_with_context(
+        &self,
+        ctx: &VerifierContext,
+    ) -> Result<(), VerificationError> {
+        let (last, segments) = self
+            .segments
+            .split_last()
+            .ok_or(VerificationError::ReceiptFormatError)?;
+
+        let mut expected_pre_state = None;
+        for segment in segments.iter().chain([last]) {
+            ctx.check_segment_verifier_parameters(&segment.verifier_parameters)?;
+            segment.verify_integrity_with_context(ctx)?;
+            if let Some(expected) = expected_pre_state {
+                if segment.claim.pre.digest::<sha::Impl>() != expected {
+                    return Err(VerificationError::SegmentContinuityError(segment.index));
+                }
+            }
+            expected_pre_state = Some(segment.claim.post.digest::<sha::Impl>());
+        }
+        for segment in segments {
+            if segment.claim.exit_code != ExitCode::SystemSplit {
+                return Err(VerificationError::UnexpectedExitCode(
+                    segment.claim.exit_code,
+                ));
+            }
+            let has_output = match &segment.claim.output {
+                MaybePruned::Value(output) => output.is_some(),
+                MaybePruned::Pruned(digest) => *digest != Digest::ZERO,
+            };
+            if has_output {
+                return Err(VerificationError::ReceiptFormatError);
+            }
+        }
+
+        // Every assumption must be resolved by the assumption receipt at the same index.
+        let assumptions = self.assumptions()?;
+        if assumptions.len() != self.assumption_receipts.len() {
+            return Err(VerificationError::ReceiptFormatError);
+        }
+        for (assumption, receipt) in assumptions.iter().zip(&self.assumption_receipts) {
+            let assumption = assumption.as_value()?;
+            // A zero control root means the assumption is verified with the same parameters.
+            let assumption_ctx;
+            let ctx = match assumption.control_root {
+                Digest::ZERO => ctx,
+                control_root => {
+                    assumption_ctx = ctx.for_control_root(control_root);
+                    &assumption_ctx
+                }
+            };
+            receipt.verify_integrity_with_context(ctx)?;
+            let claim_digest = receipt.claim_digest()?;
+            if claim_digest != assumption.claim {
+                return Err(VerificationError::ClaimDigestMismatch {
+                    expected: assumption.claim,
+                    received: claim_digest,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Receipt {
+    /// Check that this receipt was produced by the guest with the given image ID, i.e. that the
+    /// pre-execution state of its claim has the image ID as digest. The seal is not checked.
+    pub fn ensure_image_id(&self, image_id: Digest) -> Result<(), VerificationError> {
+        let pre_state = match self.inner.claim()? {
+            MaybePruned::Value(claim) => claim.pre.digest::<sha::Impl>(),
+            MaybePruned::Pruned(_) => return Err(VerificationError::PrunedClaim),
+        };
+        if pre_state != image_id {
+            return Err(VerificationError::ImageIdMismatch {
+                expected: image_id,
+                received: pre_state,
+            });
+        }
+        Ok(())
+    }
+
+    /// Verify that this receipt proves a successful execution of the guest with the given image
+    /// ID.
+    ///
+    /// Checks the seal against the claim, then that the claim starts from `image_id`, exited with
+    /// `Halted(0)`, commits to the journal of the receipt and has no unresolved assumptions.
+    pub fn verify(&self, image_id: Digest) -> Result<(), VerificationError> {
+        self.verify_with_context(&VerifierContext::default(), image_id)
+    }
+
+    /// Same as [Receipt::verify], under the given [VerifierContext].
+    pub fn verify_with_context(
+        &self,
+        ctx: &VerifierContext,
+        image_id: Digest,
+    ) -> Result<(), VerificationError> {
+        if self.inner.verifier_parameters() != self.metadata.verifier_parameters {
+            return Err(VerificationError::VerifierParametersMismatch {
+                expected: self.inner.verifier_parameters(),
+                received: self.metadata.verifier_parameters,
+            });
+        }
+        self.inner.verify_integrity_with_context(ctx)?;
+
+        let claim = self.inner.claim()?;
+        let claim_digest = claim.digest::<sha::Impl>();
+        let claim = claim.as_value()?;
+
+        self.ensure_image_id(image_id)?;
+        if claim.exit_code != ExitCode::Halted(0) {
+            return Err(VerificationError::UnexpectedExitCode(claim.exit_code));
+        }
+        self.check_journal()?;
+
+        // All fields of the claim are constrained by a successful execution, so the remaining
+        // fields are checked at once by comparing against the expected claim digest.
+        let expected = ReceiptClaim::ok(image_id, self.journal.bytes.clone()).digest::<sha::Impl>();
+        if expected != claim_digest {
+            return Err(VerificationError::ClaimDigestMismatch {
+                expected,
+                received: claim_digest,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of verifying a [Receipt], along with the values that were checked.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Debug)]
+pub struct VerificationReport {
+    error: Option<VerificationError>,
+    kind: &'static str,
+    image_id: Digest,
+    journal_digest: Digest,
+    claim_digest: Option<Digest>,
+    exit_code: Option<ExitCode>,
+}
+
+impl VerificationReport {
+    /// Verify `receipt` for `image_id` under `ctx`, recording the result instead of returning it.
+    pub fn new(receipt: &Receipt, ctx: &VerifierContext, image_id: Digest) -> Self {
+        let claim = receipt.claim().ok();
+        Self {
+            error: receipt.verify_with_context(ctx, image_id).err(),
+            kind: receipt.inner.kind(),
+            image_id,
+            journal_digest: receipt.journal.digest::<sha::Impl>(),
+            claim_digest: claim.as_ref().map(|claim| claim.digest::<sha::Impl>()),
+            exit_code: claim
+                .as_ref()
+                .and_then(|claim| claim.as_value().ok())
+                .map(|claim| claim.exit_code),
+        }
+    }
+
+    /// The verification failure, if any.
+    pub fn error(&self) -> Option<&VerificationError> {
+        self.error.as_ref()
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl VerificationReport {
+    /// Whether the receipt passed every check.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Description of the failed check, undefined if the receipt is valid.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn reason(&self) -> Option<String> {
+        self.error.as_ref().map(|err| err.to_string())
+    }
+
+    /// Kind of the inner receipt, e.g. "Groth16".
+    #[cfg_attr(
+        feature = "wasm",
+        wasm_bindgen(getter, unchecked_return_type = "ReceiptKind")
+    )]
+    pub fn kind(&self) -> String {
+        self.kind.to_string()
+    }
+
+    /// Image ID the receipt was verified against.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn image_id(&self) -> Vec<u8> {
+        self.image_id.as_bytes().to_vec()
+    }
+
+    /// SHA-256 digest of the journal carried by the receipt.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn journal_digest(&self) -> Vec<u8> {
+        self.journal_digest.as_bytes().to_vec()
+    }
+
+    /// Digest of the claim proven by the receipt, undefined if it could not be computed.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn claim_digest(&self) -> Option<Vec<u8>> {
+        self.claim_digest.map(|digest| digest.as_bytes().to_vec())
+    }
+
+    /// Exit code of the proven execution, undefined if the claim is pruned.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
+    pub fn exit_code(&self) -> Option<ExitCodeInfo> {
+        self.exit_code.map(ExitCodeInfo::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Assumption;
+
+    #[test]
+    fn assumption_context_keeps_inner_control_root() {
+        let ctx = VerifierContext::default();
+        let assumption = Assumption {
+            claim: Digest::new([1; 8]),
+            control_root: Digest::new([2; 8]),
+        };
+        let assumption_ctx = ctx.for_control_root(assumption.control_root);
+
+        assert_eq!(assumption_ctx.control_roots(), [assumption.control_root]);
+        assert_eq!(assumption_ctx.inner_control_roots(), [ALLOWED_CONTROL_ROOT]);
+
+        let params = SuccinctReceiptVerifierParameters {
+            control_root: assumption.control_root,
+            inner_control_root: Some(ALLOWED_CONTROL_ROOT),
+            ..Default::default()
+        };
+        assert_eq!(
+            assumption_ctx.check_verifier_parameters(&params.digest::<sha::Impl>()),
+            Ok(())
+        );
+
+        // Dropping the inner control root makes it default to the guest-chosen control root.
+        let params = SuccinctReceiptVerifierParameters {
+            inner_control_root: None,
+            ..params
+        };
+        let digest = params.digest::<sha::Impl>();
+        assert_eq!(
+            assumption_ctx.check_verifier_parameters(&digest),
+            Err(VerificationError::UnacceptedVerifierParameters(digest))
+        );
+    }
+}
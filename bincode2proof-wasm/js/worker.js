@@ -0,0 +1,65 @@
+// Promise-based client of a Web Worker running the conversions of this package, used by the
+// `ConversionWorker` class of the `worker` feature.
+
+// Bindings generated by wasm-bindgen for `--target web`, relative to this snippet, which is
+// copied to `snippets/<crate>/js/worker.js` next to them.
+const DEFAULT_MODULE_URL = new URL('../../../bincode2proof.js', import.meta.url);
+
+// The worker imports the bindings by absolute URL, so it can be started from a blob without a
+// separate script to bundle.
+const workerSource = (moduleUrl) => `
+import init, { __workerDispatch } from ${JSON.stringify(moduleUrl)};
+const ready = init();
+self.onmessage = async ({ data: { id, method, args } }) => {
+  try {
+    await ready;
+    self.postMessage({ id, result: __workerDispatch(method, args) });
+  } catch (error) {
+    self.postMessage({ id, error: error instanceof Error ? error.message : String(error) });
+  }
+};
+`;
+
+export class WorkerClient {
+  constructor(moduleUrl) {
+    const source = workerSource(String(moduleUrl ?? DEFAULT_MODULE_URL));
+    this.blobUrl = URL.createObjectURL(new Blob([source], { type: 'text/javascript' }));
+    this.worker = new Worker(this.blobUrl, { type: 'module' });
+    this.pending = new Map();
+    this.nextId = 0;
+    this.worker.onmessage = ({ data: { id, result, error } }) => {
+      const pending = this.pending.get(id);
+      if (pending === undefined) {
+        return;
+      }
+      this.pending.delete(id);
+      if (error === undefined) {
+        pending.resolve(result);
+      } else {
+        pending.reject(new Error(error));
+      }
+    };
+    this.worker.onerror = (event) => this.rejectAll(new Error(event.message));
+  }
+
+  call(method, args) {
+    return new Promise((resolve, reject) => {
+      const id = this.nextId++;
+      this.pending.set(id, { resolve, reject });
+      this.worker.postMessage({ id, method, args });
+    });
+  }
+
+  terminate() {
+    this.worker.terminate();
+    URL.revokeObjectURL(this.blobUrl);
+    this.rejectAll(new Error('worker terminated'));
+  }
+
+  rejectAll(error) {
+    for (const { reject } of this.pending.values()) {
+      reject(error);
+    }
+    this.pending.clear();
+  }
+}
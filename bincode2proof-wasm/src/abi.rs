@@ -0,0 +1,47 @@
+//! JavaScript bindings for decoding Solidity ABI encoded journals.
+
+use alloy_dyn_abi::DynSolValue;
+use bincode2proof_core::{abi::decode_journal, hex_string, ConvertError};
+use wasm_bindgen::prelude::*;
+
+/// Convert a decoded ABI value to a plain JavaScript value.
+///
+/// Integers become `BigInt`s, addresses and byte strings become `0x` prefixed hex strings, and
+/// tuples and arrays become arrays.
+fn to_js(value: &DynSolValue) -> Result<JsValue, JsError> {
+    let js = match value {
+        DynSolValue::Bool(value) => JsValue::from_bool(*value),
+        DynSolValue::Int(value, _) => js_sys::BigInt::new(&value.to_string().into())
+            .map_err(|_| ConvertError::AbiError(format!("invalid integer {value}")))?
+            .into(),
+        DynSolValue::Uint(value, _) => js_sys::BigInt::new(&value.to_string().into())
+            .map_err(|_| ConvertError::AbiError(format!("invalid integer {value}")))?
+            .into(),
+        DynSolValue::FixedBytes(word, size) => hex_string(&word[..*size]).into(),
+        DynSolValue::Address(address) => address.to_checksum(None).into(),
+        DynSolValue::Function(function) => hex_string(function.as_slice()).into(),
+        DynSolValue::Bytes(bytes) => hex_string(bytes).into(),
+        DynSolValue::String(value) => JsValue::from_str(value),
+        DynSolValue::Array(values)
+        | DynSolValue::FixedArray(values)
+        | DynSolValue::Tuple(values) => {
+            let array = js_sys::Array::new();
+            for value in values {
+                array.push(&to_js(value)?);
+            }
+            array.into()
+        }
+    };
+    Ok(js)
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const ABI_VALUE_TS: &str = r#"
+export type AbiValue = bigint | boolean | string | AbiValue[];
+"#;
+
+/// Decode an ABI encoded journal into a JavaScript value, given its Solidity type string.
+#[wasm_bindgen(unchecked_return_type = "AbiValue")]
+pub fn decode_journal_abi(journal: Vec<u8>, sol_type: &str) -> Result<JsValue, JsError> {
+    to_js(&decode_journal(&journal, sol_type)?)
+}
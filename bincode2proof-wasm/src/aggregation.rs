@@ -0,0 +1,19 @@
+//! JavaScript bindings for building aggregation Merkle sets.
+
+use bincode2proof_core::{aggregation::MerkleSet, Digest, DIGEST_BYTES};
+use wasm_bindgen::prelude::*;
+
+/// Build the Merkle set over claim digests given as concatenated 32-byte values.
+#[wasm_bindgen]
+pub fn build_merkle_set(claim_digests: Vec<u8>) -> Result<MerkleSet, JsError> {
+    if !claim_digests.len().is_multiple_of(DIGEST_BYTES) {
+        return Err(JsError::new(
+            "claim digests must be a concatenation of 32-byte digests",
+        ));
+    }
+    let leaves: Vec<Digest> = claim_digests
+        .chunks_exact(DIGEST_BYTES)
+        .map(Digest::try_from)
+        .collect::<Result<_, _>>()?;
+    Ok(MerkleSet::new(&leaves)?)
+}
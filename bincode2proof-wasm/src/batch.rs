@@ -0,0 +1,18 @@
+//! JavaScript bindings for converting batches of receipts.
+
+use bincode2proof_codecs::batch::convert_batch;
+use bincode2proof_core::ProofData;
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+/// Convert a batch of bincode encoded receipts, failing with the index of the first receipt that
+/// could not be converted.
+#[wasm_bindgen(js_name = convert_batch)]
+pub fn convert_batch_js(receipts: Vec<Uint8Array>) -> Result<Vec<ProofData>, JsError> {
+    let receipts: Vec<Vec<u8>> = receipts.iter().map(Uint8Array::to_vec).collect();
+    convert_batch(&receipts)
+        .into_iter()
+        .enumerate()
+        .map(|(index, proof)| proof.map_err(|err| JsError::new(&format!("receipt {index}: {err}"))))
+        .collect()
+}
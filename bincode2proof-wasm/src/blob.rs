@@ -0,0 +1,22 @@
+//! JavaScript bindings for packing receipts into EIP-4844 blobs.
+
+use bincode2proof_core::blob::{pack_blobs, unpack_blobs};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+/// Pack a serialized receipt into EIP-4844 blobs of 131072 bytes each.
+#[wasm_bindgen(js_name = pack_blobs)]
+pub fn pack_blobs_js(data: &[u8]) -> Result<Vec<Uint8Array>, JsError> {
+    let blobs = pack_blobs(data)?;
+    Ok(blobs
+        .iter()
+        .map(|blob| Uint8Array::from(&blob[..]))
+        .collect())
+}
+
+/// Recover a serialized receipt from the blobs produced by `pack_blobs`.
+#[wasm_bindgen(js_name = unpack_blobs)]
+pub fn unpack_blobs_js(blobs: Vec<Uint8Array>) -> Result<Vec<u8>, JsError> {
+    let blobs: Vec<Vec<u8>> = blobs.iter().map(Uint8Array::to_vec).collect();
+    Ok(unpack_blobs(&blobs)?)
+}
@@ -0,0 +1,481 @@
+//! Client for the Bonsai REST API.
+//!
+//! Built on `reqwest`, which issues requests through `fetch` when compiled to WebAssembly and
+//! through its native HTTP stack otherwise.
+
+use core::time::Duration;
+
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use bincode2proof_codecs::codec;
+#[cfg(feature = "compression")]
+use bincode2proof_codecs::compress::{self, Compression};
+use bincode2proof_core::{ConvertError, ProofData, Receipt};
+
+/// HTTP header key for the API key
+pub const API_KEY_HEADER: &str = "x-api-key";
+/// HTTP header for the risc0 version string
+pub const VERSION_HEADER: &str = "x-risc0-version";
+/// Environment variable name for the API url
+pub const API_URL_ENVVAR: &str = "BONSAI_API_URL";
+/// Environment variable name for the API key
+pub const API_KEY_ENVVAR: &str = "BONSAI_API_KEY";
+
+/// Errors returned by the Bonsai [Client].
+#[derive(Debug, thiserror::Error)]
+pub enum SdkErr {
+    /// The server replied with an unexpected status.
+    #[error("server error: {0}")]
+    InternalServerErr(String),
+
+    /// The HTTP request itself failed.
+    #[error("HTTP error from reqwest: {0}")]
+    HttpErr(#[from] reqwest::Error),
+
+    /// The HTTP headers could not be constructed from the API key or version.
+    #[error("HTTP header failed to construct: {0}")]
+    HttpHeaderErr(#[from] header::InvalidHeaderValue),
+
+    /// No receipt exists for the session.
+    #[error("receipt not found")]
+    ReceiptNotFound,
+
+    /// The API url was not provided.
+    #[error("missing {API_URL_ENVVAR} env var")]
+    MissingApiUrl,
+
+    /// The API key was not provided.
+    #[error("missing {API_KEY_ENVVAR} env var")]
+    MissingApiKey,
+
+    /// A session or SNARK workflow finished without succeeding.
+    #[error("workflow exited: {status} - err: {}", error_msg.as_deref().unwrap_or_default())]
+    WorkflowExited {
+        status: String,
+        error_msg: Option<String>,
+    },
+
+    /// A workflow was still running after the given number of status polls.
+    #[error("workflow still running after {0} status polls")]
+    PollLimitExceeded(u32),
+
+    /// The downloaded receipt could not be decoded or encoded.
+    #[error(transparent)]
+    ConvertErr(#[from] ConvertError),
+}
+
+/// Response of an upload request.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UploadRes {
+    /// Presigned URL to be supplied to a PUT request
+    pub url: String,
+    /// Generated UUID for this input
+    pub uuid: String,
+}
+
+/// Response of an image upload request.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ImgUploadRes {
+    /// Presigned URL to be supplied to a PUT request
+    pub url: String,
+}
+
+/// Whether an image already exists on the server or needs uploading.
+#[derive(Clone, Debug)]
+pub enum ImageExistsOpt {
+    Exists,
+    New(ImgUploadRes),
+}
+
+/// Request body for creating a proving session.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProofReq {
+    /// Image ID of the guest to prove.
+    pub img: String,
+    /// Input ID returned by [Client::upload_input].
+    pub input: String,
+    /// Receipt IDs of assumptions used by the guest.
+    pub assumptions: Vec<String>,
+    /// Run the executor only, without proving.
+    pub execute_only: bool,
+    /// Executor cycle limit, in millions of cycles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec_cycle_limit: Option<u64>,
+}
+
+/// Response of a session creation request.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreateSessRes {
+    /// Generated UUID for the session
+    pub uuid: String,
+}
+
+/// Successful session stats.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionStats {
+    /// Count of segments in this proof request
+    pub segments: usize,
+    /// Total cycles run within guest
+    pub total_cycles: u64,
+    /// User cycles run within guest, slightly below total overhead cycles
+    pub cycles: u64,
+}
+
+/// Status of a proving session.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionStatusRes {
+    /// Current status: "RUNNING", "SUCCEEDED", "FAILED", "TIMED_OUT", or "ABORTED"
+    pub status: String,
+    /// Final receipt download URL (present if status is "SUCCEEDED")
+    pub receipt_url: Option<String>,
+    /// Session error message (present if the session is not "RUNNING" or "SUCCEEDED")
+    pub error_msg: Option<String>,
+    /// Session proving state (e.g., "Setup", "Executor", "ProveSegments: N/M", "Planner", etc.)
+    pub state: Option<String>,
+    /// Elapsed time for the session in seconds
+    pub elapsed_time: Option<f64>,
+    /// Successful session stats (if available)
+    pub stats: Option<SessionStats>,
+}
+
+/// Presigned receipt download location.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReceiptDownload {
+    /// Pre-Signed URL that the receipt can be downloaded (GET) from
+    pub url: String,
+}
+
+/// Request body for converting a session receipt to a SNARK.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SnarkReq {
+    /// Existing Session ID from [SessionId]
+    pub session_id: String,
+}
+
+/// Status of a SNARK conversion.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SnarkStatusRes {
+    /// Current status
+    ///
+    /// values: `[ RUNNING | SUCCEEDED | FAILED | TIMED_OUT | ABORTED ]`
+    pub status: String,
+    /// SNARK receipt download URL
+    ///
+    /// Url to download the snark (receipt `risc0::Receipt` bincode encoded)
+    pub output: Option<String>,
+    /// Snark Error message
+    ///
+    /// If the SNARK status is not `RUNNING` or `SUCCEEDED`, this is the
+    /// error raised from within bonsai.
+    pub error_msg: Option<String>,
+}
+
+/// Identifier of a SNARK conversion.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnarkId {
+    pub uuid: String,
+}
+
+impl SnarkId {
+    pub fn new(uuid: String) -> Self {
+        Self { uuid }
+    }
+
+    /// Fetch the current status of the SNARK conversion.
+    pub async fn status(&self, client: &Client) -> Result<SnarkStatusRes, SdkErr> {
+        let url = format!("{}/snark/status/{}", client.url, self.uuid);
+        let res = client.client.get(url).send().await?;
+        if !res.status().is_success() {
+            return Err(SdkErr::InternalServerErr(res.text().await?));
+        }
+        Ok(res.json::<SnarkStatusRes>().await?)
+    }
+}
+
+/// Wait for the given duration without blocking the event loop on wasm.
+async fn sleep(duration: Duration) {
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+}
+
+/// Identifier of a proving session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionId {
+    pub uuid: String,
+}
+
+impl SessionId {
+    pub fn new(uuid: String) -> Self {
+        Self { uuid }
+    }
+
+    /// Fetch the current status of the session.
+    pub async fn status(&self, client: &Client) -> Result<SessionStatusRes, SdkErr> {
+        let url = format!("{}/sessions/status/{}", client.url, self.uuid);
+        let res = client.client.get(url).send().await?;
+        if !res.status().is_success() {
+            return Err(SdkErr::InternalServerErr(res.text().await?));
+        }
+        Ok(res.json::<SessionStatusRes>().await?)
+    }
+}
+
+/// Bonsai API client.
+#[derive(Clone, Debug)]
+pub struct Client {
+    pub(crate) url: String,
+    pub(crate) client: reqwest::Client,
+    #[cfg(feature = "compression")]
+    pub(crate) receipt_compression: Option<Compression>,
+}
+
+impl Client {
+    /// Construct a client from an API url, API key and risc0 version string.
+    pub fn from_parts(url: String, key: String, risc0_version: &str) -> Result<Self, SdkErr> {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(API_KEY_HEADER, header::HeaderValue::from_str(&key)?);
+        headers.insert(VERSION_HEADER, header::HeaderValue::from_str(risc0_version)?);
+        let client = reqwest::Client::builder().default_headers(headers).build()?;
+        let url = url.strip_suffix('/').unwrap_or(&url).to_string();
+        Ok(Self {
+            url,
+            client,
+            #[cfg(feature = "compression")]
+            receipt_compression: None,
+        })
+    }
+
+    /// Compress receipts uploaded with [Client::upload_receipt], and decompress those downloaded
+    /// with [Client::receipt_download], with the given codec.
+    #[cfg(feature = "compression")]
+    pub fn with_receipt_compression(mut self, compression: Compression) -> Self {
+        self.receipt_compression = Some(compression);
+        self
+    }
+
+    /// Construct a client from the `BONSAI_API_URL` and `BONSAI_API_KEY` environment variables.
+    pub fn from_env(risc0_version: &str) -> Result<Self, SdkErr> {
+        let url = std::env::var(API_URL_ENVVAR).map_err(|_| SdkErr::MissingApiUrl)?;
+        let key = std::env::var(API_KEY_ENVVAR).map_err(|_| SdkErr::MissingApiKey)?;
+        Self::from_parts(url, key, risc0_version)
+    }
+
+    async fn get_image_upload_url(&self, image_id: &str) -> Result<ImageExistsOpt, SdkErr> {
+        let url = format!("{}/images/upload/{image_id}", self.url);
+        let res = self.client.get(url).send().await?;
+        if res.status() == reqwest::StatusCode::NO_CONTENT {
+            return Ok(ImageExistsOpt::Exists);
+        }
+        if !res.status().is_success() {
+            return Err(SdkErr::InternalServerErr(res.text().await?));
+        }
+        Ok(ImageExistsOpt::New(res.json::<ImgUploadRes>().await?))
+    }
+
+    async fn put_data(&self, url: &str, body: Vec<u8>) -> Result<(), SdkErr> {
+        let res = self.client.put(url).body(body).send().await?;
+        if !res.status().is_success() {
+            return Err(SdkErr::InternalServerErr(res.text().await?));
+        }
+        Ok(())
+    }
+
+    async fn get_upload_url(&self, route: &str) -> Result<UploadRes, SdkErr> {
+        let url = format!("{}/{route}/upload", self.url);
+        let res = self.client.get(url).send().await?;
+        if !res.status().is_success() {
+            return Err(SdkErr::InternalServerErr(res.text().await?));
+        }
+        Ok(res.json::<UploadRes>().await?)
+    }
+
+    /// Upload a guest ELF for the given image ID.
+    ///
+    /// Returns `true` if the image already existed and nothing was uploaded.
+    pub async fn upload_img(&self, image_id: &str, buf: Vec<u8>) -> Result<bool, SdkErr> {
+        match self.get_image_upload_url(image_id).await? {
+            ImageExistsOpt::Exists => Ok(true),
+            ImageExistsOpt::New(upload_res) => {
+                self.put_data(&upload_res.url, buf).await?;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Upload guest input data, returning the input ID.
+    pub async fn upload_input(&self, buf: Vec<u8>) -> Result<String, SdkErr> {
+        let upload_data = self.get_upload_url("inputs").await?;
+        self.put_data(&upload_data.url, buf).await?;
+        Ok(upload_data.uuid)
+    }
+
+    /// Upload a bincode encoded receipt, e.g. to be used as an assumption, returning the receipt
+    /// ID.
+    pub async fn upload_receipt(&self, buf: Vec<u8>) -> Result<String, SdkErr> {
+        #[cfg(feature = "compression")]
+        let buf = match self.receipt_compression {
+            Some(compression) => compress::compress(&buf, compression)?,
+            None => buf,
+        };
+        let upload_data = self.get_upload_url("receipts").await?;
+        self.put_data(&upload_data.url, buf).await?;
+        Ok(upload_data.uuid)
+    }
+
+    /// Create a proving session for an uploaded image and input.
+    pub async fn create_session(
+        &self,
+        img_id: String,
+        input_id: String,
+        assumptions: Vec<String>,
+        execute_only: bool,
+    ) -> Result<SessionId, SdkErr> {
+        self.create_session_with_limit(img_id, input_id, assumptions, execute_only, None)
+            .await
+    }
+
+    /// Create a proving session with an executor cycle limit, in millions of cycles.
+    pub async fn create_session_with_limit(
+        &self,
+        img_id: String,
+        input_id: String,
+        assumptions: Vec<String>,
+        execute_only: bool,
+        exec_cycle_limit: Option<u64>,
+    ) -> Result<SessionId, SdkErr> {
+        let url = format!("{}/sessions/create", self.url);
+        let req = ProofReq {
+            img: img_id,
+            input: input_id,
+            assumptions,
+            execute_only,
+            exec_cycle_limit,
+        };
+        let res = self.client.post(url).json(&req).send().await?;
+        if !res.status().is_success() {
+            return Err(SdkErr::InternalServerErr(res.text().await?));
+        }
+        let res: CreateSessRes = res.json().await?;
+        Ok(SessionId::new(res.uuid))
+    }
+
+    /// Download raw bytes from a (presigned) url.
+    pub async fn download(&self, url: &str) -> Result<Vec<u8>, SdkErr> {
+        let res = self.client.get(url).send().await?;
+        if !res.status().is_success() {
+            return Err(SdkErr::InternalServerErr(res.text().await?));
+        }
+        Ok(res.bytes().await?.to_vec())
+    }
+
+    /// Download the receipt of a finished session and decode it into a [Receipt].
+    pub async fn receipt_download(&self, session_id: &SessionId) -> Result<Receipt, SdkErr> {
+        let url = format!("{}/receipts/{}", self.url, session_id.uuid);
+        let res = self.client.get(url).send().await?;
+        if res.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(SdkErr::ReceiptNotFound);
+        }
+        if !res.status().is_success() {
+            return Err(SdkErr::InternalServerErr(res.text().await?));
+        }
+        let download: ReceiptDownload = res.json().await?;
+        let bytes = self.download(&download.url).await?;
+        #[cfg(feature = "compression")]
+        let bytes = match self.receipt_compression {
+            Some(compression) => compress::decompress(&bytes, compression)?,
+            None => bytes,
+        };
+        Ok(codec::decode(&bytes, codec::Format::Bincode)?)
+    }
+
+    /// Request the conversion of a session's succinct receipt into a Groth16 SNARK.
+    pub async fn create_snark(&self, session_id: String) -> Result<SnarkId, SdkErr> {
+        let url = format!("{}/snark/create", self.url);
+        let req = SnarkReq { session_id };
+        let res = self.client.post(url).json(&req).send().await?;
+        if !res.status().is_success() {
+            return Err(SdkErr::InternalServerErr(res.text().await?));
+        }
+        let res: CreateSessRes = res.json().await?;
+        Ok(SnarkId::new(res.uuid))
+    }
+
+    /// Wait for a proving session to finish, convert its receipt to a Groth16 SNARK, and encode
+    /// the result for on-chain verification.
+    ///
+    /// Session and SNARK statuses are polled every `poll_interval`, at most `max_polls` times in
+    /// total, after which [SdkErr::PollLimitExceeded] is returned.
+    pub async fn prove_and_encode(
+        &self,
+        session_id: &SessionId,
+        poll_interval: Duration,
+        max_polls: u32,
+    ) -> Result<ProofData, SdkErr> {
+        let mut polls = 0;
+        let mut next_poll = || {
+            polls += 1;
+            if polls > max_polls {
+                return Err(SdkErr::PollLimitExceeded(max_polls));
+            }
+            Ok(())
+        };
+
+        loop {
+            next_poll()?;
+            let res = session_id.status(self).await?;
+            match res.status.as_str() {
+                "RUNNING" => sleep(poll_interval).await,
+                "SUCCEEDED" => break,
+                _ => {
+                    return Err(SdkErr::WorkflowExited {
+                        status: res.status,
+                        error_msg: res.error_msg,
+                    })
+                }
+            }
+        }
+
+        let snark_id = self.create_snark(session_id.uuid.clone()).await?;
+        let output = loop {
+            next_poll()?;
+            let res = snark_id.status(self).await?;
+            match res.status.as_str() {
+                "RUNNING" => sleep(poll_interval).await,
+                "SUCCEEDED" => {
+                    break res.output.ok_or_else(|| {
+                        SdkErr::InternalServerErr(
+                            "missing receipt on completed SNARK".to_string(),
+                        )
+                    })?
+                }
+                _ => {
+                    return Err(SdkErr::WorkflowExited {
+                        status: res.status,
+                        error_msg: res.error_msg,
+                    })
+                }
+            }
+        };
+
+        let bytes = self.download(&output).await?;
+        let receipt: Receipt = codec::decode(&bytes, codec::Format::Bincode)?;
+        Ok(ProofData::try_from(receipt)?)
+    }
+}
+
+/// Fetch a bincode encoded [Receipt] from a URL, such as a Bonsai receipt download link or an
+/// IPFS gateway, and convert it like [convert](crate::convert).
+#[wasm_bindgen]
+pub async fn convert_from_url(url: String) -> Result<ProofData, JsError> {
+    let res = reqwest::get(url).await?;
+    if !res.status().is_success() {
+        return Err(SdkErr::InternalServerErr(res.text().await?).into());
+    }
+    let bytes = res.bytes().await?;
+    let receipt: Receipt = codec::decode(&bytes, codec::Format::Bincode)?;
+    Ok(ProofData::try_from(receipt)?)
+}
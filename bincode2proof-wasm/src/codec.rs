@@ -0,0 +1,78 @@
+//! JavaScript bindings for converting receipts of any supported encoding.
+
+use bincode2proof_codecs::codec::{
+    decode, decode_auto, decode_text, encode, BincodeConfig, Format,
+};
+use bincode2proof_core::{limits, ProofData, Receipt};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const FORMAT_TS: &str = r#"
+export type ReceiptFormat = "bincode" | "borsh" | "json" | "cbor" | "msgpack" | "postcard";
+"#;
+
+/// [ProofData] converted from a receipt of a detected [Format].
+#[wasm_bindgen]
+pub struct AutoConversion {
+    format: Format,
+    proof: ProofData,
+}
+
+#[wasm_bindgen]
+impl AutoConversion {
+    /// Name of the detected format.
+    #[wasm_bindgen(getter, unchecked_return_type = "ReceiptFormat")]
+    pub fn format(&self) -> String {
+        self.format.name().to_string()
+    }
+
+    /// The converted proof data.
+    #[wasm_bindgen(getter)]
+    pub fn proof(&self) -> ProofData {
+        self.proof.clone()
+    }
+}
+
+/// Same as [convert](crate::convert), detecting whether the [Receipt] is encoded in bincode,
+/// borsh, JSON, CBOR, MessagePack or postcard.
+#[wasm_bindgen]
+pub fn convert_auto(bytes: Vec<u8>) -> Result<AutoConversion, JsError> {
+    let (receipt, format) = decode_auto(&bytes)?;
+    Ok(AutoConversion {
+        format,
+        proof: ProofData::try_from(receipt)?,
+    })
+}
+
+/// Same as [convert_auto], taking the receipt as a `0x`-prefixed hex or base64 string, as
+/// commonly returned by JSON APIs.
+#[wasm_bindgen]
+pub fn convert_str(text: &str) -> Result<AutoConversion, JsError> {
+    convert_auto(decode_text(text)?)
+}
+
+/// Re-encode a [Receipt] from one format to another.
+///
+/// Formats are named "bincode", "borsh", "json", "cbor", "msgpack" or "postcard".
+#[wasm_bindgen]
+pub fn transcode(
+    input: Vec<u8>,
+    #[wasm_bindgen(unchecked_param_type = "ReceiptFormat")] from_format: &str,
+    #[wasm_bindgen(unchecked_param_type = "ReceiptFormat")] to_format: &str,
+) -> Result<Vec<u8>, JsError> {
+    let from = from_format.parse()?;
+    let to = to_format.parse()?;
+    let receipt: Receipt = decode(&input, from)?;
+    Ok(encode(&receipt, to)?)
+}
+
+/// Same as [convert](crate::convert), decoding the bincode [Receipt] with the given
+/// [BincodeConfig], e.g. to accept bincode 2 encodings or cap the bytes read.
+#[wasm_bindgen]
+pub fn convert_with_bincode_config(
+    bincode: Vec<u8>,
+    config: &BincodeConfig,
+) -> Result<ProofData, JsError> {
+    let receipt: Receipt = limits::enforce(|| config.deserialize(&bincode))?;
+    Ok(ProofData::try_from(receipt)?)
+}
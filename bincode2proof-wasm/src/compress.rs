@@ -0,0 +1,27 @@
+//! JavaScript bindings for compressing encoded receipts.
+
+use bincode2proof_codecs::compress::{compress, decompress};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const COMPRESSION_TS: &str = r#"
+export type Compression = "gzip" | "zstd" | "brotli";
+"#;
+
+/// Compress an encoded receipt with the named codec, "gzip", "zstd" or "brotli".
+#[wasm_bindgen]
+pub fn compress_receipt(
+    bytes: &[u8],
+    #[wasm_bindgen(unchecked_param_type = "Compression")] codec: &str,
+) -> Result<Vec<u8>, JsError> {
+    Ok(compress(bytes, codec.parse()?)?)
+}
+
+/// Decompress an encoded receipt compressed with the named codec, "gzip", "zstd" or "brotli".
+#[wasm_bindgen]
+pub fn decompress_receipt(
+    bytes: &[u8],
+    #[wasm_bindgen(unchecked_param_type = "Compression")] codec: &str,
+) -> Result<Vec<u8>, JsError> {
+    Ok(decompress(bytes, codec.parse()?)?)
+}
@@ -0,0 +1,13 @@
+//! JavaScript bindings for the CosmWasm verifier messages.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::{cosmwasm::CosmWasmMsgEncoder, encoder::SealEncoder, Receipt};
+use wasm_bindgen::prelude::*;
+
+/// JSON execute message of a CosmWasm verifier contract for a bincode encoded [Receipt].
+#[wasm_bindgen]
+pub fn cosmwasm_verify_msg(receipt_bytes: Vec<u8>) -> Result<String, JsError> {
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    let msg = CosmWasmMsgEncoder.encode_seal(&receipt)?;
+    Ok(String::from_utf8(msg).expect("serde_json produces valid UTF-8"))
+}
@@ -0,0 +1,18 @@
+//! JavaScript bindings for encoding seals for the verifiers of other chains.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::{encoder::seal_encoder_from_name, ConvertError, ProofData, Receipt};
+use wasm_bindgen::prelude::*;
+
+/// Same as [convert](crate::convert), encoding the seal for the verifier of the given target
+/// chain, e.g. "eth".
+#[wasm_bindgen]
+pub fn convert_for(bincode: Vec<u8>, target: &str) -> Result<ProofData, JsError> {
+    let encoder = seal_encoder_from_name(target)
+        .ok_or_else(|| ConvertError::UnknownTarget(target.to_string()))?;
+    let receipt: Receipt = codec::decode(&bincode, codec::Format::Bincode)?;
+    Ok(ProofData::from_receipt_with_encoder(
+        receipt,
+        encoder.as_ref(),
+    )?)
+}
@@ -0,0 +1,21 @@
+//! JavaScript bindings for the calldata of the RISC Zero verifier contracts.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::{
+    digest_from_js, encode_seal, ethereum::verify_calldata, Digestible, sha, Receipt,
+};
+use wasm_bindgen::prelude::*;
+
+/// Build the `IRiscZeroVerifier.verify` calldata for a bincode encoded [Receipt] and the image
+/// ID of the guest that produced it.
+#[wasm_bindgen]
+pub fn encode_verify_calldata(
+    receipt_bytes: Vec<u8>,
+    image_id: Vec<u8>,
+) -> Result<Vec<u8>, JsError> {
+    let image_id = digest_from_js(&image_id, "image ID")?;
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    let seal = encode_seal(&receipt)?;
+    let journal_digest = receipt.journal.digest::<sha::Impl>();
+    Ok(verify_calldata(&seal, &image_id, &journal_digest))
+}
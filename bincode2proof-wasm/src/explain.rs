@@ -0,0 +1,23 @@
+//! JavaScript bindings for human-readable summaries of receipts.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::Receipt;
+use wasm_bindgen::prelude::*;
+
+use crate::handle::ReceiptHandle;
+
+#[wasm_bindgen]
+impl ReceiptHandle {
+    /// Human-readable summary of the receipt, see [explain].
+    pub fn explain(&self) -> String {
+        self.receipt().explain()
+    }
+}
+
+/// Human-readable summary of a bincode encoded [Receipt]: its kind, the risc0 release guessed
+/// from its selector, exit code, journal length, number of assumptions and total seal size.
+#[wasm_bindgen]
+pub fn explain(receipt_bytes: Vec<u8>) -> Result<String, JsError> {
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    Ok(receipt.explain())
+}
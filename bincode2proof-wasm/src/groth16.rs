@@ -0,0 +1,60 @@
+//! JavaScript bindings for Groth16 receipts.
+
+use bincode2proof_codecs::codec;
+#[cfg(feature = "json")]
+use bincode2proof_core::groth16::SnarkjsExport;
+use bincode2proof_core::{
+    digest_from_js,
+    groth16::{receipt_from_seal, Groth16Points},
+    ConvertError, Digest, InnerReceipt, Receipt, VerificationError, DIGEST_BYTES,
+};
+use wasm_bindgen::prelude::*;
+
+/// Export a bincode encoded Groth16 [Receipt] for verification with snarkjs.
+#[cfg(feature = "json")]
+#[wasm_bindgen]
+pub fn export_snarkjs(receipt_bytes: Vec<u8>) -> Result<SnarkjsExport, JsError> {
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    let InnerReceipt::Groth16(inner) = &receipt.inner else {
+        return Err(ConvertError::UnsupportedReceiptKind(receipt.inner.kind()).into());
+    };
+    Ok(SnarkjsExport::new(inner)?)
+}
+
+/// Verify a bincode encoded Groth16 [Receipt] for the given image ID.
+///
+/// Checks the Groth16 seal against the receipt claim and that the claim was produced by the guest
+/// with the given image ID. Failures are thrown to JavaScript as an `Error`.
+#[wasm_bindgen]
+pub fn verify_groth16(receipt_bytes: Vec<u8>, image_id: Vec<u8>) -> Result<(), JsError> {
+    let image_id: [u8; DIGEST_BYTES] = image_id
+        .try_into()
+        .map_err(|_| VerificationError::ReceiptFormatError)?;
+    let image_id = Digest::from_bytes(image_id);
+
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    let InnerReceipt::Groth16(inner) = &receipt.inner else {
+        return Err(VerificationError::UnsupportedReceiptKind(receipt.inner.kind()).into());
+    };
+    inner.verify_integrity()?;
+    Ok(receipt.ensure_image_id(image_id)?)
+}
+
+/// Rebuild a bincode encoded Groth16 [Receipt] from the seal, journal and image ID passed to
+/// `IRiscZeroVerifier.verify` on-chain.
+#[wasm_bindgen]
+pub fn decode_seal(seal: Vec<u8>, journal: Vec<u8>, image_id: Vec<u8>) -> Result<Vec<u8>, JsError> {
+    let image_id = digest_from_js(&image_id, "image ID")?;
+    let receipt = receipt_from_seal(&seal, journal, image_id)?;
+    Ok(codec::encode(&receipt, codec::Format::Bincode)?)
+}
+
+/// Split the seal of a bincode encoded Groth16 [Receipt] into its `(A, B, C)` proof points.
+#[wasm_bindgen]
+pub fn decompose_seal(receipt_bytes: Vec<u8>) -> Result<Groth16Points, JsError> {
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    let InnerReceipt::Groth16(inner) = &receipt.inner else {
+        return Err(ConvertError::UnsupportedReceiptKind(receipt.inner.kind()).into());
+    };
+    Ok(inner.points()?)
+}
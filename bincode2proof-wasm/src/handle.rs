@@ -0,0 +1,163 @@
+//! A decoded [Receipt] that can be queried repeatedly from JavaScript.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::{
+    encode_seal,
+    info::{AssumptionInfo, ExitCodeInfo},
+    Digestible, sha, Assumption, ConvertError, MaybePruned, Receipt,
+};
+use wasm_bindgen::prelude::*;
+
+/// A [Receipt] decoded once and kept in wasm memory, so that multi-megabyte receipts are not
+/// decoded again on every query.
+///
+/// The receipt is kept until the JavaScript object is garbage collected, or until
+/// [ReceiptHandle::dispose] or the generated `free()` is called.
+#[wasm_bindgen]
+pub struct ReceiptHandle {
+    receipt: Receipt,
+}
+
+impl ReceiptHandle {
+    /// The decoded [Receipt].
+    pub fn receipt(&self) -> &Receipt {
+        &self.receipt
+    }
+}
+
+impl From<Receipt> for ReceiptHandle {
+    fn from(receipt: Receipt) -> Self {
+        Self { receipt }
+    }
+}
+
+#[wasm_bindgen]
+impl ReceiptHandle {
+    /// Decode a bincode encoded [Receipt].
+    #[wasm_bindgen(constructor)]
+    pub fn new(bincode: Vec<u8>) -> Result<ReceiptHandle, JsError> {
+        let receipt: Receipt = codec::decode(&bincode, codec::Format::Bincode)?;
+        Ok(Self { receipt })
+    }
+
+    /// Release the decoded receipt immediately instead of waiting for the garbage collector. The
+    /// handle cannot be used afterwards.
+    pub fn dispose(self) {}
+
+    /// Seal encoded for on-chain verification, prefixed with its selector.
+    pub fn seal(&self) -> Result<Vec<u8>, JsError> {
+        Ok(encode_seal(&self.receipt)?)
+    }
+
+    /// The raw bytes of the journal.
+    pub fn journal(&self) -> Vec<u8> {
+        self.receipt.journal.bytes.clone()
+    }
+
+    /// SHA-256 digest of the journal, as taken by `IRiscZeroVerifier.verify`.
+    pub fn journal_digest(&self) -> Vec<u8> {
+        self.receipt.journal.digest::<sha::Impl>().as_bytes().to_vec()
+    }
+
+    /// Digest of the [ReceiptClaim](bincode2proof_core::ReceiptClaim) proven by the receipt.
+    pub fn claim_digest(&self) -> Result<Vec<u8>, JsError> {
+        let claim = self.receipt.claim()?;
+        Ok(claim.digest::<sha::Impl>().as_bytes().to_vec())
+    }
+
+    /// Digest of the post-execution [SystemState](bincode2proof_core::SystemState) of the claim,
+    /// as pinned by pre-1.0 verifier interfaces. Fails if the claim was pruned from the receipt.
+    pub fn post_state_digest(&self) -> Result<Vec<u8>, JsError> {
+        let claim = self.receipt.claim()?;
+        let post = &claim.as_value()?.post;
+        Ok(post.digest::<sha::Impl>().as_bytes().to_vec())
+    }
+
+    /// Exit code of the proven execution.
+    pub fn exit_code(&self) -> Result<ExitCodeInfo, JsError> {
+        let claim = self.receipt.claim()?;
+        Ok(claim.as_value()?.exit_code.into())
+    }
+
+    /// Kind of the inner receipt, e.g. "Groth16".
+    #[wasm_bindgen(unchecked_return_type = "ReceiptKind")]
+    pub fn kind(&self) -> String {
+        self.receipt.inner.kind().to_string()
+    }
+
+    /// Total number of bytes used by the seals of the receipt.
+    pub fn seal_size(&self) -> usize {
+        self.receipt.seal_size()
+    }
+
+    /// Assumptions the receipt depends on, in order. An assumption that is not resolved by the
+    /// receipt itself means it is only conditionally valid.
+    pub fn assumptions(&self) -> Result<Vec<AssumptionInfo>, JsError> {
+        let assumptions = self.receipt.assumptions()?;
+        Ok(assumptions.iter().map(AssumptionInfo::from).collect())
+    }
+
+    /// Assumptions not resolved by an assumption receipt carried within the receipt.
+    pub fn unresolved_assumptions(&self) -> Result<Vec<AssumptionInfo>, JsError> {
+        let assumptions = self.receipt.unresolved_assumptions()?;
+        Ok(assumptions.iter().map(AssumptionInfo::from).collect())
+    }
+
+    /// Whether the receipt is only conditionally valid. The journal of a conditional receipt must
+    /// not be trusted until every unresolved assumption has been proven.
+    pub fn is_conditional(&self) -> Result<bool, JsError> {
+        Ok(self.receipt.is_conditional()?)
+    }
+}
+
+/// Matches the unresolved assumptions of a receipt against the claims of companion receipts, so
+/// that a complete proof bundle can be assembled before going on-chain.
+#[wasm_bindgen]
+pub struct AssumptionResolver {
+    pending: Vec<MaybePruned<Assumption>>,
+}
+
+impl AssumptionResolver {
+    /// Resolve every pending assumption on the claim proven by `companion`. Assumptions left
+    /// unresolved by the companion itself become pending in turn. Returns the number of
+    /// assumptions resolved.
+    pub fn resolve(&mut self, companion: &Receipt) -> Result<usize, ConvertError> {
+        let claim = companion.claim()?.digest::<sha::Impl>();
+        let before = self.pending.len();
+        self.pending.retain(|assumption| match assumption {
+            MaybePruned::Value(assumption) => assumption.claim != claim,
+            MaybePruned::Pruned(_) => true,
+        });
+        let resolved = before - self.pending.len();
+        if resolved > 0 {
+            self.pending.extend(companion.unresolved_assumptions()?);
+        }
+        Ok(resolved)
+    }
+}
+
+#[wasm_bindgen]
+impl AssumptionResolver {
+    /// Start from the unresolved assumptions of `receipt`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(receipt: &ReceiptHandle) -> Result<AssumptionResolver, JsError> {
+        let pending = receipt.receipt.unresolved_assumptions()?;
+        Ok(Self { pending })
+    }
+
+    /// Add a companion receipt, returning the number of assumptions its claim resolves.
+    pub fn add_companion(&mut self, companion: &ReceiptHandle) -> Result<u32, JsError> {
+        Ok(self.resolve(&companion.receipt)? as u32)
+    }
+
+    /// Assumptions not yet resolved by any companion. Pruned assumptions cannot be matched and
+    /// always remain unresolved.
+    pub fn unresolved(&self) -> Vec<AssumptionInfo> {
+        self.pending.iter().map(AssumptionInfo::from).collect()
+    }
+
+    /// Whether every assumption has been resolved by a companion receipt.
+    pub fn is_complete(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
@@ -0,0 +1,215 @@
+//! JavaScript bindings of `bincode2proof-core` and `bincode2proof-codecs`, built with
+//! wasm-bindgen into the `bincode2proof` npm package.
+
+use std::sync::Arc;
+
+use bincode2proof_codecs::codec::{decode, decode_compressed, Format};
+use bincode2proof_core::{
+    digest_from_js, encode_seal_with_selector,
+    groth16::validate_receipt_seal,
+    hash::{hash_suite_from_name, HashFn},
+    Digestible,
+    selector::{Selector, SELECTOR_BYTES},
+    sha, ConvertError, Digest, InnerReceipt, MerkleProof, ProofData, Receipt, ReceiptClaim,
+    SealFormat, SegmentSealInfo, DIGEST_BYTES,
+};
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "abi")]
+pub mod abi;
+pub mod aggregation;
+pub mod batch;
+pub mod blob;
+#[cfg(feature = "client")]
+pub mod bonsai_client;
+pub mod codec;
+#[cfg(feature = "compression")]
+pub mod compress;
+#[cfg(feature = "json")]
+pub mod cosmwasm;
+pub mod encoder;
+pub mod ethereum;
+pub mod explain;
+pub mod groth16;
+pub mod handle;
+pub mod limits;
+pub mod move_vm;
+#[cfg(all(feature = "borsh", feature = "json"))]
+pub mod near;
+pub mod object;
+pub mod options;
+pub mod peek;
+pub mod progress;
+#[cfg(feature = "protobuf")]
+pub mod proto;
+#[cfg(feature = "rlp")]
+pub mod rlp;
+pub mod selector;
+pub mod shared;
+pub mod ssz;
+pub mod starknet;
+pub mod stats;
+#[cfg(feature = "streams")]
+pub mod stream;
+pub mod verify;
+#[cfg(feature = "webcrypto")]
+pub mod webcrypto;
+#[cfg(feature = "worker")]
+pub mod worker;
+
+#[cfg(all(feature = "wasm-threads", target_arch = "wasm32"))]
+pub use wasm_bindgen_rayon::init_thread_pool;
+
+#[cfg(all(feature = "compact-alloc", target_arch = "wasm32"))]
+#[global_allocator]
+static ALLOCATOR: talc::TalckWasm = unsafe { talc::TalckWasm::new_global() };
+
+/// Build a [MerkleProof] from a leaf index and its concatenated 32-byte sibling digests.
+fn merkle_proof_from_js(index: u32, siblings: &[u8]) -> Result<MerkleProof, JsError> {
+    if !siblings.len().is_multiple_of(DIGEST_BYTES) {
+        return Err(JsError::new(
+            "siblings must be a concatenation of 32-byte digests",
+        ));
+    }
+    let digests = siblings
+        .chunks_exact(DIGEST_BYTES)
+        .map(|sibling| digest_from_js(sibling, "sibling"))
+        .collect::<Result<_, _>>()?;
+    Ok(MerkleProof::new(index, digests))
+}
+
+fn hash_fn_from_js(hashfn: &str) -> Result<Arc<dyn HashFn>, JsError> {
+    hash_suite_from_name(hashfn)
+        .map(|suite| suite.hashfn)
+        .ok_or_else(|| JsError::new(&format!("unsupported hash function: {hashfn}")))
+}
+
+/// Compute the root of a Merkle inclusion proof, given the leaf, its index and the sibling
+/// digests concatenated from the leaf upwards, using the named hash suite.
+#[wasm_bindgen]
+pub fn merkle_root(
+    leaf: Vec<u8>,
+    index: u32,
+    siblings: Vec<u8>,
+    hashfn: &str,
+) -> Result<Vec<u8>, JsError> {
+    let proof = merkle_proof_from_js(index, &siblings)?;
+    let leaf = digest_from_js(&leaf, "leaf")?;
+    let root = proof.root(&leaf, hash_fn_from_js(hashfn)?.as_ref());
+    Ok(root.as_bytes().to_vec())
+}
+
+/// Check a Merkle inclusion proof against the expected root. See [merkle_root].
+#[wasm_bindgen]
+pub fn verify_merkle_proof(
+    leaf: Vec<u8>,
+    index: u32,
+    siblings: Vec<u8>,
+    root: Vec<u8>,
+    hashfn: &str,
+) -> Result<bool, JsError> {
+    let proof = merkle_proof_from_js(index, &siblings)?;
+    let leaf = digest_from_js(&leaf, "leaf")?;
+    let root = digest_from_js(&root, "root")?;
+    Ok(proof
+        .verify(&leaf, &root, hash_fn_from_js(hashfn)?.as_ref())
+        .is_ok())
+}
+
+/// Parse a `0x`-prefixed or bare hex string into the 32 bytes of a digest.
+#[wasm_bindgen]
+pub fn digest_from_hex(hex: &str) -> Result<Vec<u8>, JsError> {
+    let digest: Digest = hex.parse()?;
+    Ok(digest.as_bytes().to_vec())
+}
+
+/// Format the 32 bytes of a digest as a `0x`-prefixed hex string.
+#[wasm_bindgen]
+pub fn digest_to_hex(bytes: Vec<u8>) -> Result<String, JsError> {
+    Ok(digest_from_js(&bytes, "digest")?.to_string())
+}
+
+/// Encode the seal of a bincode encoded [Receipt] with a custom selector, given either as the
+/// 4-byte selector itself or as the 32-byte verifier parameters digest it is taken from.
+#[wasm_bindgen(js_name = encode_seal_with_selector)]
+pub fn encode_seal_with_selector_js(bincode: Vec<u8>, selector: &[u8]) -> Result<Vec<u8>, JsError> {
+    let selector = match selector.len() {
+        SELECTOR_BYTES | DIGEST_BYTES => Selector(selector[..SELECTOR_BYTES].try_into()?),
+        len => {
+            return Err(JsError::new(&format!(
+                "invalid selector: expected {SELECTOR_BYTES} or {DIGEST_BYTES} bytes, got {len}"
+            )))
+        }
+    };
+    let receipt: Receipt = decode(&bincode, Format::Bincode)?;
+    Ok(encode_seal_with_selector(&receipt, selector)?)
+}
+
+/// Extract the per-segment seals of a bincode encoded [Receipt] holding a
+/// [CompositeReceipt](bincode2proof_core::CompositeReceipt).
+#[wasm_bindgen]
+pub fn composite_seals(bincode: Vec<u8>) -> Result<Vec<SegmentSealInfo>, JsError> {
+    let receipt: Receipt = decode(&bincode, Format::Bincode)?;
+    match &receipt.inner {
+        InnerReceipt::Composite(composite) => Ok(composite.segment_seals()),
+        other => Err(ConvertError::UnsupportedReceiptKind(other.kind()).into()),
+    }
+}
+
+/// Decode a bincode [Receipt] and encode its seal and journal for on-chain verification. With
+/// the `compression` feature, gzip and zstd compressed receipts are decompressed first.
+///
+/// Failures are thrown to JavaScript as an `Error` rather than aborting the module.
+#[wasm_bindgen]
+pub fn convert(bincode: Vec<u8>) -> Result<ProofData, JsError> {
+    let receipt = decode_compressed(&bincode, Format::Bincode)?;
+    Ok(ProofData::try_from(receipt)?)
+}
+
+/// Same as [convert], additionally checking that the Groth16 seal points are valid BN254 curve
+/// points before returning the [ProofData].
+#[wasm_bindgen]
+pub fn convert_validated(bincode: Vec<u8>) -> Result<ProofData, JsError> {
+    let receipt = decode_compressed(&bincode, Format::Bincode)?;
+    validate_receipt_seal(&receipt)?;
+    Ok(ProofData::try_from(receipt)?)
+}
+
+/// Same as [convert], encoding the seal for the pre-1.0 `RiscZeroGroth16Verifier` contract.
+#[wasm_bindgen]
+pub fn convert_legacy(bincode: Vec<u8>) -> Result<ProofData, JsError> {
+    let receipt = decode_compressed(&bincode, Format::Bincode)?;
+    Ok(ProofData::from_receipt(receipt, SealFormat::Legacy)?)
+}
+
+/// Digest of the [ReceiptClaim] of a successful execution of the given image producing the given
+/// journal, as checked by the on-chain verifiers.
+#[wasm_bindgen]
+pub fn compute_claim_digest(image_id: Vec<u8>, journal: Vec<u8>) -> Result<Vec<u8>, JsError> {
+    let claim = ReceiptClaim::ok(digest_from_js(&image_id, "image ID")?, journal);
+    Ok(claim.digest::<sha::Impl>().as_bytes().to_vec())
+}
+
+/// Same as [convert], for a [Receipt] serialized with Borsh.
+#[cfg(feature = "borsh")]
+#[wasm_bindgen]
+pub fn convert_borsh(bytes: Vec<u8>) -> Result<ProofData, JsError> {
+    let receipt = decode_compressed(&bytes, Format::Borsh)?;
+    Ok(ProofData::try_from(receipt)?)
+}
+
+/// Same as [convert], for a [Receipt] serialized as JSON. Being text, the input is never
+/// decompressed.
+#[cfg(feature = "json")]
+#[wasm_bindgen]
+pub fn convert_json(text: String) -> Result<ProofData, JsError> {
+    let receipt: Receipt = decode(text.as_bytes(), Format::Json)?;
+    Ok(ProofData::try_from(receipt)?)
+}
+
+/// Same as [convert], for a [Receipt] serialized with postcard, as written by embedded provers.
+#[wasm_bindgen]
+pub fn convert_postcard(bytes: Vec<u8>) -> Result<ProofData, JsError> {
+    let receipt = decode_compressed(&bytes, Format::Postcard)?;
+    Ok(ProofData::try_from(receipt)?)
+}
@@ -0,0 +1,12 @@
+//! JavaScript bindings for decoding receipts under custom caps.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::{limits::DecodeLimits, ProofData, Receipt};
+use wasm_bindgen::prelude::*;
+
+/// Same as [convert](crate::convert), decoding the bincode [Receipt] with the given caps.
+#[wasm_bindgen]
+pub fn convert_with_limits(bincode: Vec<u8>, limits: &DecodeLimits) -> Result<ProofData, JsError> {
+    let receipt: Receipt = limits.scope(|| codec::decode(&bincode, codec::Format::Bincode))?;
+    Ok(ProofData::try_from(receipt)?)
+}
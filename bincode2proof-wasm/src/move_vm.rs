@@ -0,0 +1,14 @@
+//! JavaScript bindings for the Move verifier payloads.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::{move_vm::claim_bcs_bytes, Receipt};
+use wasm_bindgen::prelude::*;
+
+/// BCS encoding of the [ReceiptClaim](bincode2proof_core::ReceiptClaim) of a bincode encoded
+/// [Receipt]. Fails if the claim was pruned from the receipt.
+#[wasm_bindgen]
+pub fn encode_claim_bcs(receipt_bytes: Vec<u8>) -> Result<Vec<u8>, JsError> {
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    let claim = receipt.claim()?;
+    Ok(claim_bcs_bytes(claim.as_value()?)?)
+}
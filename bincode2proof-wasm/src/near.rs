@@ -0,0 +1,37 @@
+//! JavaScript bindings for the NEAR verifier calls.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use bincode2proof_codecs::codec;
+use bincode2proof_core::{
+    encoder::SealEncoder,
+    near::{NearArgsEncoder, DEFAULT_GAS},
+    Receipt,
+};
+use wasm_bindgen::prelude::*;
+
+/// Base64 encoded Borsh arguments of a NEAR verifier call for a bincode encoded [Receipt].
+#[wasm_bindgen]
+pub fn near_args_base64(receipt_bytes: Vec<u8>) -> Result<String, JsError> {
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    Ok(BASE64.encode(NearArgsEncoder.encode_seal(&receipt)?))
+}
+
+/// JSON of the `FunctionCall` action calling `method_name` with the verifier arguments of a
+/// bincode encoded [Receipt]. `gas` defaults to [DEFAULT_GAS] and `deposit`, in yoctoNEAR, to
+/// zero.
+#[wasm_bindgen]
+pub fn near_function_call(
+    receipt_bytes: Vec<u8>,
+    method_name: &str,
+    gas: Option<u64>,
+    deposit: Option<String>,
+) -> Result<String, JsError> {
+    let args = near_args_base64(receipt_bytes)?;
+    let call = serde_json::json!({
+        "method_name": method_name,
+        "args": args,
+        "gas": gas.unwrap_or(DEFAULT_GAS),
+        "deposit": deposit.unwrap_or_else(|| "0".to_string()),
+    });
+    Ok(call.to_string())
+}
@@ -0,0 +1,307 @@
+//! Export of a whole [Receipt] as a plain JavaScript object, for explorers rendering its full
+//! structure. Digests and byte strings are `0x`-prefixed hex strings.
+
+use bincode2proof_core::{
+    hex_string, Digestible, sha, Assumption, CompositeReceipt, ConvertError, ExitCode,
+    InnerAssumptionReceipt, InnerReceipt, MaybePruned, Output, Receipt, ReceiptClaim,
+    SegmentReceipt,
+};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::handle::ReceiptHandle;
+
+#[wasm_bindgen(typescript_custom_section)]
+const RECEIPT_OBJECT_TS: &str = r#"
+export interface ReceiptObject {
+  kind: ReceiptKind;
+  journal: string;
+  journal_digest: string;
+  seal_size: number;
+  verifier_parameters: string;
+  claim: ClaimObject;
+  inner: {
+    hashfn?: string;
+    control_id?: string;
+    segments?: SegmentObject[];
+    assumption_receipts?: AssumptionReceiptObject[];
+    merkle_path?: string[];
+    root?: AssumptionReceiptObject;
+  };
+}
+
+export interface ClaimObject {
+  digest: string;
+  pruned: boolean;
+  image_id?: string;
+  post_state_digest?: string;
+  exit_code?: { kind: ExitCodeKind; code?: number };
+  input?: string;
+  output?: {
+    digest: string;
+    journal_digest?: string;
+    assumptions?: { digest: string; claim?: string; control_root?: string }[];
+  };
+}
+
+export interface SegmentObject {
+  index: number;
+  hashfn: string;
+  seal_size: number;
+  verifier_parameters: string;
+  claim: ClaimObject;
+}
+
+export interface AssumptionReceiptObject {
+  kind: ReceiptKind;
+  seal_size: number;
+  verifier_parameters: string;
+  claim_digest: string;
+}
+"#;
+
+#[derive(Serialize)]
+struct ReceiptObject {
+    kind: &'static str,
+    journal: String,
+    journal_digest: String,
+    seal_size: usize,
+    verifier_parameters: String,
+    claim: ClaimObject,
+    inner: InnerObject,
+}
+
+#[derive(Serialize)]
+struct ClaimObject {
+    digest: String,
+    pruned: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post_state_digest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<ExitCodeObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output: Option<OutputObject>,
+}
+
+#[derive(Serialize)]
+struct ExitCodeObject {
+    kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct OutputObject {
+    digest: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    journal_digest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assumptions: Option<Vec<AssumptionObject>>,
+}
+
+#[derive(Serialize)]
+struct AssumptionObject {
+    digest: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    claim: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    control_root: Option<String>,
+}
+
+/// Fields specific to the kind of the inner receipt.
+#[derive(Default, Serialize)]
+struct InnerObject {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hashfn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    control_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    segments: Option<Vec<SegmentObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assumption_receipts: Option<Vec<AssumptionReceiptObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merkle_path: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    root: Option<AssumptionReceiptObject>,
+}
+
+#[derive(Serialize)]
+struct SegmentObject {
+    index: u32,
+    hashfn: String,
+    seal_size: usize,
+    verifier_parameters: String,
+    claim: ClaimObject,
+}
+
+#[derive(Serialize)]
+struct AssumptionReceiptObject {
+    kind: &'static str,
+    seal_size: usize,
+    verifier_parameters: String,
+    claim_digest: String,
+}
+
+fn digest_hex(digest: &impl Digestible) -> String {
+    digest.digest::<sha::Impl>().to_string()
+}
+
+impl From<ExitCode> for ExitCodeObject {
+    fn from(exit_code: ExitCode) -> Self {
+        Self {
+            kind: exit_code.kind(),
+            code: exit_code.user_code(),
+        }
+    }
+}
+
+impl From<&MaybePruned<Assumption>> for AssumptionObject {
+    fn from(assumption: &MaybePruned<Assumption>) -> Self {
+        let value = assumption.as_value().ok();
+        Self {
+            digest: digest_hex(assumption),
+            claim: value.map(|assumption| assumption.claim.to_string()),
+            control_root: value.map(|assumption| assumption.control_root.to_string()),
+        }
+    }
+}
+
+impl From<&Output> for OutputObject {
+    fn from(output: &Output) -> Self {
+        Self {
+            digest: digest_hex(output),
+            journal_digest: output.journal.as_value().ok().map(digest_hex),
+            assumptions: output
+                .assumptions
+                .as_value()
+                .ok()
+                .map(|assumptions| assumptions.0.iter().map(AssumptionObject::from).collect()),
+        }
+    }
+}
+
+impl From<&MaybePruned<ReceiptClaim>> for ClaimObject {
+    fn from(claim: &MaybePruned<ReceiptClaim>) -> Self {
+        let digest = digest_hex(claim);
+        let Ok(claim) = claim.as_value() else {
+            return Self {
+                digest,
+                pruned: true,
+                image_id: None,
+                post_state_digest: None,
+                exit_code: None,
+                input: None,
+                output: None,
+            };
+        };
+        let output = match &claim.output {
+            MaybePruned::Value(Some(output)) => Some(OutputObject::from(output)),
+            MaybePruned::Value(None) => None,
+            MaybePruned::Pruned(digest) => Some(OutputObject {
+                digest: digest.to_string(),
+                journal_digest: None,
+                assumptions: None,
+            }),
+        };
+        Self {
+            digest,
+            pruned: false,
+            image_id: Some(digest_hex(&claim.pre)),
+            post_state_digest: Some(digest_hex(&claim.post)),
+            exit_code: Some(claim.exit_code.into()),
+            input: Some(digest_hex(&claim.input)),
+            output,
+        }
+    }
+}
+
+impl From<&SegmentReceipt> for SegmentObject {
+    fn from(segment: &SegmentReceipt) -> Self {
+        Self {
+            index: segment.index,
+            hashfn: segment.hashfn.clone(),
+            seal_size: segment.seal_size(),
+            verifier_parameters: segment.verifier_parameters.to_string(),
+            claim: ClaimObject::from(&MaybePruned::Value(segment.claim.clone())),
+        }
+    }
+}
+
+impl TryFrom<&InnerAssumptionReceipt> for AssumptionReceiptObject {
+    type Error = ConvertError;
+
+    fn try_from(receipt: &InnerAssumptionReceipt) -> Result<Self, ConvertError> {
+        Ok(Self {
+            kind: receipt.kind(),
+            seal_size: receipt.seal_size(),
+            verifier_parameters: receipt.verifier_parameters().to_string(),
+            claim_digest: receipt.claim_digest()?.to_string(),
+        })
+    }
+}
+
+fn composite_object(composite: &CompositeReceipt) -> Result<InnerObject, ConvertError> {
+    Ok(InnerObject {
+        segments: Some(composite.segments.iter().map(SegmentObject::from).collect()),
+        assumption_receipts: Some(
+            composite
+                .assumption_receipts
+                .iter()
+                .map(AssumptionReceiptObject::try_from)
+                .collect::<Result<_, _>>()?,
+        ),
+        ..Default::default()
+    })
+}
+
+impl TryFrom<&Receipt> for ReceiptObject {
+    type Error = ConvertError;
+
+    fn try_from(receipt: &Receipt) -> Result<Self, ConvertError> {
+        let inner = match &receipt.inner {
+            InnerReceipt::Composite(composite) => composite_object(composite)?,
+            InnerReceipt::Succinct(succinct) => InnerObject {
+                hashfn: Some(succinct.hashfn.clone()),
+                control_id: Some(succinct.control_id.to_string()),
+                ..Default::default()
+            },
+            InnerReceipt::Groth16(_) | InnerReceipt::Fake(_) => InnerObject::default(),
+            InnerReceipt::SetInclusion(set) => InnerObject {
+                merkle_path: Some(set.merkle_path.iter().map(ToString::to_string).collect()),
+                root: set
+                    .root
+                    .as_deref()
+                    .map(AssumptionReceiptObject::try_from)
+                    .transpose()?,
+                ..Default::default()
+            },
+        };
+        Ok(Self {
+            kind: receipt.inner.kind(),
+            journal: hex_string(&receipt.journal.bytes),
+            journal_digest: digest_hex(&receipt.journal.bytes),
+            seal_size: receipt.seal_size(),
+            verifier_parameters: receipt.inner.verifier_parameters().to_string(),
+            claim: ClaimObject::from(&receipt.claim()?),
+            inner,
+        })
+    }
+}
+
+#[wasm_bindgen]
+impl ReceiptHandle {
+    /// The whole receipt as a plain JavaScript object: its kind, journal, seal size, verifier
+    /// parameters and claim, with the exit code and assumptions, and the fields specific to the
+    /// receipt kind, such as the hash function and segments. Pruned parts of the claim are given
+    /// by their digest only.
+    #[wasm_bindgen(unchecked_return_type = "ReceiptObject")]
+    pub fn to_object(&self) -> Result<JsValue, JsError> {
+        let object = ReceiptObject::try_from(self.receipt())?;
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+        Ok(object.serialize(&serializer)?)
+    }
+}
@@ -0,0 +1,16 @@
+//! JavaScript bindings for converting receipts with [ConvertOptions].
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::{options::ConvertOptions, ProofData, Receipt};
+use wasm_bindgen::prelude::*;
+
+/// Same as [convert](crate::convert), rejecting receipts whose exit code is not accepted by the
+/// given [ConvertOptions] and encoding the seal with its encoder.
+#[wasm_bindgen]
+pub fn convert_with_options(
+    bincode: Vec<u8>,
+    options: &ConvertOptions,
+) -> Result<ProofData, JsError> {
+    let receipt: Receipt = codec::decode(&bincode, codec::Format::Bincode)?;
+    Ok(options.convert(receipt)?)
+}
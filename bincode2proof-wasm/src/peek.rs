@@ -0,0 +1,24 @@
+//! JavaScript bindings for the partial decoding of bincode encoded receipts.
+
+use bincode2proof_codecs::peek::{estimate_decode_memory, extract_journal, peek_kind};
+use wasm_bindgen::prelude::*;
+
+/// Kind of the [InnerReceipt](bincode2proof_core::InnerReceipt) of a bincode encoded receipt, e.g.
+/// "Groth16", without decoding the rest of the receipt.
+#[wasm_bindgen(js_name = peek_kind, unchecked_return_type = "ReceiptKind")]
+pub fn peek_kind_js(bytes: &[u8]) -> Result<String, JsError> {
+    Ok(peek_kind(bytes)?.to_string())
+}
+
+/// Journal of a bincode encoded receipt, decoded without storing the seals.
+#[wasm_bindgen(js_name = extract_journal)]
+pub fn extract_journal_js(bytes: &[u8]) -> Result<Vec<u8>, JsError> {
+    Ok(extract_journal(bytes)?)
+}
+
+/// Approximate peak memory, in bytes, of converting a bincode encoded receipt, e.g. to route
+/// receipts too large for the device to a server-side converter.
+#[wasm_bindgen(js_name = estimate_decode_memory)]
+pub fn estimate_decode_memory_js(bytes: &[u8]) -> Result<usize, JsError> {
+    Ok(estimate_decode_memory(bytes)?)
+}
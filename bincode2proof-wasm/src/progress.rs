@@ -0,0 +1,151 @@
+//! Conversion of large receipts in steps, yielding to the JavaScript event loop in between so
+//! that the UI thread stays responsive.
+
+use bincode2proof_codecs::{codec::BincodeConfig, peek};
+use bincode2proof_core::{
+    limits::{self, LimitedField},
+    CompositeReceipt, ConvertError, Digest, InnerAssumptionReceipt, InnerReceipt, Journal,
+    ProofData, Receipt, ReceiptMetadata, SegmentReceipt,
+};
+use js_sys::{Function, Promise, Reflect};
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+/// Minimum time between two yields to the event loop, in milliseconds, i.e. about one frame.
+const YIELD_INTERVAL_MS: f64 = 16.0;
+
+/// Fields of a bincode encoded composite [Receipt] following its segments.
+#[derive(Deserialize)]
+struct CompositeTail {
+    #[serde(deserialize_with = "bincode2proof_core::limits::fields::assumptions")]
+    assumption_receipts: Vec<InnerAssumptionReceipt>,
+    verifier_parameters: Digest,
+    journal: Journal,
+    metadata: ReceiptMetadata,
+}
+
+/// Decode a value from `bytes` starting at `*offset`, advancing the offset past it.
+fn decode_at<T: serde::de::DeserializeOwned>(
+    bytes: &[u8],
+    offset: &mut usize,
+) -> Result<T, ConvertError> {
+    let mut rest = &bytes[*offset..];
+    let len = rest.len();
+    let value = limits::enforce(|| BincodeConfig::default().deserialize_reader(&mut rest, len))
+        .map_err(|err| match err {
+            ConvertError::InvalidInput {
+                format,
+                offset: relative,
+                path,
+                expected,
+                message,
+            } => ConvertError::InvalidInput {
+                format,
+                offset: *offset + relative,
+                path,
+                expected,
+                message,
+            },
+            err => err,
+        })?;
+    *offset += len - rest.len();
+    Ok(value)
+}
+
+/// Let the event loop run pending tasks and rendering, through a zero-delay `setTimeout`.
+async fn yield_to_event_loop() -> Result<(), JsValue> {
+    let set_timeout: Function =
+        Reflect::get(&js_sys::global(), &"setTimeout".into())?.dyn_into()?;
+    let mut schedule = |resolve: Function, _reject: Function| {
+        let _ = set_timeout.call2(&JsValue::UNDEFINED, &resolve, &0.into());
+    };
+    JsFuture::from(Promise::new(&mut schedule)).await?;
+    Ok(())
+}
+
+/// Reports progress to a JavaScript callback, yielding to the event loop at most once per
+/// [YIELD_INTERVAL_MS].
+struct Progress<'a> {
+    callback: &'a Function,
+    total_segments: usize,
+    last_yield: f64,
+}
+
+impl Progress<'_> {
+    fn report(&self, bytes_decoded: usize, segments_processed: usize) -> Result<(), JsValue> {
+        self.callback.call3(
+            &JsValue::UNDEFINED,
+            &bytes_decoded.into(),
+            &segments_processed.into(),
+            &self.total_segments.into(),
+        )?;
+        Ok(())
+    }
+
+    async fn step(
+        &mut self,
+        bytes_decoded: usize,
+        segments_processed: usize,
+    ) -> Result<(), JsValue> {
+        let now = js_sys::Date::now();
+        if now - self.last_yield >= YIELD_INTERVAL_MS {
+            self.report(bytes_decoded, segments_processed)?;
+            yield_to_event_loop().await?;
+            self.last_yield = js_sys::Date::now();
+        }
+        Ok(())
+    }
+}
+
+/// Same as [convert](crate::convert), decoding composite receipts one segment at a time and
+/// yielding to the event loop between segments, so that converting a large receipt does not
+/// freeze the page. `callback(bytesDecoded, segmentsProcessed, totalSegments)` is called before
+/// each yield and once decoding is complete. Receipts of other kinds are decoded in one step.
+#[wasm_bindgen]
+pub async fn convert_with_progress(
+    bytes: Vec<u8>,
+    #[wasm_bindgen(
+        unchecked_param_type = "(bytesDecoded: number, segmentsProcessed: number, totalSegments: \
+                                number) => void"
+    )]
+    callback: Function,
+) -> Result<ProofData, JsValue> {
+    let mut progress = Progress {
+        callback: &callback,
+        total_segments: 0,
+        last_yield: js_sys::Date::now(),
+    };
+    if peek::peek_kind(&bytes).map_err(JsError::from)? != "Composite" {
+        let receipt: Receipt = decode_at(&bytes, &mut 0).map_err(JsError::from)?;
+        progress.report(bytes.len(), 0)?;
+        return Ok(ProofData::try_from(receipt).map_err(JsError::from)?);
+    }
+
+    // Skip the variant tag of the inner receipt, then read the length of the segments.
+    let mut offset = 4;
+    let total_segments: u64 = decode_at(&bytes, &mut offset).map_err(JsError::from)?;
+    let total_segments = total_segments as usize;
+    limits::check_len(LimitedField::Segments, total_segments).map_err(JsError::from)?;
+    progress.total_segments = total_segments;
+
+    let mut segments = Vec::with_capacity(total_segments);
+    for _ in 0..total_segments {
+        let segment: SegmentReceipt = decode_at(&bytes, &mut offset).map_err(JsError::from)?;
+        segments.push(segment);
+        progress.step(offset, segments.len()).await?;
+    }
+    let tail: CompositeTail = decode_at(&bytes, &mut offset).map_err(JsError::from)?;
+    progress.report(offset, total_segments)?;
+
+    let receipt = Receipt {
+        inner: InnerReceipt::Composite(CompositeReceipt {
+            segments,
+            assumption_receipts: tail.assumption_receipts,
+            verifier_parameters: tail.verifier_parameters,
+        }),
+        journal: tail.journal,
+        metadata: tail.metadata,
+    };
+    Ok(ProofData::try_from(receipt).map_err(JsError::from)?)
+}
@@ -0,0 +1,31 @@
+//! JavaScript bindings for the risc0 protobuf receipt messages.
+
+use bincode2proof_codecs::{
+    codec,
+    proto::{decode_receipt, encode_receipt},
+};
+use bincode2proof_core::{ProofData, Receipt};
+use wasm_bindgen::prelude::*;
+
+/// Same as [convert](crate::convert), for a [Receipt] encoded as a protobuf `core.Receipt`
+/// message.
+#[wasm_bindgen]
+pub fn convert_protobuf(bytes: Vec<u8>) -> Result<ProofData, JsError> {
+    Ok(ProofData::try_from(decode_receipt(&bytes)?)?)
+}
+
+/// Re-encode a protobuf `core.Receipt` message as a bincode [Receipt].
+#[wasm_bindgen]
+pub fn protobuf_to_bincode(bytes: Vec<u8>) -> Result<Vec<u8>, JsError> {
+    Ok(codec::encode(
+        &decode_receipt(&bytes)?,
+        codec::Format::Bincode,
+    )?)
+}
+
+/// Re-encode a bincode [Receipt] as a protobuf `core.Receipt` message.
+#[wasm_bindgen]
+pub fn bincode_to_protobuf(bincode: Vec<u8>) -> Result<Vec<u8>, JsError> {
+    let receipt: Receipt = codec::decode(&bincode, codec::Format::Bincode)?;
+    Ok(encode_receipt(receipt)?)
+}
@@ -0,0 +1,23 @@
+//! JavaScript bindings for RLP encoded proof payloads.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::{
+    digest_from_js, encode_seal, Digestible, rlp::encode_proof, sha, Receipt,
+};
+use wasm_bindgen::prelude::*;
+
+/// RLP encode the seal, journal and image ID of a bincode encoded [Receipt]. The image ID is
+/// taken from the receipt claim unless given.
+#[wasm_bindgen]
+pub fn encode_proof_rlp(
+    receipt_bytes: Vec<u8>,
+    image_id: Option<Vec<u8>>,
+) -> Result<Vec<u8>, JsError> {
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    let image_id = match image_id {
+        Some(image_id) => digest_from_js(&image_id, "image ID")?,
+        None => receipt.claim()?.as_value()?.pre.digest::<sha::Impl>(),
+    };
+    let seal = encode_seal(&receipt)?;
+    Ok(encode_proof(&seal, &receipt.journal.bytes, &image_id))
+}
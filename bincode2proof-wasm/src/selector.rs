@@ -0,0 +1,18 @@
+//! JavaScript bindings for the seal selector registry.
+
+use bincode2proof_core::selector::{split_seal, SplitSeal, VersionInfo};
+use wasm_bindgen::prelude::*;
+
+/// Split an encoded seal into its selector and raw seal.
+#[wasm_bindgen(js_name = split_seal)]
+pub fn split_seal_js(seal: &[u8]) -> Result<SplitSeal, JsError> {
+    Ok(SplitSeal::new(seal)?)
+}
+
+/// Identify the verifier targeted by a 4-byte selector, or by a seal starting with one.
+/// Returns `undefined` for selectors not in the registry.
+#[wasm_bindgen]
+pub fn identify_selector(selector: &[u8]) -> Result<Option<VersionInfo>, JsError> {
+    let (selector, _) = split_seal(selector)?;
+    Ok(selector.info())
+}
@@ -0,0 +1,51 @@
+//! Decoding of receipts held in JavaScript memory, such as a `SharedArrayBuffer` handed between
+//! workers, without first copying the whole input into wasm linear memory.
+
+use std::io::{self, BufReader, Read};
+
+use bincode2proof_codecs::codec::BincodeConfig;
+use bincode2proof_core::{limits, ConvertError, ProofData, Receipt};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+/// Size of the window of JavaScript memory copied into wasm memory at a time.
+const WINDOW_BYTES: usize = 64 * 1024;
+
+/// Reads a `Uint8Array` through `subarray` views copied straight into the caller's buffer.
+struct ArrayReader<'a> {
+    array: &'a Uint8Array,
+    position: u32,
+}
+
+impl Read for ArrayReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.array.length() - self.position;
+        let len = remaining.min(buf.len().try_into().unwrap_or(u32::MAX));
+        self.array
+            .subarray(self.position, self.position + len)
+            .copy_to(&mut buf[..len as usize]);
+        self.position += len;
+        Ok(len as usize)
+    }
+}
+
+/// Decode a bincode encoded [Receipt] held in JavaScript memory. Only a window of the input is
+/// in wasm memory at a time, besides the decoded receipt itself.
+pub fn decode_array(bytes: &Uint8Array) -> Result<Receipt, ConvertError> {
+    let reader = BufReader::with_capacity(
+        WINDOW_BYTES,
+        ArrayReader {
+            array: bytes,
+            position: 0,
+        },
+    );
+    limits::enforce(|| BincodeConfig::default().deserialize_reader(reader, bytes.length() as usize))
+}
+
+/// Same as [convert](crate::convert), reading the receipt in place from a `Uint8Array`, which
+/// may be backed by a `SharedArrayBuffer`, instead of copying it into wasm memory first. The
+/// input must not be modified until the conversion returns, and must not be compressed.
+#[wasm_bindgen]
+pub fn convert_shared(bytes: &Uint8Array) -> Result<ProofData, JsError> {
+    Ok(ProofData::try_from(decode_array(bytes)?)?)
+}
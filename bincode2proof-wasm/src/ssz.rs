@@ -0,0 +1,22 @@
+//! JavaScript bindings for the SSZ encoding of receipt claims.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::Receipt;
+use wasm_bindgen::prelude::*;
+
+/// SSZ encoding of the [ReceiptClaim](bincode2proof_core::ReceiptClaim) of a bincode encoded
+/// [Receipt].
+#[wasm_bindgen]
+pub fn encode_claim_ssz(receipt_bytes: Vec<u8>) -> Result<Vec<u8>, JsError> {
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    Ok(receipt.claim()?.as_value()?.ssz_bytes())
+}
+
+/// SSZ hash tree root of the [ReceiptClaim](bincode2proof_core::ReceiptClaim) of a bincode
+/// encoded [Receipt].
+#[wasm_bindgen]
+pub fn claim_hash_tree_root(receipt_bytes: Vec<u8>) -> Result<Vec<u8>, JsError> {
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    let root = receipt.claim()?.as_value()?.ssz_hash_tree_root();
+    Ok(root.as_bytes().to_vec())
+}
@@ -0,0 +1,14 @@
+//! JavaScript bindings for the StarkNet verifier calldata.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::{starknet::calldata_felts, Receipt};
+use wasm_bindgen::prelude::*;
+
+/// Build the calldata of the Garaga RISC Zero verifier for a bincode encoded Groth16 [Receipt], as
+/// decimal felts. The pairing hints generated by Garaga's tooling must be appended to them.
+#[wasm_bindgen]
+pub fn starknet_calldata(receipt_bytes: Vec<u8>) -> Result<Vec<String>, JsError> {
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    let felts = calldata_felts(&receipt)?;
+    Ok(felts.iter().map(u128::to_string).collect())
+}
@@ -0,0 +1,22 @@
+//! JavaScript bindings for the size metrics of receipts.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::{stats::ReceiptStats, Receipt};
+use wasm_bindgen::prelude::*;
+
+use crate::handle::ReceiptHandle;
+
+#[wasm_bindgen]
+impl ReceiptHandle {
+    /// Size metrics of the receipt.
+    pub fn stats(&self) -> ReceiptStats {
+        ReceiptStats::from(self.receipt())
+    }
+}
+
+/// Size metrics of a bincode encoded [Receipt].
+#[wasm_bindgen]
+pub fn receipt_stats(receipt_bytes: Vec<u8>) -> Result<ReceiptStats, JsError> {
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    Ok(ReceiptStats::from(&receipt))
+}
@@ -0,0 +1,28 @@
+//! JavaScript bindings for converting receipts read from a `ReadableStream`.
+
+use bincode2proof_codecs::stream::StreamingDecoder;
+use bincode2proof_core::ProofData;
+use js_sys::{Reflect, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+/// Convert a bincode encoded receipt read from a `ReadableStream` of `Uint8Array` chunks, e.g.
+/// `convert_stream((await fetch(receiptUrl)).body)`, without first collecting the whole receipt
+/// into an `ArrayBuffer`. The stream is locked while it is read.
+#[wasm_bindgen]
+pub async fn convert_stream(stream: web_sys::ReadableStream) -> Result<ProofData, JsValue> {
+    let reader = web_sys::ReadableStreamDefaultReader::new(&stream)?;
+    let mut decoder = StreamingDecoder::default();
+    loop {
+        let result = JsFuture::from(reader.read()).await?;
+        if Reflect::get(&result, &"done".into())?.is_truthy() {
+            break;
+        }
+        let chunk: Uint8Array = Reflect::get(&result, &"value".into())?
+            .dyn_into()
+            .map_err(|_| JsError::new("stream chunk is not a Uint8Array"))?;
+        decoder.push_js(&chunk)?;
+    }
+    reader.release_lock();
+    Ok(decoder.finish_js()?)
+}
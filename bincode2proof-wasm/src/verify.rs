@@ -0,0 +1,64 @@
+//! JavaScript bindings for verifying receipts.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::{
+    digest_from_js,
+    verify::{VerificationReport, VerifierContext},
+    ProofData, Receipt,
+};
+use wasm_bindgen::prelude::*;
+
+/// Verify a bincode encoded [Receipt] for the given image ID.
+///
+/// Malformed input is thrown to JavaScript as an `Error`; the outcome of verification itself is
+/// returned in the report.
+#[wasm_bindgen]
+pub fn verify(receipt_bytes: Vec<u8>, image_id: Vec<u8>) -> Result<VerificationReport, JsError> {
+    let image_id = digest_from_js(&image_id, "image ID")?;
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    Ok(VerificationReport::new(
+        &receipt,
+        &VerifierContext::default(),
+        image_id,
+    ))
+}
+
+/// Same as [verify], under the given [VerifierContext].
+#[wasm_bindgen]
+pub fn verify_with_context(
+    receipt_bytes: Vec<u8>,
+    image_id: Vec<u8>,
+    ctx: &VerifierContext,
+) -> Result<VerificationReport, JsError> {
+    let image_id = digest_from_js(&image_id, "image ID")?;
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    Ok(VerificationReport::new(&receipt, ctx, image_id))
+}
+
+/// Check that a bincode encoded [Receipt] was produced by the guest with the given image ID,
+/// throwing an `Error` describing both image IDs on mismatch.
+#[wasm_bindgen]
+pub fn ensure_image_id(receipt_bytes: Vec<u8>, image_id: Vec<u8>) -> Result<(), JsError> {
+    let image_id = digest_from_js(&image_id, "image ID")?;
+    let receipt: Receipt = codec::decode(&receipt_bytes, codec::Format::Bincode)?;
+    Ok(receipt.ensure_image_id(image_id)?)
+}
+
+/// Same as [convert](crate::convert), rejecting receipts not produced by the guest with the given
+/// image ID.
+#[wasm_bindgen]
+pub fn convert_for_image(bincode: Vec<u8>, image_id: Vec<u8>) -> Result<ProofData, JsError> {
+    let image_id = digest_from_js(&image_id, "image ID")?;
+    let receipt: Receipt = codec::decode(&bincode, codec::Format::Bincode)?;
+    receipt.ensure_image_id(image_id)?;
+    Ok(ProofData::try_from(receipt)?)
+}
+
+/// Same as [convert](crate::convert), rejecting receipts whose verifier parameters are not
+/// accepted by the given [VerifierContext].
+#[wasm_bindgen]
+pub fn convert_with_context(bincode: Vec<u8>, ctx: &VerifierContext) -> Result<ProofData, JsError> {
+    let receipt: Receipt = codec::decode(&bincode, codec::Format::Bincode)?;
+    ctx.check_verifier_parameters(&receipt.inner.verifier_parameters())?;
+    Ok(ProofData::try_from(receipt)?)
+}
@@ -0,0 +1,17 @@
+//! JavaScript bindings for hashing with the WebCrypto API.
+
+use bincode2proof_core::sha::webcrypto;
+use wasm_bindgen::prelude::*;
+
+/// Whether hashing is delegated to WebCrypto in this environment.
+#[wasm_bindgen]
+pub fn webcrypto_available() -> bool {
+    webcrypto::available()
+}
+
+/// SHA-256 digest of a journal, as taken by `IRiscZeroVerifier.verify`, computed with
+/// WebCrypto when available.
+#[wasm_bindgen]
+pub async fn journal_digest_async(journal: Vec<u8>) -> Result<Vec<u8>, JsValue> {
+    Ok(webcrypto::hash_bytes(&journal).await?.as_bytes().to_vec())
+}
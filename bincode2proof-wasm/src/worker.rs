@@ -0,0 +1,172 @@
+//! Conversion and verification on a dedicated Web Worker, behind a Promise-based facade.
+//!
+//! The worker imports the JavaScript bindings of this package and instantiates its own copy of
+//! the wasm module, which requires the bindings to be built with `wasm-pack --target web`.
+//! Results cross the worker boundary as plain objects, since wasm-bindgen classes such as
+//! [ProofData] cannot be posted between workers.
+
+use bincode2proof_codecs::codec;
+use bincode2proof_core::ProofData;
+use js_sys::{Array, Promise, Uint8Array};
+use serde::{Serialize, Serializer};
+use wasm_bindgen::prelude::*;
+
+use crate::verify;
+
+#[wasm_bindgen(module = "/js/worker.js")]
+extern "C" {
+    type WorkerClient;
+
+    #[wasm_bindgen(constructor, catch)]
+    fn new(module_url: Option<String>) -> Result<WorkerClient, JsValue>;
+
+    #[wasm_bindgen(method)]
+    fn call(this: &WorkerClient, method: &str, args: Array) -> Promise;
+
+    #[wasm_bindgen(method)]
+    fn terminate(this: &WorkerClient);
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const WORKER_TS: &str = r#"
+export interface ProofObject {
+  seal: Uint8Array;
+  journal: Uint8Array;
+  claim_digest: Uint8Array;
+  journal_digest: Uint8Array;
+  image_id?: Uint8Array;
+}
+
+export interface VerificationObject {
+  valid: boolean;
+  reason?: string;
+  kind: ReceiptKind;
+  claim_digest?: Uint8Array;
+}
+"#;
+
+fn bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(bytes)
+}
+
+fn optional_bytes<S: Serializer>(
+    bytes: &Option<Vec<u8>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match bytes {
+        Some(bytes) => serializer.serialize_bytes(bytes),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// [ProofData] as posted back by the worker.
+#[derive(Serialize)]
+struct ProofObject {
+    #[serde(serialize_with = "bytes")]
+    seal: Vec<u8>,
+    #[serde(serialize_with = "bytes")]
+    journal: Vec<u8>,
+    #[serde(serialize_with = "bytes")]
+    claim_digest: Vec<u8>,
+    #[serde(serialize_with = "bytes")]
+    journal_digest: Vec<u8>,
+    #[serde(
+        serialize_with = "optional_bytes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    image_id: Option<Vec<u8>>,
+}
+
+impl From<ProofData> for ProofObject {
+    fn from(proof: ProofData) -> Self {
+        Self {
+            claim_digest: proof.claim_digest(),
+            journal_digest: proof.journal_digest(),
+            image_id: proof.image_id(),
+            seal: proof.seal(),
+            journal: proof.journal(),
+        }
+    }
+}
+
+/// [VerificationReport](bincode2proof_core::verify::VerificationReport) as posted back by the
+/// worker.
+#[derive(Serialize)]
+struct VerificationObject {
+    valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+    kind: String,
+    #[serde(
+        serialize_with = "optional_bytes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    claim_digest: Option<Vec<u8>>,
+}
+
+/// Run `method` on `args` inside the worker. Not meant to be called directly.
+#[doc(hidden)]
+#[wasm_bindgen(js_name = __workerDispatch)]
+pub fn worker_dispatch(method: &str, args: Array) -> Result<JsValue, JsError> {
+    let bytes = |index: u32| {
+        args.get(index)
+            .dyn_into::<Uint8Array>()
+            .map(|array| array.to_vec())
+            .map_err(|_| JsError::new(&format!("{method}: argument {index} is not a Uint8Array")))
+    };
+    let serializer = serde_wasm_bindgen::Serializer::new();
+    let result = match method {
+        "convert" => {
+            let receipt = codec::decode_compressed(&bytes(0)?, codec::Format::Bincode)?;
+            ProofObject::from(ProofData::try_from(receipt)?).serialize(&serializer)?
+        }
+        "verify" => {
+            let report = verify::verify(bytes(0)?, bytes(1)?)?;
+            VerificationObject {
+                valid: report.valid(),
+                reason: report.reason(),
+                kind: report.kind(),
+                claim_digest: report.claim_digest(),
+            }
+            .serialize(&serializer)?
+        }
+        _ => return Err(JsError::new(&format!("unknown worker method: {method}"))),
+    };
+    Ok(result)
+}
+
+/// A dedicated Web Worker running conversions and verifications off the calling thread.
+#[wasm_bindgen]
+pub struct ConversionWorker {
+    client: WorkerClient,
+}
+
+#[wasm_bindgen]
+impl ConversionWorker {
+    /// Start a worker. `module_url` is the URL of the JavaScript bindings of this package, which
+    /// defaults to their location next to the generated snippets.
+    #[wasm_bindgen(constructor)]
+    pub fn new(module_url: Option<String>) -> Result<ConversionWorker, JsValue> {
+        Ok(Self {
+            client: WorkerClient::new(module_url)?,
+        })
+    }
+
+    /// Same as [convert](crate::convert), run in the worker.
+    #[wasm_bindgen(unchecked_return_type = "Promise<ProofObject>")]
+    pub fn convert(&self, bincode: Uint8Array) -> Promise {
+        self.client.call("convert", Array::of1(&bincode))
+    }
+
+    /// Same as [verify](crate::verify::verify), run in the worker.
+    #[wasm_bindgen(unchecked_return_type = "Promise<VerificationObject>")]
+    pub fn verify(&self, receipt_bytes: Uint8Array, image_id: Uint8Array) -> Promise {
+        self.client
+            .call("verify", Array::of2(&receipt_bytes, &image_id))
+    }
+
+    /// Stop the worker, rejecting the calls still in progress.
+    pub fn terminate(&self) {
+        self.client.terminate();
+    }
+}